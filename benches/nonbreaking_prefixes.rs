@@ -0,0 +1,139 @@
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::hint::black_box;
+use std::io::{self, BufReader, Read};
+use tokenaisu::moses::{Language, MosesTokenizer, NonbreakingPrefixes, PrefixType};
+
+const UNTOKENIZED_TEXT: &str = include_str!("../tests/untokenized_text.txt");
+
+// Size of the large input, 64 MiB by default, which can be raised with TOKENAISU_BENCH_BYTES (such as 1073741824 for
+// 1 GiB) for more representative runs
+fn large_input_bytes() -> u64 {
+    env::var("TOKENAISU_BENCH_BYTES")
+        .ok()
+        .and_then(|bytes| bytes.parse().ok())
+        .unwrap_or(64 << 20)
+}
+
+// Prefixes of every language as the literal tables the previous table was generated with, read from the vendored
+// Moses files with the parser of NonbreakingPrefixes
+fn legacy_prefix_tables() -> Vec<(&'static str, Vec<(&'static str, PrefixType)>)> {
+    let mut tables = Vec::new();
+    for entry in fs::read_dir("src/nonbreaking_prefixes").unwrap() {
+        let path = entry.unwrap().path();
+        let file_name = path.file_name().unwrap().to_str().unwrap();
+        let Some(language) = file_name.strip_prefix("nonbreaking_prefix.") else {
+            continue;
+        };
+        let prefixes = NonbreakingPrefixes::parse(&fs::read_to_string(&path).unwrap())
+            .iter()
+            .map(|(prefix, prefix_type)| (&*prefix.to_owned().leak(), prefix_type))
+            .collect();
+        tables.push((&*language.to_owned().leak(), prefixes));
+    }
+    tables
+}
+
+// Previous structure of the built-in prefixes, a map of languages to maps of prefixes that was built on first use
+fn legacy_prefixes(
+    tables: &[(&'static str, Vec<(&'static str, PrefixType)>)],
+) -> HashMap<&'static str, HashMap<&'static str, PrefixType>> {
+    tables
+        .iter()
+        .map(|(language, prefixes)| (*language, prefixes.iter().copied().collect()))
+        .collect()
+}
+
+// Repeats a text up to a number of bytes without keeping the whole input in memory
+struct RepeatedText {
+    text: Vec<u8>,
+    position: usize,
+    remaining: u64,
+}
+
+impl Read for RepeatedText {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let len = buf
+            .len()
+            .min(self.text.len() - self.position)
+            .min(self.remaining.try_into().unwrap_or(usize::MAX));
+        buf[..len].copy_from_slice(&self.text[self.position..self.position + len]);
+        self.position = (self.position + len) % self.text.len();
+        self.remaining -= len as u64;
+        Ok(len)
+    }
+}
+
+fn prefix_lookups(c: &mut Criterion) {
+    // Words followed by a period, as checked by the tokenizer
+    let words: Vec<&str> = UNTOKENIZED_TEXT
+        .split_whitespace()
+        .map(|word| word.trim_end_matches('.'))
+        .collect();
+    let prefixes = NonbreakingPrefixes::builtin(&Language::En);
+    let tables = legacy_prefix_tables();
+    let legacy = legacy_prefixes(&tables);
+    let mut group = c.benchmark_group("prefix lookups");
+    group.bench_function("phf", |b| {
+        b.iter(|| {
+            words
+                .iter()
+                .filter(|word| prefixes.get(word).is_some())
+                .count()
+        })
+    });
+    // The language was looked up for every word before its prefix
+    group.bench_function("legacy hash maps", |b| {
+        b.iter(|| {
+            words
+                .iter()
+                .filter(|word| legacy[black_box(Language::En.as_ref())].contains_key(*word))
+                .count()
+        })
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("builtin prefixes");
+    group.bench_function("phf", |b| {
+        b.iter(|| NonbreakingPrefixes::builtin(black_box(&Language::En)))
+    });
+    // Cold initialization of the previous structure, which built the maps of every language at once
+    group.bench_function("legacy hash maps", |b| {
+        b.iter(|| legacy_prefixes(black_box(&tables)))
+    });
+    group.finish();
+}
+
+fn tokenize(c: &mut Criterion) {
+    let tokenizer = MosesTokenizer::builder(Language::En).build().unwrap();
+    let mut group = c.benchmark_group("tokenize");
+    group.throughput(Throughput::Bytes(UNTOKENIZED_TEXT.len() as u64));
+    group.bench_function("untokenized_text.txt", |b| {
+        b.iter(|| tokenizer.tokenize(black_box(UNTOKENIZED_TEXT)))
+    });
+
+    let bytes = large_input_bytes();
+    group.sample_size(10);
+    group.throughput(Throughput::Bytes(bytes));
+    group.bench_with_input(
+        BenchmarkId::new("repeated text", bytes),
+        &bytes,
+        |b, &bytes| {
+            b.iter(|| {
+                let reader = BufReader::new(RepeatedText {
+                    // The file does not end with a newline, which would join its last and first lines
+                    text: format!("{UNTOKENIZED_TEXT}\n").into_bytes(),
+                    position: 0,
+                    remaining: bytes,
+                });
+                tokenizer.tokenize_reader(reader, io::sink()).unwrap()
+            })
+        },
+    );
+    group.finish();
+}
+
+criterion_group!(benches, prefix_lookups, tokenize);
+criterion_main!(benches);
@@ -0,0 +1,71 @@
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use std::hint::black_box;
+use tokenaisu::moses::{Language, MosesTokenizer, MosesTokenizerBuilder};
+
+const UNTOKENIZED_TEXT: &str = include_str!("../tests/untokenized_text.txt");
+
+// Tokenizes the lines of the test text one by one, so that the results do not depend on the number of cores
+fn tokenize_lines(c: &mut Criterion) {
+    let configurations: [(&str, MosesTokenizerBuilder); 5] = [
+        ("en", MosesTokenizer::builder(Language::En)),
+        ("fr", MosesTokenizer::builder(Language::Fr)),
+        ("fi", MosesTokenizer::builder(Language::Fi)),
+        (
+            "en aggressive hyphen splitting",
+            MosesTokenizer::builder(Language::En).aggressive_hyphen_splitting(true),
+        ),
+        (
+            "en protected patterns",
+            MosesTokenizer::builder(Language::En).protected_patterns(&[r"https?://\S+", r"\d+"]),
+        ),
+    ];
+    let mut group = c.benchmark_group("tokenize lines");
+    group.throughput(Throughput::Bytes(UNTOKENIZED_TEXT.len() as u64));
+    for (name, builder) in configurations {
+        let tokenizer = builder.build().unwrap();
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                for line in UNTOKENIZED_TEXT.lines() {
+                    black_box(tokenizer.tokenize_line_string(black_box(line)));
+                }
+            })
+        });
+    }
+    group.finish();
+}
+
+// Compares the raw tokens of the single-pass engine with those of the regular expression cascade the tokenizer was
+// originally ported with, before escaping
+fn engine_against_regex_cascade(c: &mut Criterion) {
+    let configurations: [(&str, MosesTokenizerBuilder); 3] = [
+        ("en", MosesTokenizer::builder(Language::En)),
+        ("fr", MosesTokenizer::builder(Language::Fr)),
+        (
+            "en aggressive hyphen splitting",
+            MosesTokenizer::builder(Language::En).aggressive_hyphen_splitting(true),
+        ),
+    ];
+    let mut group = c.benchmark_group("engine against regex cascade");
+    group.throughput(Throughput::Bytes(UNTOKENIZED_TEXT.len() as u64));
+    for (name, builder) in configurations {
+        let tokenizer = builder.build().unwrap();
+        group.bench_function(BenchmarkId::new("single pass", name), |b| {
+            b.iter(|| {
+                for line in UNTOKENIZED_TEXT.lines() {
+                    black_box(tokenizer.bench_tokenize_engine(black_box(line)));
+                }
+            })
+        });
+        group.bench_function(BenchmarkId::new("regex cascade", name), |b| {
+            b.iter(|| {
+                for line in UNTOKENIZED_TEXT.lines() {
+                    black_box(tokenizer.bench_tokenize_regex_cascade(black_box(line)));
+                }
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, tokenize_lines, engine_against_regex_cascade);
+criterion_main!(benches);
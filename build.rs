@@ -0,0 +1,178 @@
+use prefix_file::{PrefixType, parse_prefix_line};
+use regex_syntax::hir::{Class, HirKind};
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+// Parser of the lines of prefixes files, shared with `NonbreakingPrefixes`
+#[path = "src/moses/prefix_file.rs"]
+mod prefix_file;
+
+// Directory with the nonbreaking prefixes files of https://github.com/moses-smt/mosesdecoder/tree/master/scripts/share/nonbreaking_prefixes
+const PREFIXES_DIR: &str = "src/nonbreaking_prefixes";
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=src/moses/prefix_file.rs");
+    let out_dir = env::var("OUT_DIR").unwrap();
+    write_nonbreaking_prefixes(Path::new(&out_dir));
+    write_unicode_classes(Path::new(&out_dir), "letters.rs", &[("LETTERS", r"\p{L}")]);
+    write_unicode_classes(
+        Path::new(&out_dir),
+        "bert_classes.rs",
+        &[
+            ("NONSPACING_MARKS", r"\p{Mn}"),
+            ("PUNCTUATION", r"\p{P}"),
+            ("SPACE_SEPARATORS", r"\p{Zs}"),
+            ("CONTROLS", r"[\p{Cc}\p{Cf}]"),
+        ],
+    );
+    write_scripts(Path::new(&out_dir));
+}
+
+// Generates the table of built-in nonbreaking prefixes included by `src/moses/nonbreaking_prefixes.rs`
+fn write_nonbreaking_prefixes(out_dir: &Path) {
+    println!("cargo:rerun-if-changed={PREFIXES_DIR}");
+
+    // Languages are sorted so that the generated code is reproducible. Repeated prefixes keep their last type, as in
+    // the original tokenizer.
+    let mut prefixes: BTreeMap<String, BTreeMap<String, PrefixType>> = BTreeMap::new();
+    for entry in fs::read_dir(PREFIXES_DIR).unwrap() {
+        let path = entry.unwrap().path();
+        let Some(language) = path
+            .file_name()
+            .and_then(|file_name| file_name.to_str())
+            .and_then(|file_name| file_name.strip_prefix("nonbreaking_prefix."))
+        else {
+            continue;
+        };
+        println!("cargo:rerun-if-changed={}", path.display());
+        let contents = fs::read_to_string(&path).unwrap();
+        let language_prefixes = prefixes.entry(language.to_owned()).or_default();
+        for (prefix, prefix_type) in contents.lines().filter_map(parse_prefix_line) {
+            language_prefixes.insert(prefix.to_owned(), prefix_type);
+        }
+    }
+
+    let mut code = String::new();
+    for (language, language_prefixes) in &prefixes {
+        // Perfect hash maps, built at compile time
+        let mut map = phf_codegen::Map::new();
+        for (prefix, prefix_type) in language_prefixes {
+            map.entry(prefix.as_str(), &format!("PrefixType::{prefix_type:?}"));
+        }
+        writeln!(
+            code,
+            "static {}: phf::Map<&'static str, PrefixType> = {};\n",
+            language.to_uppercase(),
+            map.build()
+        )
+        .unwrap();
+    }
+    // Language variants are named after their codes, so a prefixes file without a language fails to compile
+    code.push_str(
+        "pub(super) fn builtin_prefixes(language: &Language) -> Option<&'static phf::Map<&'static str, PrefixType>> {\n    \
+         match language {\n",
+    );
+    for language in prefixes.keys() {
+        let mut variant = language.clone();
+        variant[..1].make_ascii_uppercase();
+        writeln!(
+            code,
+            "        Language::{variant} => Some(&{}),",
+            language.to_uppercase()
+        )
+        .unwrap();
+    }
+    code.push_str("        _ => None,\n    }\n}\n");
+
+    fs::write(out_dir.join("nonbreaking_prefixes.rs"), code).unwrap();
+}
+
+// Generates the ranges of Unicode classes, such as the letter category included by `src/moses/engine.rs` or the
+// categories used by `src/wordpiece.rs`. They are taken from the same crate as the `\p{L}` class of the regular
+// expressions of the tokenizer so that both agree on the Unicode version.
+fn write_unicode_classes(out_dir: &Path, file_name: &str, classes: &[(&str, &str)]) {
+    let mut code = String::new();
+    for (name, pattern) in classes {
+        let hir = regex_syntax::Parser::new().parse(pattern).unwrap();
+        let HirKind::Class(Class::Unicode(class)) = hir.kind() else {
+            unreachable!("{pattern} is a Unicode class");
+        };
+        writeln!(
+            code,
+            "static {name}: [(char, char); {}] = [",
+            class.ranges().len()
+        )
+        .unwrap();
+        for range in class.ranges() {
+            writeln!(code, "    ({:?}, {:?}),", range.start(), range.end()).unwrap();
+        }
+        code.push_str("];\n");
+    }
+    fs::write(out_dir.join(file_name), code).unwrap();
+}
+
+// Scripts told apart by the Unigram trainer, which does not let pieces mix them. Like in SentencePiece, Hiragana and
+// Katakana are merged into Han, and the rest of scripts are merged together.
+const SCRIPTS: [&str; 35] = [
+    r"[\p{Script=Common}--\x{30FC}]",
+    r"\p{Script=Inherited}",
+    r"[\p{Script=Han}\p{Script=Hiragana}\p{Script=Katakana}\x{30FC}]",
+    r"\p{Script=Latin}",
+    r"\p{Script=Greek}",
+    r"\p{Script=Cyrillic}",
+    r"\p{Script=Armenian}",
+    r"\p{Script=Hebrew}",
+    r"\p{Script=Arabic}",
+    r"\p{Script=Syriac}",
+    r"\p{Script=Thaana}",
+    r"\p{Script=Devanagari}",
+    r"\p{Script=Bengali}",
+    r"\p{Script=Gurmukhi}",
+    r"\p{Script=Gujarati}",
+    r"\p{Script=Oriya}",
+    r"\p{Script=Tamil}",
+    r"\p{Script=Telugu}",
+    r"\p{Script=Kannada}",
+    r"\p{Script=Malayalam}",
+    r"\p{Script=Sinhala}",
+    r"\p{Script=Thai}",
+    r"\p{Script=Lao}",
+    r"\p{Script=Tibetan}",
+    r"\p{Script=Myanmar}",
+    r"\p{Script=Georgian}",
+    r"\p{Script=Hangul}",
+    r"\p{Script=Ethiopic}",
+    r"\p{Script=Cherokee}",
+    r"\p{Script=Khmer}",
+    r"\p{Script=Mongolian}",
+    r"\p{Script=Bopomofo}",
+    r"\p{Script=Yi}",
+    r"\p{Script=Javanese}",
+    r"\p{Script=Tifinagh}",
+];
+
+// Generates the ranges of the scripts of `SCRIPTS` along with their indices, sorted by their first character, which
+// are included by `src/unigram/train.rs`
+fn write_scripts(out_dir: &Path) {
+    let mut ranges = Vec::new();
+    for (index, pattern) in SCRIPTS.iter().enumerate() {
+        let hir = regex_syntax::Parser::new().parse(pattern).unwrap();
+        let HirKind::Class(Class::Unicode(class)) = hir.kind() else {
+            unreachable!("{pattern} is a Unicode class");
+        };
+        for range in class.ranges() {
+            ranges.push((range.start(), range.end(), index));
+        }
+    }
+    ranges.sort_unstable();
+    let mut code = format!("static SCRIPTS: [(char, char, u8); {}] = [\n", ranges.len());
+    for (start, end, index) in ranges {
+        writeln!(code, "    ({start:?}, {end:?}, {index}),").unwrap();
+    }
+    code.push_str("];\n");
+    fs::write(out_dir.join("scripts.rs"), code).unwrap();
+}
@@ -0,0 +1,269 @@
+use crate::error::{Error, Result};
+use crate::stream::read_line;
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+mod apply;
+mod learn;
+pub use apply::BpeTokenizer;
+pub use learn::{BpeLearner, count_words_reader};
+
+// Ports of https://github.com/rsennrich/subword-nmt/blob/master/subword_nmt/learn_bpe.py and
+// https://github.com/rsennrich/subword-nmt/blob/master/subword_nmt/apply_bpe.py
+
+// Suffix of the symbols at the end of a word
+const END_OF_WORD: &str = "</w>";
+
+// Header of the codes files written by current versions of subword-nmt
+const VERSION_HEADER: &str = "#version: 0.2";
+
+// Format of a codes file. In version 0.1 (no header), the end of a word is a symbol of its own, while in version 0.2
+// it is attached to the last character of the word.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CodesVersion {
+    V0_1,
+    V0_2,
+}
+
+/// Merge operations learned by BPE, in the order they were learned, as found in subword-nmt `codes` files
+#[derive(Debug, Clone, PartialEq)]
+pub struct BpeCodes {
+    version: CodesVersion,
+    merges: Vec<(String, String)>,
+}
+
+impl BpeCodes {
+    pub fn merges(&self) -> &[(String, String)] {
+        &self.merges
+    }
+
+    /// Loads a codes file written by `learn_bpe.py`, with or without its `#version: 0.2` header
+    pub fn load(codes_file_path: &str) -> Result<Self> {
+        let reader =
+            BufReader::new(File::open(codes_file_path).map_err(|e| Error::io(codes_file_path, e))?);
+        Self::read(reader).map_err(|e| e.with_path(codes_file_path))
+    }
+
+    pub fn read(mut reader: impl BufRead) -> Result<Self> {
+        let mut lines = Vec::new();
+        let mut offset = 0;
+        while let Some(line) = read_line(&mut reader, &mut offset, None)? {
+            lines.push(line);
+        }
+        // Like the original script, trailing empty lines are ignored but empty lines in between are not
+        while lines.last().is_some_and(String::is_empty) {
+            lines.pop();
+        }
+
+        let mut version = CodesVersion::V0_1;
+        let mut first_line_number = 1;
+        if let Some(header) = lines.first().filter(|line| line.starts_with("#version:")) {
+            version = match header.split_whitespace().last() {
+                Some("0.2") => CodesVersion::V0_2,
+                Some("0.1") => CodesVersion::V0_1,
+                _ => {
+                    return Err(Error::InvalidModel {
+                        path: None,
+                        message: format!("unsupported version `{header}`"),
+                    });
+                }
+            };
+            lines.remove(0);
+            first_line_number = 2;
+        }
+
+        let mut merges = Vec::with_capacity(lines.len());
+        for (i, line) in lines.iter().enumerate() {
+            match line
+                .trim_matches([' ', '\r', '\n'])
+                .split(' ')
+                .collect::<Vec<&str>>()[..]
+            {
+                [first, second] => merges.push((first.to_owned(), second.to_owned())),
+                _ => {
+                    return Err(Error::InvalidModel {
+                        path: None,
+                        message: format!("line {}: `{line}`", first_line_number + i),
+                    });
+                }
+            }
+        }
+        Ok(BpeCodes { version, merges })
+    }
+
+    pub fn save(&self, codes_file_path: &str) -> Result<()> {
+        let writer = BufWriter::new(
+            File::create(codes_file_path).map_err(|e| Error::io(codes_file_path, e))?,
+        );
+        self.write(writer).map_err(|e| e.with_path(codes_file_path))
+    }
+
+    /// Writes the codes in the format of `learn_bpe.py`, one merge per line after the version header
+    pub fn write(&self, mut writer: impl Write) -> Result<()> {
+        if self.version == CodesVersion::V0_2 {
+            writeln!(writer, "{VERSION_HEADER}")?;
+        }
+        for (first, second) in &self.merges {
+            writeln!(writer, "{first} {second}")?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+/// Loads a vocabulary file with one `subword count` pair per line, as written by `learn-bpe --write-vocabulary`,
+/// keeping the subwords seen at least `threshold` times
+pub fn load_vocabulary(
+    vocabulary_file_path: &str,
+    threshold: Option<u64>,
+) -> Result<HashSet<String>> {
+    let reader = BufReader::new(
+        File::open(vocabulary_file_path).map_err(|e| Error::io(vocabulary_file_path, e))?,
+    );
+    read_vocabulary(reader, threshold).map_err(|e| e.with_path(vocabulary_file_path))
+}
+
+pub fn read_vocabulary(
+    mut reader: impl BufRead,
+    threshold: Option<u64>,
+) -> Result<HashSet<String>> {
+    let mut vocabulary = HashSet::new();
+    let mut offset = 0;
+    let mut line_number = 0;
+    while let Some(line) = read_line(&mut reader, &mut offset, None)? {
+        line_number += 1;
+        let entry = line.trim_matches([' ', '\r', '\n']).split_once(' ');
+        let Some((subword, count)) = entry
+            .and_then(|(subword, count)| count.parse::<u64>().ok().map(|count| (subword, count)))
+        else {
+            return Err(Error::InvalidModel {
+                path: None,
+                message: format!("line {line_number}: `{line}`"),
+            });
+        };
+        if threshold.is_none_or(|threshold| count >= threshold) {
+            vocabulary.insert(subword.to_owned());
+        }
+    }
+    Ok(vocabulary)
+}
+
+/// Writes the subwords of a vocabulary along with their counts, the most frequent ones first
+pub fn write_vocabulary(counts: &HashMap<String, u64>, mut writer: impl Write) -> Result<()> {
+    let mut entries: Vec<(&String, &u64)> = counts.iter().collect();
+    // Ties are broken alphabetically so that the vocabulary does not depend on the hashing order
+    entries.sort_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then_with(|| a.cmp(b)));
+    for (subword, count) in entries {
+        writeln!(writer, "{subword} {count}")?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+// Words of a line as split by subword-nmt, which only splits on ASCII spaces
+fn words(line: &str) -> impl Iterator<Item = &str> {
+    line.split(' ').filter(|word| !word.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tokenizer::{Detokenizer, Tokenizer};
+
+    // Example of the original BPE paper (Sennrich et al., 2016)
+    const CORPUS: &str = "low low low low low lower lower\n\
+                          newest newest newest newest newest newest widest widest widest\n";
+
+    const CODES: &str = "#version: 0.2\n\
+                         s t</w>\ne st</w>\nl o\nw est</w>\nn e\nne west</w>\nlo w</w>\nw i\nwi d\n\
+                         wid est</w>\nw e\nwe r</w>\nlo wer</w>\n";
+
+    fn codes_file(codes: &BpeCodes) -> String {
+        let mut written = Vec::new();
+        codes.write(&mut written).unwrap();
+        String::from_utf8(written).unwrap()
+    }
+
+    #[test]
+    fn learn_codes() {
+        let codes = BpeLearner::new(100).learn(CORPUS);
+        assert_eq!(codes_file(&codes), CODES);
+        // Merges are learned in order, so fewer merges are a prefix of the rest
+        let codes = BpeLearner::new(4).learn(CORPUS);
+        assert_eq!(codes.merges().len(), 4);
+        assert_eq!(codes.merges()[3], ("w".to_owned(), "est</w>".to_owned()));
+        // Only the pairs seen at least 5 times are merged
+        let codes = BpeLearner::new(100).min_frequency(5).learn(CORPUS);
+        assert_eq!(codes.merges().len(), 7);
+        assert_eq!(
+            BpeLearner::new(100)
+                .learn_reader(CORPUS.as_bytes())
+                .unwrap(),
+            BpeLearner::new(100).learn(CORPUS)
+        );
+    }
+
+    #[test]
+    fn read_codes() {
+        let codes = BpeCodes::read(CODES.as_bytes()).unwrap();
+        assert_eq!(codes, BpeLearner::new(100).learn(CORPUS));
+        // Codes without header are read as version 0.1 and written back as they are
+        let codes = BpeCodes::read("l o\nlo w\nlow </w>\n".as_bytes()).unwrap();
+        assert_eq!(codes.version, CodesVersion::V0_1);
+        assert_eq!(codes_file(&codes), "l o\nlo w\nlow </w>\n");
+        assert!(matches!(
+            BpeCodes::read("#version: 0.2\nl o\nlow\n".as_bytes()),
+            Err(Error::InvalidModel { path: None, message }) if message == "line 3: `low`"
+        ));
+    }
+
+    #[test]
+    fn apply_codes() {
+        let tokenizer = BpeTokenizer::new(&BpeCodes::read(CODES.as_bytes()).unwrap());
+        assert_eq!(
+            tokenizer.apply_line("  lowest newer a  low "),
+            "  lo@@ west ne@@ wer a low \n"
+        );
+        assert_eq!(tokenizer.apply_line(" "), " \n");
+        assert_eq!(
+            tokenizer.tokenize_line("lowest newer"),
+            ["lo@@", "west", "ne@@", "wer"]
+        );
+        assert_eq!(
+            tokenizer.detokenize_line(&tokenizer.tokenize_line("lowest newer")),
+            "lowest newer"
+        );
+        let tokens = tokenizer.tokenize_spans("é lowest");
+        assert_eq!(
+            (tokens[1].text.as_str(), tokens[1].start, tokens[1].end),
+            ("lo@@", 3, 5)
+        );
+        assert_eq!((tokens[2].char_start, tokens[2].char_end), (4, 8));
+        let tokens = tokenizer.tokenize_spans("é lowest\n");
+        assert_eq!(
+            (tokens[2].text.as_str(), tokens[2].start, tokens[2].end),
+            ("west", 5, 9)
+        );
+
+        let tokenizer = tokenizer.separator("##");
+        assert_eq!(tokenizer.apply_line("newer"), "ne## wer\n");
+
+        // Version 0.1 codes have a separate end of word symbol
+        let codes = BpeCodes::read("l o\nlo w\nlow </w>\ne r\n".as_bytes()).unwrap();
+        let tokenizer = BpeTokenizer::new(&codes);
+        assert_eq!(tokenizer.apply_line("low lower"), "low low@@ er\n");
+    }
+
+    #[test]
+    fn apply_codes_with_vocabulary() {
+        let codes = BpeCodes::read(CODES.as_bytes()).unwrap();
+        let vocabulary = read_vocabulary("lo@@ 3\nw@@ 2\nest 2\nne@@ 1\n".as_bytes(), Some(2));
+        let tokenizer = BpeTokenizer::new(&codes).vocabulary(vocabulary.unwrap());
+        // Subwords out of the vocabulary are split back with the merges that built them
+        assert_eq!(
+            tokenizer.apply_line("lowest newest"),
+            "lo@@ w@@ est n@@ e@@ w@@ est\n"
+        );
+        assert!(read_vocabulary("lo@@\n".as_bytes(), None).is_err());
+    }
+}
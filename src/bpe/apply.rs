@@ -0,0 +1,318 @@
+use super::{BpeCodes, CodesVersion, END_OF_WORD, words};
+use crate::error::{Error, Result};
+use crate::stream::map_lines_parallel;
+use crate::tokenizer::{Detokenizer, Token, Tokenizer};
+use rayon::prelude::*;
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::ops::Range;
+
+// Symbol that is not part of any merge
+const UNKNOWN: u32 = u32::MAX;
+
+/// Splits words into subwords with learned BPE merges, like `apply_bpe.py`. Every subword but the last one of a word
+/// is followed by a separator (`@@` by default).
+#[derive(Debug, Clone)]
+pub struct BpeTokenizer {
+    version: CodesVersion,
+    // Identifiers of the symbols found in the merges
+    symbols: HashMap<String, u32>,
+    // Rank and resulting symbol of every merge, where earlier merges take precedence
+    merges: HashMap<(u32, u32), (usize, u32)>,
+    // Pair of symbols every merged symbol was built from, used to split subwords out of the vocabulary
+    splits: HashMap<String, (String, String)>,
+    separator: String,
+    vocabulary: Option<HashSet<String>>,
+}
+
+impl BpeTokenizer {
+    pub fn new(codes: &BpeCodes) -> Self {
+        let mut symbols: HashMap<String, u32> = HashMap::new();
+        let mut id = |symbol: &str| {
+            let next_id = symbols.len() as u32;
+            *symbols.entry(symbol.to_owned()).or_insert(next_id)
+        };
+        let mut merges = HashMap::new();
+        let mut splits = HashMap::new();
+        for (rank, (first, second)) in codes.merges.iter().enumerate() {
+            let merged = format!("{first}{second}");
+            let pair = (id(first), id(second));
+            let merged_id = id(&merged);
+            merges.entry(pair).or_insert((rank, merged_id));
+            splits
+                .entry(merged)
+                .or_insert_with(|| (first.clone(), second.clone()));
+        }
+        BpeTokenizer {
+            version: codes.version,
+            symbols,
+            merges,
+            splits,
+            separator: "@@".to_owned(),
+            vocabulary: None,
+        }
+    }
+
+    pub fn separator(mut self, separator: &str) -> Self {
+        self.separator = separator.to_owned();
+        self
+    }
+
+    /// Only produces subwords found in `vocabulary` (as returned by [`super::load_vocabulary`]), splitting the rest
+    /// back with the merges they were built from, like the `--vocabulary` option of the original script
+    pub fn vocabulary(mut self, vocabulary: HashSet<String>) -> Self {
+        self.vocabulary = Some(vocabulary);
+        self
+    }
+
+    pub fn apply_file(&self, input_file_path: &str, output_file_path: &str) -> Result<()> {
+        let reader =
+            BufReader::new(File::open(input_file_path).map_err(|e| Error::io(input_file_path, e))?);
+        let writer = BufWriter::new(
+            File::create(output_file_path).map_err(|e| Error::io(output_file_path, e))?,
+        );
+        map_lines_parallel(
+            reader,
+            writer,
+            Some(input_file_path),
+            Some(output_file_path),
+            |line| self.apply_line(line),
+        )
+    }
+
+    pub fn apply_reader(&self, reader: impl BufRead, writer: impl Write) -> Result<()> {
+        map_lines_parallel(reader, writer, None, None, |line| self.apply_line(line))
+    }
+
+    pub fn apply(&self, text: &str) -> String {
+        text.par_lines()
+            .map(|line| self.apply_line(line))
+            .collect::<String>()
+    }
+
+    /// Counts the subwords (along with their separators) of words seen a number of times, such as the words of a
+    /// training corpus counted with [`super::count_words_reader`]
+    pub fn subword_counts(&self, word_counts: &HashMap<String, u64>) -> HashMap<String, u64> {
+        let mut counts = HashMap::new();
+        for (word, count) in word_counts {
+            for subword in self.segment(word) {
+                *counts.entry(subword).or_default() += count;
+            }
+        }
+        counts
+    }
+
+    /// Splits the words of a line into subwords, keeping the spaces at its start and end like the original script
+    pub fn apply_line(&self, text: &str) -> String {
+        let text = text.trim_end_matches('\n');
+        let content = text.trim_matches([' ', '\r']);
+        if content.is_empty() {
+            return format!("{text}\n");
+        }
+        let start = text.len() - text.trim_start_matches([' ', '\r']).len();
+        let mut segmented_text = text[..start].to_owned();
+        segmented_text.push_str(&self.segment(content).join(" "));
+        segmented_text.push_str(&text[start + content.len()..]);
+        segmented_text.push('\n');
+        segmented_text
+    }
+
+    // Subwords of every word of a line, followed by the separator unless they end a word
+    fn segment(&self, text: &str) -> Vec<String> {
+        let mut tokens = Vec::new();
+        for word in words(text) {
+            let subwords = self.encode(word);
+            let last = subwords.len() - 1;
+            for (i, subword) in subwords.into_iter().enumerate() {
+                if i < last {
+                    tokens.push(format!("{subword}{}", self.separator));
+                } else {
+                    tokens.push(subword);
+                }
+            }
+        }
+        tokens
+    }
+
+    // Subwords of a word, checked against the vocabulary if there is one
+    fn encode(&self, word: &str) -> Vec<String> {
+        let ranges = self.encode_ranges(word);
+        let subwords: Vec<&str> = ranges.iter().map(|range| &word[range.clone()]).collect();
+        match &self.vocabulary {
+            // Single characters are never split further, like in the original script
+            Some(vocabulary) if word.chars().nth(1).is_some() => {
+                self.check_vocabulary(&subwords, vocabulary)
+            }
+            _ => subwords.into_iter().map(str::to_owned).collect(),
+        }
+    }
+
+    // Byte ranges of the subwords of a word, merging the pair of symbols of the earliest merge at every step
+    fn encode_ranges(&self, word: &str) -> Vec<Range<usize>> {
+        let chars: Vec<(usize, char)> = word.char_indices().collect();
+        if chars.len() == 1 {
+            return vec![Range {
+                start: 0,
+                end: word.len(),
+            }];
+        }
+        let symbol = |name: &str| self.symbols.get(name).copied().unwrap_or(UNKNOWN);
+        let mut buffer = [0; 4];
+        let mut pieces: Vec<(u32, Range<usize>)> = chars
+            .iter()
+            .map(|&(i, ch)| (symbol(ch.encode_utf8(&mut buffer)), i..i + ch.len_utf8()))
+            .collect();
+        match self.version {
+            CodesVersion::V0_1 => pieces.push((symbol(END_OF_WORD), word.len()..word.len())),
+            CodesVersion::V0_2 => {
+                let (last_id, last_range) = pieces.last_mut().unwrap();
+                *last_id = symbol(&format!("{}{END_OF_WORD}", &word[last_range.clone()]));
+            }
+        }
+
+        while pieces.len() > 1 {
+            let best = pieces
+                .windows(2)
+                .filter_map(|pair| self.merges.get(&(pair[0].0, pair[1].0)))
+                .min();
+            let Some(&(rank, merged)) = best else {
+                break;
+            };
+            let mut merged_pieces: Vec<(u32, Range<usize>)> = Vec::with_capacity(pieces.len());
+            let mut i = 0;
+            while i < pieces.len() {
+                let is_best = pieces.get(i + 1).is_some_and(|next| {
+                    self.merges.get(&(pieces[i].0, next.0)) == Some(&(rank, merged))
+                });
+                if is_best {
+                    merged_pieces.push((merged, pieces[i].1.start..pieces[i + 1].1.end));
+                    i += 2;
+                } else {
+                    merged_pieces.push(pieces[i].clone());
+                    i += 1;
+                }
+            }
+            pieces = merged_pieces;
+        }
+
+        // The end of word symbol is not part of the output
+        if pieces.last().is_some_and(|(_, range)| range.is_empty()) {
+            pieces.pop();
+        }
+        pieces.into_iter().map(|(_, range)| range).collect()
+    }
+
+    // Splits the subwords out of the vocabulary (followed by the separator unless they are the last one) back into
+    // the symbols they were merged from, recursively
+    fn check_vocabulary(&self, subwords: &[&str], vocabulary: &HashSet<String>) -> Vec<String> {
+        let mut checked_subwords = Vec::new();
+        let last = subwords.len() - 1;
+        for (i, subword) in subwords.iter().enumerate() {
+            self.split_out_of_vocabulary(subword, i == last, vocabulary, &mut checked_subwords);
+        }
+        checked_subwords
+    }
+
+    fn split_out_of_vocabulary(
+        &self,
+        subword: &str,
+        last: bool,
+        vocabulary: &HashSet<String>,
+        output: &mut Vec<String>,
+    ) {
+        let in_vocabulary = |subword: &str, last: bool| {
+            if last {
+                vocabulary.contains(subword)
+            } else {
+                vocabulary.contains(&format!("{subword}{}", self.separator))
+            }
+        };
+        if in_vocabulary(subword, last) {
+            output.push(subword.to_owned());
+            return;
+        }
+        let split = if last {
+            self.splits
+                .get(&format!("{subword}{END_OF_WORD}"))
+                .map(|(first, second)| {
+                    let second = second.strip_suffix(END_OF_WORD).unwrap_or(second);
+                    (first.as_str(), second)
+                })
+        } else {
+            self.splits
+                .get(subword)
+                .map(|(first, second)| (first.as_str(), second.as_str()))
+        };
+        match split {
+            Some((first, second)) => {
+                self.split_out_of_vocabulary(first, false, vocabulary, output);
+                self.split_out_of_vocabulary(second, last, vocabulary, output);
+            }
+            None => output.push(subword.to_owned()),
+        }
+    }
+}
+
+impl Tokenizer for BpeTokenizer {
+    fn name(&self) -> &str {
+        "bpe"
+    }
+
+    fn tokenize_line(&self, line: &str) -> Vec<String> {
+        self.segment(line.trim_end_matches('\n'))
+    }
+
+    fn tokenize_spans(&self, line: &str) -> Vec<Token> {
+        let line = line.trim_end_matches('\n');
+        let mut tokens = Vec::new();
+        for word in words(line) {
+            let word_start = word.as_ptr() as usize - line.as_ptr() as usize;
+            let mut char_start = line[..word_start].chars().count();
+            let subwords = self.encode(word);
+            let mut start = word_start;
+            let last = subwords.len() - 1;
+            for (i, subword) in subwords.into_iter().enumerate() {
+                let end = start + subword.len();
+                let char_end = char_start + subword.chars().count();
+                let text = if i < last {
+                    format!("{subword}{}", self.separator)
+                } else {
+                    subword
+                };
+                tokens.push(Token {
+                    text,
+                    start,
+                    end,
+                    char_start,
+                    char_end,
+                });
+                start = end;
+                char_start = char_end;
+            }
+        }
+        tokens
+    }
+
+    fn tokenize_reader(&self, reader: &mut dyn BufRead, writer: &mut dyn Write) -> Result<()> {
+        self.apply_reader(reader, writer)
+    }
+}
+
+impl Detokenizer for BpeTokenizer {
+    // Joins the subwords followed by the separator with the next one
+    fn detokenize_line(&self, tokens: &[String]) -> String {
+        let mut text = String::new();
+        for token in tokens {
+            match token.strip_suffix(self.separator.as_str()) {
+                Some(subword) => text.push_str(subword),
+                None => {
+                    text.push_str(token);
+                    text.push(' ');
+                }
+            }
+        }
+        text.truncate(text.trim_end_matches(' ').len());
+        text
+    }
+}
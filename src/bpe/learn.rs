@@ -0,0 +1,209 @@
+use super::{BpeCodes, CodesVersion, END_OF_WORD, words};
+use crate::error::{Error, Result};
+use crate::stream::{fold_lines_parallel, merge_counts};
+use rayon::prelude::*;
+use std::collections::{BinaryHeap, HashMap};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::rc::Rc;
+
+/// Learns BPE merges from tokenized text, like `learn_bpe.py`
+#[derive(Debug, Clone)]
+pub struct BpeLearner {
+    num_merges: usize,
+    min_frequency: u64,
+}
+
+impl BpeLearner {
+    /// Learner of up to `num_merges` merges (the `--symbols` option of the original script)
+    pub fn new(num_merges: usize) -> Self {
+        BpeLearner {
+            num_merges,
+            min_frequency: 2,
+        }
+    }
+
+    /// Stops learning when no pair of symbols is seen at least `min_frequency` times (2 by default)
+    pub fn min_frequency(mut self, min_frequency: u64) -> Self {
+        self.min_frequency = min_frequency;
+        self
+    }
+
+    pub fn learn(&self, text: &str) -> BpeCodes {
+        let counts = text
+            .par_lines()
+            .fold(HashMap::new, |mut counts, line| {
+                count_words(line, &mut counts);
+                counts
+            })
+            .reduce(HashMap::new, merge_counts);
+        self.learn_counts(&counts)
+    }
+
+    pub fn learn_reader(&self, reader: impl BufRead) -> Result<BpeCodes> {
+        Ok(self.learn_counts(&count_words_reader(reader)?))
+    }
+
+    pub fn learn_file(&self, corpus_file_path: &str) -> Result<BpeCodes> {
+        let reader = BufReader::new(
+            File::open(corpus_file_path).map_err(|e| Error::io(corpus_file_path, e))?,
+        );
+        self.learn_reader(reader)
+            .map_err(|e| e.with_path(corpus_file_path))
+    }
+
+    /// Learns the merges from the number of times every word has been seen. At every step, the most frequent pair
+    /// of adjacent symbols is merged, breaking ties by the greatest pair like the original script does.
+    pub fn learn_counts(&self, word_counts: &HashMap<String, u64>) -> BpeCodes {
+        let mut symbols = Symbols::default();
+        // Words as sequences of symbols, starting from their characters with the end of word attached to the last
+        let mut words: Vec<(Vec<u32>, i64)> = word_counts
+            .iter()
+            .filter(|(word, _)| !word.is_empty())
+            .map(|(word, &count)| {
+                let mut chars: Vec<String> = word.chars().map(String::from).collect();
+                chars.last_mut().unwrap().push_str(END_OF_WORD);
+                let word_symbols = chars.iter().map(|ch| symbols.id(ch)).collect();
+                (word_symbols, count as i64)
+            })
+            .collect();
+
+        // Frequency of every pair, along with the words where it may be found
+        let mut stats: HashMap<(u32, u32), i64> = HashMap::new();
+        let mut indices: HashMap<(u32, u32), Vec<usize>> = HashMap::new();
+        for (i, (word, count)) in words.iter().enumerate() {
+            for pair in word.windows(2) {
+                let pair = (pair[0], pair[1]);
+                *stats.entry(pair).or_default() += count;
+                indices.entry(pair).or_default().push(i);
+            }
+        }
+        // Candidates to the most frequent pair, which are discarded when popped if their frequency has changed
+        let mut candidates: BinaryHeap<Candidate> = stats
+            .iter()
+            .map(|(&pair, &count)| symbols.candidate(pair, count))
+            .collect();
+
+        let mut merges = Vec::new();
+        while merges.len() < self.num_merges {
+            let Some(candidate) = candidates.pop() else {
+                break;
+            };
+            if stats.get(&candidate.pair) != Some(&candidate.count) {
+                continue;
+            }
+            if candidate.count < self.min_frequency as i64 {
+                break;
+            }
+            let pair = candidate.pair;
+            merges.push((candidate.first.to_string(), candidate.second.to_string()));
+            let merged = symbols.id(&format!("{}{}", candidate.first, candidate.second));
+
+            let mut word_indices = indices.remove(&pair).unwrap_or_default();
+            word_indices.dedup();
+            let mut changed_pairs = Vec::new();
+            for i in word_indices {
+                let (word, count) = &mut words[i];
+                if !word.windows(2).any(|w| (w[0], w[1]) == pair) {
+                    continue;
+                }
+                for w in word.windows(2) {
+                    *stats.get_mut(&(w[0], w[1])).unwrap() -= *count;
+                    changed_pairs.push((w[0], w[1]));
+                }
+                *word = merge_pair(word, pair, merged);
+                for w in word.windows(2) {
+                    *stats.entry((w[0], w[1])).or_default() += *count;
+                    changed_pairs.push((w[0], w[1]));
+                    if w[0] == merged || w[1] == merged {
+                        let pair_indices = indices.entry((w[0], w[1])).or_default();
+                        if pair_indices.last() != Some(&i) {
+                            pair_indices.push(i);
+                        }
+                    }
+                }
+            }
+            changed_pairs.sort_unstable();
+            changed_pairs.dedup();
+            for changed_pair in changed_pairs {
+                let count = stats[&changed_pair];
+                if count > 0 {
+                    candidates.push(symbols.candidate(changed_pair, count));
+                }
+            }
+        }
+        BpeCodes {
+            version: CodesVersion::V0_2,
+            merges,
+        }
+    }
+}
+
+// Pair of symbols along with its frequency, ordered by frequency and then by the symbols themselves
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct Candidate {
+    count: i64,
+    first: Rc<str>,
+    second: Rc<str>,
+    pair: (u32, u32),
+}
+
+// Symbols seen while learning, identified by their index
+#[derive(Debug, Default)]
+struct Symbols {
+    names: Vec<Rc<str>>,
+    ids: HashMap<Rc<str>, u32>,
+}
+
+impl Symbols {
+    fn id(&mut self, name: &str) -> u32 {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+        let name: Rc<str> = Rc::from(name);
+        let id = self.names.len() as u32;
+        self.names.push(name.clone());
+        self.ids.insert(name, id);
+        id
+    }
+
+    fn candidate(&self, pair: (u32, u32), count: i64) -> Candidate {
+        Candidate {
+            count,
+            first: self.names[pair.0 as usize].clone(),
+            second: self.names[pair.1 as usize].clone(),
+            pair,
+        }
+    }
+}
+
+// Replaces the non-overlapping occurrences of a pair of symbols, from left to right
+fn merge_pair(word: &[u32], pair: (u32, u32), merged: u32) -> Vec<u32> {
+    let mut merged_word = Vec::with_capacity(word.len());
+    let mut i = 0;
+    while i < word.len() {
+        if i + 1 < word.len() && (word[i], word[i + 1]) == pair {
+            merged_word.push(merged);
+            i += 2;
+        } else {
+            merged_word.push(word[i]);
+            i += 1;
+        }
+    }
+    merged_word
+}
+
+/// Counts the words of tokenized text, as learned from by [`BpeLearner::learn_counts`]
+pub fn count_words_reader(reader: impl BufRead) -> Result<HashMap<String, u64>> {
+    fold_lines_parallel(
+        reader,
+        |counts, line| count_words(line, counts),
+        merge_counts,
+    )
+}
+
+fn count_words(line: &str, counts: &mut HashMap<String, u64>) {
+    for word in words(line) {
+        *counts.entry(word.to_owned()).or_default() += 1;
+    }
+}
@@ -0,0 +1,879 @@
+use crate::error::{Error, Result};
+use crate::stream::{STREAMING_CHUNK_LINES, read_line_with_terminator};
+use crate::tokenizer::{Detokenizer, Encoding, Token, Tokenizer};
+use fancy_regex::Regex;
+use rayon::prelude::*;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read};
+use std::ops::Range;
+use std::path::Path;
+use std::sync::LazyLock;
+
+// Pre-tokenization patterns of https://github.com/openai/tiktoken/blob/main/tiktoken_ext/openai_public.py
+const GPT2_PATTERN: &str =
+    r"'(?:[sdmt]|ll|ve|re)| ?\p{L}++| ?\p{N}++| ?[^\s\p{L}\p{N}]++|\s++$|\s+(?!\S)|\s";
+const CL100K_PATTERN: &str = r"'(?i:[sdmt]|ll|ve|re)|[^\r\n\p{L}\p{N}]?+\p{L}++|\p{N}{1,3}+| ?[^\s\p{L}\p{N}]++[\r\n]*+|\s++$|\s*[\r\n]|\s+(?!\S)|\s";
+const O200K_PATTERN: &str = concat!(
+    r"[^\r\n\p{L}\p{N}]?[\p{Lu}\p{Lt}\p{Lm}\p{Lo}\p{M}]*[\p{Ll}\p{Lm}\p{Lo}\p{M}]+(?i:'s|'t|'re|'ve|'m|'ll|'d)?",
+    r"|[^\r\n\p{L}\p{N}]?[\p{Lu}\p{Lt}\p{Lm}\p{Lo}\p{M}]+[\p{Ll}\p{Lm}\p{Lo}\p{M}]*(?i:'s|'t|'re|'ve|'m|'ll|'d)?",
+    r"|\p{N}{1,3}| ?[^\s\p{L}\p{N}]+[\r\n/]*|\s*[\r\n]+|\s+(?!\S)|\s+",
+);
+
+/// Pre-tokenization pattern and special tokens of the encodings of OpenAI models
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ByteBpePreset {
+    /// GPT-2 and `r50k_base` of tiktoken
+    #[value(name = "gpt2", alias = "r50k_base")]
+    Gpt2,
+    /// `p50k_base` of tiktoken, used by Codex models
+    #[value(name = "p50k_base")]
+    P50kBase,
+    /// `cl100k_base` of tiktoken, used by GPT-3.5 and GPT-4
+    #[value(name = "cl100k_base")]
+    Cl100kBase,
+    /// `o200k_base` of tiktoken, used by GPT-4o
+    #[value(name = "o200k_base")]
+    O200kBase,
+}
+
+impl ByteBpePreset {
+    pub fn pattern(&self) -> &'static str {
+        match self {
+            ByteBpePreset::Gpt2 | ByteBpePreset::P50kBase => GPT2_PATTERN,
+            ByteBpePreset::Cl100kBase => CL100K_PATTERN,
+            ByteBpePreset::O200kBase => O200K_PATTERN,
+        }
+    }
+
+    pub fn special_tokens(&self) -> &'static [(&'static str, u32)] {
+        match self {
+            ByteBpePreset::Gpt2 | ByteBpePreset::P50kBase => &[("<|endoftext|>", 50256)],
+            ByteBpePreset::Cl100kBase => &[
+                ("<|endoftext|>", 100257),
+                ("<|fim_prefix|>", 100258),
+                ("<|fim_middle|>", 100259),
+                ("<|fim_suffix|>", 100260),
+                ("<|endofprompt|>", 100276),
+            ],
+            ByteBpePreset::O200kBase => &[("<|endoftext|>", 199999), ("<|endofprompt|>", 200018)],
+        }
+    }
+
+    /// Preset of a model with as many tokens as the files published for it, if any
+    pub fn detect(model: &ByteBpeModel) -> Option<Self> {
+        match model.len() {
+            // The `vocab.json` of GPT-2 includes `<|endoftext|>`, but rank files do not
+            50256 | 50257 => Some(ByteBpePreset::Gpt2),
+            50280 | 50281 => Some(ByteBpePreset::P50kBase),
+            100256 => Some(ByteBpePreset::Cl100kBase),
+            199998 => Some(ByteBpePreset::O200kBase),
+            _ => None,
+        }
+    }
+}
+
+// Characters that stand for every byte in the tokens of GPT-2 files, where printable Latin-1 characters stand for
+// their own byte and the rest of bytes are shifted to U+0100 onwards so that tokens are readable
+static BYTE_CHARS: LazyLock<[char; 256]> = LazyLock::new(|| {
+    let mut chars = ['\0'; 256];
+    let mut shifted = 0;
+    for byte in 0..=u8::MAX {
+        chars[byte as usize] = if matches!(byte, b'!'..=b'~' | 0xa1..=0xac | 0xae..=0xff) {
+            byte as char
+        } else {
+            shifted += 1;
+            char::from_u32(0xff + shifted).unwrap()
+        };
+    }
+    chars
+});
+
+static CHAR_BYTES: LazyLock<HashMap<char, u8>> = LazyLock::new(|| {
+    BYTE_CHARS
+        .iter()
+        .enumerate()
+        .map(|(byte, &ch)| (ch, byte as u8))
+        .collect()
+});
+
+/// Bytes written with the characters of GPT-2 files, such as `Ġ` for a space
+pub(crate) fn bytes_to_chars(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|&byte| BYTE_CHARS[byte as usize])
+        .collect()
+}
+
+/// Bytes of text written with the characters of GPT-2 files, or `None` if a character does not stand for a byte
+pub(crate) fn chars_to_bytes(text: &str) -> Option<Vec<u8>> {
+    text.chars()
+        .map(|ch| CHAR_BYTES.get(&ch).copied())
+        .collect()
+}
+
+/// Tokens of a byte-level BPE model along with the way they are merged, either from the `vocab.json` and
+/// `merges.txt` files of GPT-2 or from a tiktoken rank file, where the id of a token also is the priority of the merge
+/// that produces it
+#[derive(Debug, Clone)]
+pub struct ByteBpeModel {
+    ids: HashMap<Vec<u8>, u32>,
+    tokens: HashMap<u32, Vec<u8>>,
+    byte_ids: [u32; 256],
+    // Priority and result of every pair of tokens that is merged, or `None` when merges are ranked by the ids of
+    // their results
+    merges: Option<HashMap<(u32, u32), (u32, u32)>>,
+}
+
+impl ByteBpeModel {
+    /// Model with the given ids of tokens, which must include every single byte, and the merges of pairs of tokens
+    /// from the highest priority to the lowest one. Without merges, tokens are merged in the order of their ids.
+    pub fn new(
+        ids: HashMap<Vec<u8>, u32>,
+        merges: Option<Vec<(Vec<u8>, Vec<u8>)>>,
+    ) -> Result<Self> {
+        let mut byte_ids = [0; 256];
+        for byte in 0..=u8::MAX {
+            byte_ids[byte as usize] =
+                *ids.get([byte].as_slice())
+                    .ok_or_else(|| Error::InvalidModel {
+                        path: None,
+                        message: format!("no token for byte 0x{byte:02X}"),
+                    })?;
+        }
+        let merges = match merges {
+            Some(merges) => {
+                let mut merge_ranks = HashMap::with_capacity(merges.len());
+                for (rank, (left, right)) in merges.into_iter().enumerate() {
+                    let merged = [left.as_slice(), right.as_slice()].concat();
+                    let (Some(&left_id), Some(&right_id), Some(&merged_id)) =
+                        (ids.get(&left), ids.get(&right), ids.get(&merged))
+                    else {
+                        return Err(Error::InvalidModel {
+                            path: None,
+                            message: format!(
+                                "merge `{} {}` has tokens that are not in the vocabulary",
+                                bytes_to_chars(&left),
+                                bytes_to_chars(&right)
+                            ),
+                        });
+                    };
+                    merge_ranks
+                        .entry((left_id, right_id))
+                        .or_insert((rank as u32, merged_id));
+                }
+                Some(merge_ranks)
+            }
+            None => None,
+        };
+        Ok(ByteBpeModel {
+            tokens: ids.iter().map(|(token, &id)| (id, token.clone())).collect(),
+            ids,
+            byte_ids,
+            merges,
+        })
+    }
+
+    /// Loads a tiktoken rank file, or a GPT-2 `vocab.json` file whose `merges.txt` is in the same directory
+    pub fn load(model_file_path: &str) -> Result<Self> {
+        let path = Path::new(model_file_path);
+        if path
+            .extension()
+            .is_some_and(|extension| extension == "json")
+        {
+            let merges_file_path = path.with_file_name("merges.txt");
+            Self::load_gpt2(model_file_path, &merges_file_path.to_string_lossy())
+        } else {
+            Self::load_tiktoken(model_file_path)
+        }
+    }
+
+    /// Loads a rank file of tiktoken, such as `cl100k_base.tiktoken`
+    pub fn load_tiktoken(ranks_file_path: &str) -> Result<Self> {
+        let reader =
+            BufReader::new(File::open(ranks_file_path).map_err(|e| Error::io(ranks_file_path, e))?);
+        Self::read_tiktoken(reader).map_err(|e| e.with_path(ranks_file_path))
+    }
+
+    /// Reads the lines of a rank file, made of every token encoded in base64 followed by a space and its id
+    pub fn read_tiktoken(reader: impl BufRead) -> Result<Self> {
+        let mut ids = HashMap::new();
+        for (i, line) in reader.lines().enumerate() {
+            let line = line?;
+            if line.is_empty() {
+                continue;
+            }
+            let invalid = || Error::InvalidModel {
+                path: None,
+                message: format!("invalid rank on line {}", i + 1),
+            };
+            let (token, id) = line.split_once(' ').ok_or_else(invalid)?;
+            let token = decode_base64(token).ok_or_else(invalid)?;
+            ids.insert(token, id.parse().map_err(|_| invalid())?);
+        }
+        Self::new(ids, None)
+    }
+
+    /// Loads the `vocab.json` and `merges.txt` files of GPT-2 and the models that share their format
+    pub fn load_gpt2(vocab_file_path: &str, merges_file_path: &str) -> Result<Self> {
+        let vocab_reader =
+            BufReader::new(File::open(vocab_file_path).map_err(|e| Error::io(vocab_file_path, e))?);
+        let ids = read_gpt2_vocab(vocab_reader).map_err(|e| e.with_path(vocab_file_path))?;
+        let merges_reader = BufReader::new(
+            File::open(merges_file_path).map_err(|e| Error::io(merges_file_path, e))?,
+        );
+        let merges = read_gpt2_merges(merges_reader).map_err(|e| e.with_path(merges_file_path))?;
+        Self::new(ids, Some(merges)).map_err(|e| e.with_path(merges_file_path))
+    }
+
+    pub fn read_gpt2(vocab_reader: impl Read, merges_reader: impl BufRead) -> Result<Self> {
+        Self::new(
+            read_gpt2_vocab(vocab_reader)?,
+            Some(read_gpt2_merges(merges_reader)?),
+        )
+    }
+
+    /// Number of tokens, not counting special tokens
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    pub fn id(&self, token: &[u8]) -> Option<u32> {
+        self.ids.get(token).copied()
+    }
+
+    pub fn token(&self, id: u32) -> Option<&[u8]> {
+        self.tokens.get(&id).map(Vec::as_slice)
+    }
+
+    // Priority and result of merging the adjacent parts of `piece` that start at `left` and `right` and end at `end`
+    fn merge(
+        &self,
+        piece: &[u8],
+        left: (usize, u32),
+        right: (usize, u32),
+        end: usize,
+    ) -> Option<(u32, u32)> {
+        match &self.merges {
+            Some(merges) => merges.get(&(left.1, right.1)).copied(),
+            None => self.id(&piece[left.0..end]).map(|id| (id, id)),
+        }
+    }
+}
+
+fn read_gpt2_vocab(reader: impl Read) -> Result<HashMap<Vec<u8>, u32>> {
+    let invalid = |message: String| Error::InvalidModel {
+        path: None,
+        message,
+    };
+    let vocab: serde_json::Value =
+        serde_json::from_reader(reader).map_err(|e| invalid(e.to_string()))?;
+    let vocab = vocab
+        .as_object()
+        .ok_or_else(|| invalid("the vocabulary is not a JSON object".to_owned()))?;
+    vocab
+        .iter()
+        .map(|(token, id)| {
+            let id = id
+                .as_u64()
+                .and_then(|id| u32::try_from(id).ok())
+                .ok_or_else(|| invalid(format!("invalid id of token `{token}`")))?;
+            // Tokens such as `<|endoftext|>` are only made of characters that stand for their own bytes
+            let bytes = chars_to_bytes(token).unwrap_or_else(|| token.as_bytes().to_vec());
+            Ok((bytes, id))
+        })
+        .collect()
+}
+
+fn read_gpt2_merges(reader: impl BufRead) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+    let mut merges = Vec::new();
+    for (i, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.is_empty() || (i == 0 && line.starts_with("#version")) {
+            continue;
+        }
+        let merge = line
+            .split_once(' ')
+            .and_then(|(left, right)| Some((chars_to_bytes(left)?, chars_to_bytes(right)?)))
+            .ok_or_else(|| Error::InvalidModel {
+                path: None,
+                message: format!("invalid merge on line {}", i + 1),
+            })?;
+        merges.push(merge);
+    }
+    Ok(merges)
+}
+
+// Decodes standard base64 with padding, as found in rank files
+pub(crate) fn decode_base64(text: &str) -> Option<Vec<u8>> {
+    let value = |byte: u8| match byte {
+        b'A'..=b'Z' => Some(byte - b'A'),
+        b'a'..=b'z' => Some(byte - b'a' + 26),
+        b'0'..=b'9' => Some(byte - b'0' + 52),
+        b'+' => Some(62),
+        b'/' => Some(63),
+        _ => None,
+    };
+    let text = text.as_bytes();
+    if !text.len().is_multiple_of(4) {
+        return None;
+    }
+    let mut bytes = Vec::with_capacity(text.len() / 4 * 3);
+    for (i, quad) in text.chunks_exact(4).enumerate() {
+        let padding = quad.iter().rev().take_while(|&&byte| byte == b'=').count();
+        if padding > 2 || (padding > 0 && i != text.len() / 4 - 1) {
+            return None;
+        }
+        let mut bits = 0u32;
+        for &byte in &quad[..4 - padding] {
+            bits = (bits << 6) | u32::from(value(byte)?);
+        }
+        bits <<= 6 * padding;
+        bytes.extend_from_slice(&bits.to_be_bytes()[1..4 - padding]);
+    }
+    Some(bytes)
+}
+
+/// Configuration of a [`ByteBpeTokenizer`], whose pattern and special tokens are those of the preset of the model
+/// unless they are given
+#[derive(Debug, Clone, Default)]
+pub struct ByteBpeTokenizerBuilder {
+    preset: Option<ByteBpePreset>,
+    pattern: Option<String>,
+    special_tokens: Option<Vec<(String, u32)>>,
+}
+
+impl ByteBpeTokenizerBuilder {
+    /// Preset of the model, which is detected from its number of tokens by default, or else GPT-2
+    pub fn preset(mut self, preset: ByteBpePreset) -> Self {
+        self.preset = Some(preset);
+        self
+    }
+
+    /// Regular expression splitting text into the pieces whose bytes are merged
+    pub fn pattern(mut self, pattern: &str) -> Self {
+        self.pattern = Some(pattern.to_owned());
+        self
+    }
+
+    /// Tokens found in text as they are, along with their ids
+    pub fn special_tokens(mut self, special_tokens: Vec<(String, u32)>) -> Self {
+        self.special_tokens = Some(special_tokens);
+        self
+    }
+
+    pub fn build(self, model: ByteBpeModel) -> Result<ByteBpeTokenizer> {
+        let preset = self
+            .preset
+            .or_else(|| ByteBpePreset::detect(&model))
+            .unwrap_or(ByteBpePreset::Gpt2);
+        let pattern = self.pattern.as_deref().unwrap_or(preset.pattern());
+        let pattern = Regex::new(pattern).map_err(|e| Error::InvalidModel {
+            path: None,
+            message: format!("invalid pre-tokenization pattern `{pattern}`: {e}"),
+        })?;
+        let special_tokens: HashMap<String, u32> = match self.special_tokens {
+            Some(special_tokens) => special_tokens.into_iter().collect(),
+            None => preset
+                .special_tokens()
+                .iter()
+                .map(|&(token, id)| (token.to_owned(), id))
+                .collect(),
+        };
+        // The longest special tokens are found first
+        let mut alternatives: Vec<&str> = special_tokens.keys().map(String::as_str).collect();
+        alternatives.sort_unstable_by_key(|token| (std::cmp::Reverse(token.len()), *token));
+        let special_pattern = (!alternatives.is_empty()).then(|| {
+            let alternatives: Vec<String> = alternatives
+                .iter()
+                .map(|token| regex::escape(token))
+                .collect();
+            regex::Regex::new(&alternatives.join("|")).unwrap()
+        });
+        Ok(ByteBpeTokenizer {
+            special_ids: special_tokens
+                .iter()
+                .map(|(token, &id)| (id, token.clone()))
+                .collect(),
+            model,
+            pattern,
+            special_pattern,
+            special_tokens,
+        })
+    }
+}
+
+/// Byte-level BPE tokenizer of GPT-2 and the models of tiktoken, which splits text into pieces with a regular
+/// expression and then merges the bytes of every piece into tokens
+#[derive(Debug, Clone)]
+pub struct ByteBpeTokenizer {
+    model: ByteBpeModel,
+    pattern: Regex,
+    special_pattern: Option<regex::Regex>,
+    special_tokens: HashMap<String, u32>,
+    special_ids: HashMap<u32, String>,
+}
+
+impl ByteBpeTokenizer {
+    pub fn builder() -> ByteBpeTokenizerBuilder {
+        ByteBpeTokenizerBuilder::default()
+    }
+
+    pub fn model(&self) -> &ByteBpeModel {
+        &self.model
+    }
+
+    pub fn special_tokens(&self) -> &HashMap<String, u32> {
+        &self.special_tokens
+    }
+
+    /// Splits text into tokens and returns their ids along with their offsets into `text`. Special tokens are found
+    /// as they are, and tokens are shown with the characters of GPT-2 files, such as `Ġworld`. Tokens may split the
+    /// bytes of a character, whose char offsets then span the whole character.
+    pub fn encode(&self, text: &str) -> Encoding {
+        let mut pieces = Vec::new();
+        self.encode_pieces(text, true, &mut pieces);
+
+        let mut char_offsets = vec![0; text.len() + 1];
+        let mut char_offset = 0;
+        for (position, offset) in char_offsets.iter_mut().enumerate() {
+            if text.is_char_boundary(position) {
+                *offset = char_offset;
+                char_offset += 1;
+            } else {
+                *offset = char_offset - 1;
+            }
+        }
+        let char_end = |end: usize| {
+            if text.is_char_boundary(end) {
+                char_offsets[end]
+            } else {
+                char_offsets[end] + 1
+            }
+        };
+
+        let mut encoding = Encoding::default();
+        for (range, id) in pieces {
+            let text = match self.special_ids.get(&id) {
+                Some(token) if token.as_str() == &text[range.clone()] => token.clone(),
+                _ => bytes_to_chars(&text.as_bytes()[range.clone()]),
+            };
+            encoding.ids.push(id);
+            encoding.tokens.push(Token {
+                text,
+                start: range.start,
+                end: range.end,
+                char_start: char_offsets[range.start],
+                char_end: char_end(range.end),
+            });
+        }
+        encoding
+    }
+
+    /// Encodes every text in parallel, keeping their order
+    pub fn encode_batch(&self, texts: &[&str]) -> Vec<Encoding> {
+        texts.par_iter().map(|text| self.encode(text)).collect()
+    }
+
+    /// Ids of the tokens of text, finding special tokens as they are
+    pub fn encode_ids(&self, text: &str) -> Vec<u32> {
+        let mut pieces = Vec::new();
+        self.encode_pieces(text, true, &mut pieces);
+        pieces.into_iter().map(|(_, id)| id).collect()
+    }
+
+    /// Ids of the tokens of text, where special tokens are ordinary text
+    pub fn encode_ordinary(&self, text: &str) -> Vec<u32> {
+        let mut pieces = Vec::new();
+        self.encode_pieces(text, false, &mut pieces);
+        pieces.into_iter().map(|(_, id)| id).collect()
+    }
+
+    /// Number of tokens of text, finding special tokens as they are
+    pub fn count(&self, text: &str) -> usize {
+        self.encode_ids(text).len()
+    }
+
+    /// Counts the tokens of the text of `reader` in parallel, giving the same count as [`Self::count`] on the whole
+    /// text with the patterns of the presets. Text is only split before the lines that start with a letter or a number
+    /// and follow a line ending in a single line break, which the patterns never join to the text around it.
+    pub fn count_reader(&self, mut reader: impl BufRead) -> Result<usize> {
+        let mut count = 0;
+        let mut offset = 0;
+        let mut segment = String::new();
+        loop {
+            let mut segments = Vec::new();
+            let mut lines = 0;
+            let mut end = false;
+            while lines < STREAMING_CHUNK_LINES {
+                let Some(line) = read_line_with_terminator(&mut reader, &mut offset, None)? else {
+                    end = true;
+                    break;
+                };
+                if line.starts_with(char::is_alphanumeric) && ends_with_single_line_break(&segment)
+                {
+                    segments.push(std::mem::take(&mut segment));
+                }
+                segment.push_str(&line);
+                lines += 1;
+            }
+            if end {
+                segments.push(std::mem::take(&mut segment));
+            }
+            count += segments
+                .par_iter()
+                .map(|segment| self.count(segment))
+                .sum::<usize>();
+            if end {
+                return Ok(count);
+            }
+        }
+    }
+
+    pub fn count_file(&self, input_file_path: &str) -> Result<usize> {
+        let reader =
+            BufReader::new(File::open(input_file_path).map_err(|e| Error::io(input_file_path, e))?);
+        self.count_reader(reader)
+            .map_err(|e| e.with_path(input_file_path))
+    }
+
+    /// Bytes of the tokens with the given ids, ignoring the ids that are not in the model
+    pub fn decode_bytes(&self, ids: &[u32]) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        for id in ids {
+            if let Some(token) = self.special_ids.get(id) {
+                bytes.extend_from_slice(token.as_bytes());
+            } else if let Some(token) = self.model.token(*id) {
+                bytes.extend_from_slice(token);
+            }
+        }
+        bytes
+    }
+
+    /// Text of the tokens with the given ids, where invalid UTF-8 is replaced by `�`
+    pub fn decode(&self, ids: &[u32]) -> String {
+        String::from_utf8_lossy(&self.decode_bytes(ids)).into_owned()
+    }
+
+    // Byte ranges of the tokens of text along with their ids
+    fn encode_pieces(&self, text: &str, special: bool, pieces: &mut Vec<(Range<usize>, u32)>) {
+        let mut start = 0;
+        if special && let Some(special_pattern) = &self.special_pattern {
+            for special_match in special_pattern.find_iter(text) {
+                self.encode_ordinary_pieces(text, start..special_match.start(), pieces);
+                pieces.push((
+                    special_match.range(),
+                    self.special_tokens[special_match.as_str()],
+                ));
+                start = special_match.end();
+            }
+        }
+        self.encode_ordinary_pieces(text, start..text.len(), pieces);
+    }
+
+    // Splits `text[range]` with the pattern, keeping the text between matches as pieces too, and merges the bytes of
+    // every piece. Text left after the backtracking limit of the pattern is exceeded is a single piece.
+    fn encode_ordinary_pieces(
+        &self,
+        text: &str,
+        range: Range<usize>,
+        pieces: &mut Vec<(Range<usize>, u32)>,
+    ) {
+        let segment = &text[range.clone()];
+        let mut end = 0;
+        for piece_match in self.pattern.find_iter(segment) {
+            let Ok(piece_match) = piece_match else {
+                break;
+            };
+            if piece_match.start() > end {
+                self.merge(
+                    text,
+                    range.start + end..range.start + piece_match.start(),
+                    pieces,
+                );
+            }
+            self.merge(
+                text,
+                range.start + piece_match.start()..range.start + piece_match.end(),
+                pieces,
+            );
+            end = piece_match.end();
+        }
+        if end < segment.len() {
+            self.merge(text, range.start + end..range.end, pieces);
+        }
+    }
+
+    // Merges the bytes of `text[range]` into tokens, always merging the pair of adjacent tokens with the highest
+    // priority first, and the leftmost one among equals
+    fn merge(&self, text: &str, range: Range<usize>, pieces: &mut Vec<(Range<usize>, u32)>) {
+        let piece = &text.as_bytes()[range.clone()];
+        if piece.is_empty() {
+            return;
+        }
+        if self.model.merges.is_none()
+            && let Some(id) = self.model.id(piece)
+        {
+            pieces.push((range, id));
+            return;
+        }
+        // Start and id of every part, along with the priority and result of merging it with the next part
+        let mut parts: Vec<(usize, u32)> = piece
+            .iter()
+            .enumerate()
+            .map(|(i, &byte)| (i, self.model.byte_ids[byte as usize]))
+            .collect();
+        let end =
+            |parts: &[(usize, u32)], i: usize| parts.get(i + 1).map_or(piece.len(), |part| part.0);
+        let mut merges: Vec<Option<(u32, u32)>> = (0..parts.len() - 1)
+            .map(|i| {
+                self.model
+                    .merge(piece, parts[i], parts[i + 1], end(&parts, i + 1))
+            })
+            .collect();
+        while let Some((i, merged_id)) = merges
+            .iter()
+            .enumerate()
+            .filter_map(|(i, merge)| merge.map(|(rank, id)| (rank, i, id)))
+            .min()
+            .map(|(_, i, id)| (i, id))
+        {
+            parts[i].1 = merged_id;
+            parts.remove(i + 1);
+            merges.remove(i);
+            if i > 0 {
+                merges[i - 1] = self
+                    .model
+                    .merge(piece, parts[i - 1], parts[i], end(&parts, i));
+            }
+            if i < merges.len() {
+                merges[i] = self
+                    .model
+                    .merge(piece, parts[i], parts[i + 1], end(&parts, i + 1));
+            }
+        }
+        for (i, &(start, id)) in parts.iter().enumerate() {
+            pieces.push((range.start + start..range.start + end(&parts, i), id));
+        }
+    }
+}
+
+fn ends_with_single_line_break(text: &str) -> bool {
+    let mut chars = text.chars().rev();
+    chars.next() == Some('\n') && !chars.next().is_some_and(char::is_whitespace)
+}
+
+impl Tokenizer for ByteBpeTokenizer {
+    fn name(&self) -> &str {
+        "byte_bpe"
+    }
+
+    fn tokenize_line(&self, line: &str) -> Vec<String> {
+        self.encode(line)
+            .tokens
+            .into_iter()
+            .map(|token| token.text)
+            .collect()
+    }
+
+    fn tokenize_spans(&self, line: &str) -> Vec<Token> {
+        self.encode(line).tokens
+    }
+}
+
+impl Detokenizer for ByteBpeTokenizer {
+    fn detokenize_line(&self, tokens: &[String]) -> String {
+        let mut bytes = Vec::new();
+        for token in tokens {
+            match chars_to_bytes(token) {
+                Some(token_bytes) if !self.special_tokens.contains_key(token) => {
+                    bytes.extend_from_slice(&token_bytes)
+                }
+                _ => bytes.extend_from_slice(token.as_bytes()),
+            }
+        }
+        String::from_utf8_lossy(&bytes).into_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Tokens merged by the test models, whose ids follow those of the bytes
+    const MERGES: [(&str, &str); 7] = [
+        ("h", "e"),
+        ("l", "l"),
+        ("ll", "o"),
+        (" ", "w"),
+        ("o", "r"),
+        (" w", "or"),
+        ("l", "d"),
+    ];
+
+    fn tiktoken_model() -> ByteBpeModel {
+        let mut ranks = String::new();
+        for byte in 0..=u8::MAX {
+            ranks.push_str(&format!("{} {byte}\n", encode_base64(&[byte])));
+        }
+        for (i, (left, right)) in MERGES.iter().enumerate() {
+            ranks.push_str(&format!(
+                "{} {}\n",
+                encode_base64(format!("{left}{right}").as_bytes()),
+                256 + i
+            ));
+        }
+        ByteBpeModel::read_tiktoken(ranks.as_bytes()).unwrap()
+    }
+
+    fn gpt2_model() -> ByteBpeModel {
+        let mut vocab = serde_json::Map::new();
+        for byte in 0..=u8::MAX {
+            vocab.insert(bytes_to_chars(&[byte]), byte.into());
+        }
+        let mut merges = "#version: 0.2\n".to_owned();
+        for (i, (left, right)) in MERGES.iter().enumerate() {
+            let (left, right) = (
+                bytes_to_chars(left.as_bytes()),
+                bytes_to_chars(right.as_bytes()),
+            );
+            vocab.insert(format!("{left}{right}"), (256 + i).into());
+            merges.push_str(&format!("{left} {right}\n"));
+        }
+        let vocab = serde_json::Value::Object(vocab).to_string();
+        ByteBpeModel::read_gpt2(vocab.as_bytes(), merges.as_bytes()).unwrap()
+    }
+
+    fn encode_base64(bytes: &[u8]) -> String {
+        const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+        let mut text = String::new();
+        for chunk in bytes.chunks(3) {
+            let bits = chunk
+                .iter()
+                .fold(0u32, |bits, &byte| (bits << 8) | u32::from(byte))
+                << (8 * (3 - chunk.len()));
+            for i in 0..4 {
+                if i <= chunk.len() {
+                    text.push(ALPHABET[(bits >> (18 - 6 * i) & 63) as usize] as char);
+                } else {
+                    text.push('=');
+                }
+            }
+        }
+        text
+    }
+
+    #[test]
+    fn encode_and_decode() {
+        for model in [tiktoken_model(), gpt2_model()] {
+            let tokenizer = ByteBpeTokenizer::builder()
+                .special_tokens(vec![("<|endoftext|>".to_owned(), 300)])
+                .build(model)
+                .unwrap();
+            let text = "hello world<|endoftext|>é";
+            let encoding = tokenizer.encode(text);
+            assert_eq!(encoding.ids, [256, 258, 261, 262, 300, 0xc3, 0xa9]);
+            let tokens: Vec<&str> = encoding
+                .tokens
+                .iter()
+                .map(|token| token.text.as_str())
+                .collect();
+            assert_eq!(
+                tokens,
+                ["he", "llo", "Ġwor", "ld", "<|endoftext|>", "Ã", "©"]
+            );
+            let spans: Vec<(usize, usize, usize, usize)> = encoding.tokens[4..]
+                .iter()
+                .map(|t| (t.start, t.end, t.char_start, t.char_end))
+                .collect();
+            assert_eq!(
+                spans,
+                [(11, 24, 11, 24), (24, 25, 24, 25), (25, 26, 24, 25)]
+            );
+            assert_eq!(tokenizer.decode(&encoding.ids), text);
+            assert_eq!(
+                tokenizer.detokenize_line(&tokenizer.tokenize_line(text)),
+                text
+            );
+            assert_eq!(tokenizer.count(text), 7);
+            assert_eq!(tokenizer.encode_ordinary("<|endoftext|>").len(), 13);
+        }
+    }
+
+    #[test]
+    fn presets() {
+        let model = tiktoken_model();
+        assert_eq!(ByteBpePreset::detect(&model), None);
+        let pieces = |preset: ByteBpePreset, text: &str| {
+            let tokenizer = ByteBpeTokenizer::builder()
+                .preset(preset)
+                .build(model.clone())
+                .unwrap();
+            tokenizer
+                .pattern
+                .find_iter(text)
+                .map(|m| m.unwrap().as_str().to_owned())
+                .collect::<Vec<_>>()
+        };
+        let text = "I'm  12345 words!!\n\n  Hello";
+        assert_eq!(
+            pieces(ByteBpePreset::Gpt2, text),
+            ["I", "'m", " ", " 12345", " words", "!!", "\n\n ", " Hello"]
+        );
+        assert_eq!(
+            pieces(ByteBpePreset::Cl100kBase, text),
+            [
+                "I", "'m", " ", " ", "123", "45", " words", "!!\n\n", " ", " Hello"
+            ]
+        );
+        assert_eq!(
+            pieces(ByteBpePreset::O200kBase, text),
+            [
+                "I'm", " ", " ", "123", "45", " words", "!!\n\n", " ", " Hello"
+            ]
+        );
+    }
+
+    #[test]
+    fn count_reader() {
+        let text = "hello world\nworld \n\nhello\r\nhello\n!! \nworld\nhello";
+        for preset in [
+            ByteBpePreset::Gpt2,
+            ByteBpePreset::Cl100kBase,
+            ByteBpePreset::O200kBase,
+        ] {
+            let tokenizer = ByteBpeTokenizer::builder()
+                .preset(preset)
+                .build(tiktoken_model())
+                .unwrap();
+            assert_eq!(
+                tokenizer.count_reader(text.as_bytes()).unwrap(),
+                tokenizer.count(text)
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_models() {
+        assert!(matches!(
+            ByteBpeModel::read_tiktoken("aGk= 0\n".as_bytes()),
+            Err(Error::InvalidModel { message, .. }) if message == "no token for byte 0x00"
+        ));
+        assert!(matches!(
+            ByteBpeModel::read_tiktoken("aGk 0\n".as_bytes()),
+            Err(Error::InvalidModel { message, .. }) if message == "invalid rank on line 1"
+        ));
+        assert_eq!(decode_base64("aGVsbG8="), Some(b"hello".to_vec()));
+        assert_eq!(decode_base64("aGk=aGk="), None);
+        assert!(
+            ByteBpeTokenizer::builder()
+                .pattern("(")
+                .build(tiktoken_model())
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,120 @@
+use std::fmt;
+
+#[derive(Debug)]
+pub enum Error {
+    /// A protected pattern could not be compiled as a regular expression
+    InvalidPattern {
+        pattern: String,
+        index: usize,
+        source: regex::Error,
+    },
+    /// An I/O operation failed, with the path of the file involved if there is one
+    Io {
+        path: Option<String>,
+        source: std::io::Error,
+    },
+    /// The input is not valid UTF-8 from the given byte offset onwards
+    InvalidUtf8 { offset: usize },
+    /// The given language code does not match any supported language
+    UnsupportedLanguage(String),
+    /// The given tokenizer name does not match any tokenizer of the registry
+    UnsupportedTokenizer(String),
+    /// The given tokenizer needs a model file but none was given
+    MissingModel(String),
+    /// The given tokenizer needs the language of the text but none was given
+    MissingLanguage(String),
+    /// A model file is malformed, with the path of the file if there is one
+    InvalidModel {
+        path: Option<String>,
+        message: String,
+    },
+    /// The thread pool requested for parallel processing could not be created
+    ThreadPool(rayon::ThreadPoolBuildError),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    /// I/O error on the file at `path`
+    pub fn io(path: &str, source: std::io::Error) -> Self {
+        Error::Io {
+            path: Some(path.to_owned()),
+            source,
+        }
+    }
+
+    // Adds the path of a file to the errors of reading or writing it that have none
+    pub(crate) fn with_path(self, path: &str) -> Self {
+        match self {
+            Error::Io { path: None, source } => Error::io(path, source),
+            Error::InvalidModel {
+                path: None,
+                message,
+            } => Error::InvalidModel {
+                path: Some(path.to_owned()),
+                message,
+            },
+            e => e,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidPattern {
+                pattern,
+                index,
+                source,
+            } => write!(
+                f,
+                "invalid protected pattern #{index} `{pattern}`: {source}"
+            ),
+            Error::Io {
+                path: Some(path),
+                source,
+            } => write!(f, "{path}: {source}"),
+            Error::Io { path: None, source } => write!(f, "{source}"),
+            Error::InvalidUtf8 { offset } => {
+                write!(f, "invalid UTF-8 at byte offset {offset}")
+            }
+            Error::UnsupportedLanguage(language) => {
+                write!(f, "unsupported language `{language}`")
+            }
+            Error::UnsupportedTokenizer(name) => write!(f, "unsupported tokenizer `{name}`"),
+            Error::MissingModel(name) => write!(f, "the `{name}` tokenizer requires a model file"),
+            Error::MissingLanguage(name) => write!(f, "the `{name}` tokenizer requires a language"),
+            Error::InvalidModel {
+                path: Some(path),
+                message,
+            } => write!(f, "{path}: invalid model: {message}"),
+            Error::InvalidModel {
+                path: None,
+                message,
+            } => write!(f, "invalid model: {message}"),
+            Error::ThreadPool(source) => write!(f, "could not create thread pool: {source}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::InvalidPattern { source, .. } => Some(source),
+            Error::Io { source, .. } => Some(source),
+            Error::ThreadPool(source) => Some(source),
+            Error::InvalidUtf8 { .. }
+            | Error::UnsupportedLanguage(_)
+            | Error::UnsupportedTokenizer(_)
+            | Error::MissingModel(_)
+            | Error::MissingLanguage(_)
+            | Error::InvalidModel { .. } => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(source: std::io::Error) -> Self {
+        Error::Io { path: None, source }
+    }
+}
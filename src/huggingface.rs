@@ -0,0 +1,659 @@
+use crate::error::{Error, Result};
+use crate::moses::MosesTokenizer;
+use crate::tokenizer::{Detokenizer, Encoding, Token, Tokenizer};
+use decoder::Decoder;
+use model::Model;
+use normalized::NormalizedText;
+use normalizer::Normalizer;
+use post_processor::PostProcessor;
+use pre_tokenizer::PreTokenizer;
+use rayon::prelude::*;
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::{self, BufReader, Read};
+use std::ops::Range;
+mod decoder;
+mod model;
+mod normalized;
+mod normalizer;
+mod post_processor;
+mod pre_tokenizer;
+
+// Port of the pipelines of https://github.com/huggingface/tokenizers, as described by their `tokenizer.json` files
+
+// Result of parsing a component of a pipeline, whose errors are the messages of `Error::InvalidModel`
+type ParseResult<T> = std::result::Result<T, String>;
+
+fn get_type(value: &Value) -> ParseResult<&str> {
+    get_str(value, "type")
+}
+
+fn get_str<'a>(value: &'a Value, name: &str) -> ParseResult<&'a str> {
+    value
+        .get(name)
+        .and_then(Value::as_str)
+        .ok_or_else(|| format!("missing `{name}` string"))
+}
+
+// Boolean option of a component, which is `default` when it is missing or null
+fn get_bool(value: &Value, name: &str, default: bool) -> ParseResult<bool> {
+    match value.get(name) {
+        None | Some(Value::Null) => Ok(default),
+        Some(Value::Bool(option)) => Ok(*option),
+        Some(_) => Err(format!("invalid `{name}` boolean")),
+    }
+}
+
+// Pattern of a component, given either as a string matched as it is or as a regular expression
+fn get_pattern(value: &Value) -> ParseResult<fancy_regex::Regex> {
+    let pattern = value.get("pattern");
+    let (pattern, is_regex) = match (
+        pattern.and_then(|pattern| pattern.get("String")),
+        pattern.and_then(|pattern| pattern.get("Regex")),
+    ) {
+        (Some(Value::String(text)), _) => (regex::escape(text), false),
+        (_, Some(Value::String(regex))) => (regex.clone(), true),
+        _ => return Err("missing pattern".to_owned()),
+    };
+    fancy_regex::Regex::new(&pattern).map_err(|e| match is_regex {
+        true => format!("invalid pattern `{pattern}`: {e}"),
+        false => e.to_string(),
+    })
+}
+
+// Token produced by the pipeline along with the byte range of the text it comes from, and the index of that text
+// unless it is a special token added by the post-processor
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PipelineToken {
+    id: u32,
+    text: String,
+    range: Range<usize>,
+    sequence: Option<usize>,
+}
+
+/// Token added to the vocabulary of a pipeline, which is found in text as it is instead of being produced by the
+/// model
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AddedToken {
+    pub id: u32,
+    pub content: String,
+    /// Only found when not surrounded by the characters of a word
+    pub single_word: bool,
+    /// Takes the whitespace on its left
+    pub lstrip: bool,
+    /// Takes the whitespace on its right
+    pub rstrip: bool,
+    /// Found in normalized text rather than in the original text
+    pub normalized: bool,
+    /// Left out when decoding
+    pub special: bool,
+}
+
+impl AddedToken {
+    fn parse(value: &Value) -> ParseResult<Self> {
+        let id = value
+            .get("id")
+            .and_then(Value::as_u64)
+            .ok_or_else(|| "missing id of added token".to_owned())?;
+        let special = get_bool(value, "special", false)?;
+        Ok(AddedToken {
+            id: id as u32,
+            content: get_str(value, "content")?.to_owned(),
+            single_word: get_bool(value, "single_word", false)?,
+            lstrip: get_bool(value, "lstrip", false)?,
+            rstrip: get_bool(value, "rstrip", false)?,
+            normalized: get_bool(value, "normalized", !special)?,
+            special,
+        })
+    }
+}
+
+// Alternation of the added tokens found in text, the longest first, along with the tokens of the text they match
+#[derive(Debug, Clone)]
+struct AddedTokenPattern {
+    regex: regex::Regex,
+    tokens: HashMap<String, AddedToken>,
+}
+
+impl AddedTokenPattern {
+    fn new(tokens: HashMap<String, AddedToken>) -> Option<Self> {
+        let mut contents: Vec<&str> = tokens.keys().map(String::as_str).collect();
+        contents.sort_unstable_by_key(|content| (std::cmp::Reverse(content.len()), *content));
+        let alternatives: Vec<String> = contents
+            .iter()
+            .map(|content| regex::escape(content))
+            .collect();
+        (!alternatives.is_empty()).then(|| AddedTokenPattern {
+            regex: regex::Regex::new(&alternatives.join("|")).unwrap(),
+            tokens,
+        })
+    }
+
+    // Byte ranges of the added tokens found in text, including the whitespace they take, along with their ids
+    fn find(&self, text: &str) -> Vec<(Range<usize>, u32)> {
+        let is_word_char = |ch: char| ch.is_alphanumeric() || ch == '_';
+        let mut found_tokens = Vec::new();
+        let mut start = 0;
+        let mut last_end = 0;
+        while let Some(found) = self.regex.find_at(text, start) {
+            let token = &self.tokens[found.as_str()];
+            if token.single_word
+                && (text[..found.start()]
+                    .chars()
+                    .next_back()
+                    .is_some_and(is_word_char)
+                    || text[found.end()..].chars().next().is_some_and(is_word_char))
+            {
+                start = found.start() + text[found.start()..].chars().next().unwrap().len_utf8();
+                continue;
+            }
+            let mut range = found.range();
+            if token.lstrip {
+                range.start = last_end + text[last_end..range.start].trim_end().len();
+            }
+            if token.rstrip {
+                range.end = text.len() - text[range.end..].trim_start().len();
+            }
+            start = range.end;
+            last_end = range.end;
+            found_tokens.push((range, token.id));
+        }
+        found_tokens
+    }
+}
+
+/// Tokenizer running the pipeline of a Hugging Face `tokenizer.json` file, which normalizes text, splits it into
+/// pieces with its pre-tokenizer, splits every piece into the tokens of its BPE, WordPiece, Unigram or WordLevel model
+/// and adds the special tokens of its post-processor. Added tokens are found in text as they are, and offsets always
+/// point to the original text.
+#[derive(Debug, Clone)]
+pub struct HuggingFaceTokenizer {
+    normalizer: Option<Normalizer>,
+    pre_tokenizer: Option<PreTokenizer>,
+    model: Model,
+    post_processor: Option<PostProcessor>,
+    decoder: Option<Decoder>,
+    added_tokens: Vec<AddedToken>,
+    added_ids: HashMap<String, u32>,
+    added_contents: HashMap<u32, String>,
+    special_ids: HashSet<u32>,
+    // Added tokens found in the original text and in normalized text
+    added_pattern: Option<AddedTokenPattern>,
+    normalized_added_pattern: Option<AddedTokenPattern>,
+}
+
+impl HuggingFaceTokenizer {
+    pub fn load(tokenizer_file_path: &str) -> Result<Self> {
+        let reader = BufReader::new(
+            File::open(tokenizer_file_path).map_err(|e| Error::io(tokenizer_file_path, e))?,
+        );
+        Self::read(reader).map_err(|e| e.with_path(tokenizer_file_path))
+    }
+
+    pub fn read(reader: impl Read) -> Result<Self> {
+        let value: Value = serde_json::from_reader(reader).map_err(|e| {
+            if e.is_io() {
+                Error::from(io::Error::from(e))
+            } else {
+                Error::InvalidModel {
+                    path: None,
+                    message: e.to_string(),
+                }
+            }
+        })?;
+        Self::parse(&value).map_err(|message| Error::InvalidModel {
+            path: None,
+            message,
+        })
+    }
+
+    fn parse(value: &Value) -> ParseResult<Self> {
+        fn component<T>(
+            value: &Value,
+            name: &str,
+            parse: fn(&Value) -> ParseResult<T>,
+        ) -> ParseResult<Option<T>> {
+            match value.get(name) {
+                None | Some(Value::Null) => Ok(None),
+                Some(component) => parse(component).map(Some),
+            }
+        }
+        let normalizer = component(value, "normalizer", Normalizer::parse)?;
+        let added_tokens: Vec<AddedToken> = match value.get("added_tokens") {
+            Some(Value::Array(added_tokens)) => added_tokens
+                .iter()
+                .map(AddedToken::parse)
+                .collect::<ParseResult<_>>()?,
+            _ => Vec::new(),
+        };
+
+        // Normalized added tokens are found in normalized text, so their contents are normalized as well
+        let mut tokens = HashMap::new();
+        let mut normalized_tokens = HashMap::new();
+        for token in added_tokens
+            .iter()
+            .filter(|token| !token.content.is_empty())
+        {
+            match (&normalizer, token.normalized) {
+                (Some(normalizer), true) => {
+                    let mut content = NormalizedText::new(&token.content, 0);
+                    normalizer.normalize(&mut content);
+                    if !content.is_empty() {
+                        normalized_tokens.insert(content.as_str().to_owned(), token.clone());
+                    }
+                }
+                _ => {
+                    tokens.insert(token.content.clone(), token.clone());
+                }
+            }
+        }
+
+        Ok(HuggingFaceTokenizer {
+            pre_tokenizer: component(value, "pre_tokenizer", PreTokenizer::parse)?,
+            model: Model::parse(
+                value
+                    .get("model")
+                    .ok_or_else(|| "missing model".to_owned())?,
+            )?,
+            post_processor: component(value, "post_processor", PostProcessor::parse)?,
+            decoder: component(value, "decoder", Decoder::parse)?,
+            normalizer,
+            added_ids: added_tokens
+                .iter()
+                .map(|token| (token.content.clone(), token.id))
+                .collect(),
+            added_contents: added_tokens
+                .iter()
+                .map(|token| (token.id, token.content.clone()))
+                .collect(),
+            special_ids: added_tokens
+                .iter()
+                .filter(|token| token.special)
+                .map(|token| token.id)
+                .collect(),
+            added_tokens,
+            added_pattern: AddedTokenPattern::new(tokens),
+            normalized_added_pattern: AddedTokenPattern::new(normalized_tokens),
+        })
+    }
+
+    /// Replaces the pre-tokenizer of the pipeline with the Moses tokenizer, whose tokens are then split by the model.
+    /// This suits pipelines whose pre-tokenizer only splits words, such as the ones of BERT models.
+    pub fn moses_pre_tokenizer(mut self, moses: MosesTokenizer) -> Self {
+        self.pre_tokenizer = Some(PreTokenizer::Moses(Box::new(moses)));
+        self
+    }
+
+    pub fn added_tokens(&self) -> &[AddedToken] {
+        &self.added_tokens
+    }
+
+    /// Id of a token of the model or of an added token
+    pub fn id(&self, token: &str) -> Option<u32> {
+        self.added_ids
+            .get(token)
+            .copied()
+            .or_else(|| self.model.id(token))
+    }
+
+    pub fn token(&self, id: u32) -> Option<&str> {
+        self.added_contents
+            .get(&id)
+            .map(String::as_str)
+            .or_else(|| self.model.token(id))
+    }
+
+    /// Runs the pipeline on a text and returns the ids of its tokens along with their offsets into `text`. The special
+    /// tokens of the post-processor have empty offsets at the beginning of the text.
+    pub fn encode(&self, text: &str) -> Encoding {
+        self.encode_texts(&[text])
+    }
+
+    /// Runs the pipeline on a pair of texts, such as a question and its context, which are joined with the template
+    /// of pairs of the post-processor. The offsets of the tokens of `pair` are offsets into `pair`.
+    pub fn encode_pair(&self, text: &str, pair: &str) -> Encoding {
+        self.encode_texts(&[text, pair])
+    }
+
+    /// Encodes every text in parallel, keeping their order
+    pub fn encode_batch(&self, texts: &[&str]) -> Vec<Encoding> {
+        texts.par_iter().map(|text| self.encode(text)).collect()
+    }
+
+    /// Text of the tokens with the given ids as produced by the decoder of the pipeline, leaving out special tokens
+    /// and the ids that are not in the vocabulary
+    pub fn decode(&self, ids: &[u32]) -> String {
+        let tokens = ids
+            .iter()
+            .filter(|id| !self.special_ids.contains(id))
+            .filter_map(|&id| self.token(id))
+            .map(str::to_owned)
+            .collect();
+        self.decode_tokens(tokens)
+    }
+
+    // Joins tokens with the decoder, or else with spaces
+    fn decode_tokens(&self, tokens: Vec<String>) -> String {
+        match &self.decoder {
+            Some(decoder) => decoder.decode(tokens).concat(),
+            None => tokens.join(" "),
+        }
+    }
+
+    fn encode_texts(&self, texts: &[&str]) -> Encoding {
+        let mut tokens = Vec::new();
+        for (sequence, text) in texts.iter().enumerate() {
+            self.encode_sequence(text, sequence, &mut tokens);
+        }
+        if let Some(post_processor) = &self.post_processor {
+            tokens = post_processor.process(tokens, texts);
+        }
+
+        let char_offsets: Vec<Vec<usize>> = texts.iter().map(|text| char_offsets(text)).collect();
+        let mut encoding = Encoding::default();
+        for token in tokens {
+            let (char_start, char_end) = match token.sequence {
+                Some(sequence) => (
+                    char_offsets[sequence][token.range.start],
+                    char_offsets[sequence][token.range.end],
+                ),
+                None => (0, 0),
+            };
+            encoding.ids.push(token.id);
+            encoding.tokens.push(Token {
+                text: token.text,
+                start: token.range.start,
+                end: token.range.end,
+                char_start,
+                char_end,
+            });
+        }
+        encoding
+    }
+
+    // Tokens of a text, split at the added tokens found in the original text
+    fn encode_sequence(&self, text: &str, sequence: usize, tokens: &mut Vec<PipelineToken>) {
+        let added = self
+            .added_pattern
+            .as_ref()
+            .map_or_else(Vec::new, |pattern| pattern.find(text));
+        let mut start = 0;
+        for (range, id) in added {
+            self.encode_segment(text, start..range.start, sequence, tokens);
+            tokens.push(self.added_token(id, range.clone(), sequence));
+            start = range.end;
+        }
+        self.encode_segment(text, start..text.len(), sequence, tokens);
+    }
+
+    // Tokens of a segment of a text between added tokens, which is normalized and split at the normalized added
+    // tokens found in it
+    fn encode_segment(
+        &self,
+        text: &str,
+        range: Range<usize>,
+        sequence: usize,
+        tokens: &mut Vec<PipelineToken>,
+    ) {
+        if range.is_empty() {
+            return;
+        }
+        let first = range.start == 0;
+        let mut normalized = NormalizedText::new(&text[range.clone()], range.start);
+        if let Some(normalizer) = &self.normalizer {
+            normalizer.normalize(&mut normalized);
+        }
+        let added = self
+            .normalized_added_pattern
+            .as_ref()
+            .map_or_else(Vec::new, |pattern| pattern.find(normalized.as_str()));
+        let mut start = 0;
+        for (added_range, id) in added {
+            let piece = normalized.slice(start..added_range.start);
+            self.encode_piece(piece, first && start == 0, sequence, tokens);
+            tokens.push(self.added_token(
+                id,
+                normalized.original_range(added_range.clone()),
+                sequence,
+            ));
+            start = added_range.end;
+        }
+        let piece = normalized.slice(start..normalized.as_str().len());
+        self.encode_piece(piece, first && start == 0, sequence, tokens);
+    }
+
+    // Tokens of normalized text, which is pre-tokenized and split by the model
+    fn encode_piece(
+        &self,
+        text: NormalizedText,
+        first: bool,
+        sequence: usize,
+        tokens: &mut Vec<PipelineToken>,
+    ) {
+        if text.is_empty() {
+            return;
+        }
+        let pieces = match &self.pre_tokenizer {
+            Some(pre_tokenizer) => pre_tokenizer.pre_tokenize(text, first),
+            None => vec![text],
+        };
+        for piece in pieces.iter().filter(|piece| !piece.is_empty()) {
+            for (range, id) in self.model.tokenize(piece.as_str()) {
+                tokens.push(PipelineToken {
+                    id,
+                    text: self.model.token(id).unwrap_or_default().to_owned(),
+                    range: piece.original_range(range),
+                    sequence: Some(sequence),
+                });
+            }
+        }
+    }
+
+    fn added_token(&self, id: u32, range: Range<usize>, sequence: usize) -> PipelineToken {
+        PipelineToken {
+            id,
+            text: self.added_contents[&id].clone(),
+            range,
+            sequence: Some(sequence),
+        }
+    }
+}
+
+// Char offset of every byte offset of a text, where the offsets inside characters are those of their characters
+fn char_offsets(text: &str) -> Vec<usize> {
+    let mut offsets = Vec::with_capacity(text.len() + 1);
+    for (char_offset, ch) in text.chars().enumerate() {
+        offsets.extend(std::iter::repeat_n(char_offset, ch.len_utf8()));
+    }
+    offsets.push(text.chars().count());
+    offsets
+}
+
+impl Tokenizer for HuggingFaceTokenizer {
+    fn name(&self) -> &str {
+        "huggingface"
+    }
+
+    fn tokenize_line(&self, line: &str) -> Vec<String> {
+        self.encode(line)
+            .tokens
+            .into_iter()
+            .map(|token| token.text)
+            .collect()
+    }
+
+    fn tokenize_spans(&self, line: &str) -> Vec<Token> {
+        self.encode(line).tokens
+    }
+}
+
+impl Detokenizer for HuggingFaceTokenizer {
+    fn detokenize_line(&self, tokens: &[String]) -> String {
+        let tokens = tokens
+            .iter()
+            .filter(|token| {
+                self.added_ids
+                    .get(token.as_str())
+                    .is_none_or(|id| !self.special_ids.contains(id))
+            })
+            .cloned()
+            .collect();
+        self.decode_tokens(tokens)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::moses::Language;
+    use serde_json::json;
+
+    fn bert() -> HuggingFaceTokenizer {
+        let tokenizer = json!({
+            "version": "1.0",
+            "added_tokens": [
+                {"id": 0, "content": "[PAD]", "single_word": false, "lstrip": false, "rstrip": false, "normalized": false, "special": true},
+                {"id": 1, "content": "[UNK]", "single_word": false, "lstrip": false, "rstrip": false, "normalized": false, "special": true},
+                {"id": 2, "content": "[CLS]", "single_word": false, "lstrip": false, "rstrip": false, "normalized": false, "special": true},
+                {"id": 3, "content": "[SEP]", "single_word": false, "lstrip": false, "rstrip": false, "normalized": false, "special": true},
+                {"id": 4, "content": "[MASK]", "single_word": false, "lstrip": true, "rstrip": false, "normalized": false, "special": true},
+            ],
+            "normalizer": {"type": "BertNormalizer", "clean_text": true, "handle_chinese_chars": true, "strip_accents": null, "lowercase": true},
+            "pre_tokenizer": {"type": "BertPreTokenizer"},
+            "post_processor": {"type": "BertProcessing", "sep": ["[SEP]", 3], "cls": ["[CLS]", 2]},
+            "decoder": {"type": "WordPiece", "prefix": "##", "cleanup": true},
+            "model": {
+                "type": "WordPiece",
+                "unk_token": "[UNK]",
+                "continuing_subword_prefix": "##",
+                "max_input_chars_per_word": 100,
+                "vocab": {
+                    "[PAD]": 0, "[UNK]": 1, "[CLS]": 2, "[SEP]": 3, "[MASK]": 4,
+                    "un": 5, "##want": 6, "##ed": 7, ",": 8, "running": 9, "cafe": 10, "is": 11, "open": 12,
+                },
+            },
+        });
+        HuggingFaceTokenizer::read(tokenizer.to_string().as_bytes()).unwrap()
+    }
+
+    fn texts(encoding: &Encoding) -> Vec<&str> {
+        encoding
+            .tokens
+            .iter()
+            .map(|token| token.text.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn bert_pipeline() {
+        let tokenizer = bert();
+        let encoding = tokenizer.encode("UNwanted, Café [MASK] running");
+        assert_eq!(
+            texts(&encoding),
+            [
+                "[CLS]", "un", "##want", "##ed", ",", "cafe", "[MASK]", "running", "[SEP]"
+            ]
+        );
+        assert_eq!(encoding.ids, [2, 5, 6, 7, 8, 10, 4, 9, 3]);
+        let spans: Vec<(usize, usize, usize, usize)> = encoding.tokens[5..7]
+            .iter()
+            .map(|token| (token.start, token.end, token.char_start, token.char_end))
+            .collect();
+        assert_eq!(spans, [(10, 15, 10, 14), (15, 22, 14, 21)]);
+        assert_eq!(tokenizer.decode(&encoding.ids), "unwanted, cafe running");
+        assert_eq!(
+            tokenizer.detokenize_line(&tokenizer.tokenize_line("Cafe is open")),
+            "cafe is open"
+        );
+        assert_eq!(
+            texts(&tokenizer.encode_pair("cafe", "open")),
+            ["[CLS]", "cafe", "[SEP]", "open", "[SEP]"]
+        );
+        assert_eq!(tokenizer.id("[MASK]"), Some(4));
+        assert_eq!(tokenizer.token(7), Some("##ed"));
+    }
+
+    #[test]
+    fn moses_pre_tokenizer() {
+        let moses = MosesTokenizer::builder(Language::En)
+            .no_escaping(true)
+            .build()
+            .unwrap();
+        let tokenizer = bert().moses_pre_tokenizer(moses);
+        assert_eq!(
+            texts(&tokenizer.encode("cafe, isn't")),
+            ["[CLS]", "cafe", ",", "[UNK]", "[UNK]", "[SEP]"]
+        );
+    }
+
+    #[test]
+    fn byte_level_pipeline() {
+        let mut vocab = serde_json::Map::new();
+        for byte in 0..=u8::MAX {
+            vocab.insert(crate::byte_bpe::bytes_to_chars(&[byte]), byte.into());
+        }
+        for (token, id) in [
+            ("Ġw", 256),
+            ("Ġwor", 257),
+            ("ld", 258),
+            ("Ġworld", 259),
+            ("<|endoftext|>", 260),
+            ("or", 261),
+        ] {
+            vocab.insert(token.to_owned(), id.into());
+        }
+        let tokenizer = json!({
+            "added_tokens": [
+                {"id": 260, "content": "<|endoftext|>", "single_word": false, "lstrip": false, "rstrip": false, "normalized": true, "special": true},
+            ],
+            "normalizer": {"type": "NFC"},
+            "pre_tokenizer": {"type": "ByteLevel", "add_prefix_space": true, "trim_offsets": true, "use_regex": true},
+            "post_processor": {"type": "ByteLevel", "add_prefix_space": true, "trim_offsets": true, "use_regex": true},
+            "decoder": {"type": "ByteLevel", "add_prefix_space": true, "trim_offsets": true, "use_regex": true},
+            "model": {
+                "type": "BPE",
+                "dropout": null,
+                "unk_token": null,
+                "fuse_unk": false,
+                "byte_fallback": false,
+                "vocab": vocab,
+                "merges": ["Ġ w", "o r", "Ġw or", "l d", "Ġwor ld"],
+            },
+        });
+        let tokenizer = HuggingFaceTokenizer::read(tokenizer.to_string().as_bytes()).unwrap();
+        let text = "world<|endoftext|> é";
+        let encoding = tokenizer.encode(text);
+        assert_eq!(texts(&encoding), ["Ġworld", "<|endoftext|>", "Ġ", "Ã", "©"]);
+        let spans: Vec<(usize, usize)> = encoding
+            .tokens
+            .iter()
+            .map(|token| (token.start, token.end))
+            .collect();
+        assert_eq!(spans, [(0, 5), (5, 18), (19, 19), (19, 21), (19, 21)]);
+        assert_eq!(tokenizer.decode(&encoding.ids), " world é");
+    }
+
+    #[test]
+    fn invalid_pipelines() {
+        let invalid =
+            |tokenizer: Value| match HuggingFaceTokenizer::read(tokenizer.to_string().as_bytes()) {
+                Err(Error::InvalidModel { message, .. }) => message,
+                result => panic!("unexpected {result:?}"),
+            };
+        assert_eq!(
+            invalid(
+                json!({"model": {"type": "WordLevel", "vocab": {}}, "normalizer": {"type": "Unknown"}})
+            ),
+            "unsupported normalizer `Unknown`"
+        );
+        assert_eq!(
+            invalid(json!({"model": {"type": "BPE", "vocab": {"a": 0}, "merges": ["a b"]}})),
+            "merge `a b` has tokens that are not in the vocabulary"
+        );
+        assert!(matches!(
+            HuggingFaceTokenizer::read("{".as_bytes()),
+            Err(Error::InvalidModel { .. })
+        ));
+    }
+}
@@ -0,0 +1,274 @@
+use super::{ParseResult, get_bool, get_pattern, get_str, get_type};
+use crate::byte_bpe::chars_to_bytes;
+use fancy_regex::{NoExpand, Regex};
+use serde_json::Value;
+
+// Replacements of the cleanup of the WordPiece decoder, which joins punctuation and contractions to the words before
+// them
+const CLEANUP_REPLACEMENTS: [(&str, &str); 11] = [
+    (" .", "."),
+    (" ?", "?"),
+    (" !", "!"),
+    (" ,", ","),
+    (" ' ", "'"),
+    (" n't", "n't"),
+    (" 'm", "'m"),
+    (" do not", " don't"),
+    (" 's", "'s"),
+    (" 've", "'ve"),
+    (" 're", "'re"),
+];
+
+// Decoder of a Hugging Face pipeline, which turns tokens back into text
+#[derive(Debug, Clone)]
+pub(super) enum Decoder {
+    ByteLevel,
+    WordPiece {
+        prefix: String,
+        cleanup: bool,
+    },
+    Metaspace {
+        replacement: char,
+        strip_first_space: bool,
+    },
+    Bpe {
+        suffix: String,
+    },
+    ByteFallback,
+    Fuse,
+    Strip {
+        content: char,
+        start: usize,
+        stop: usize,
+    },
+    Replace {
+        pattern: Regex,
+        content: String,
+    },
+    Sequence(Vec<Decoder>),
+}
+
+impl Decoder {
+    pub(super) fn parse(value: &Value) -> ParseResult<Self> {
+        let single_char = |name: &str| -> ParseResult<char> {
+            let text = get_str(value, name)?;
+            let mut chars = text.chars();
+            match (chars.next(), chars.next()) {
+                (Some(ch), None) => Ok(ch),
+                _ => Err(format!("invalid {name} `{text}`")),
+            }
+        };
+        let count = |name: &str| value.get(name).and_then(Value::as_u64).unwrap_or(0) as usize;
+        Ok(match get_type(value)? {
+            "ByteLevel" => Decoder::ByteLevel,
+            "WordPiece" => Decoder::WordPiece {
+                prefix: get_str(value, "prefix")?.to_owned(),
+                cleanup: get_bool(value, "cleanup", true)?,
+            },
+            "Metaspace" => Decoder::Metaspace {
+                replacement: single_char("replacement")?,
+                strip_first_space: match value.get("prepend_scheme") {
+                    Some(Value::String(scheme)) => scheme != "never",
+                    _ => get_bool(value, "add_prefix_space", true)?,
+                },
+            },
+            "BPEDecoder" => Decoder::Bpe {
+                suffix: get_str(value, "suffix")?.to_owned(),
+            },
+            "ByteFallback" => Decoder::ByteFallback,
+            "Fuse" => Decoder::Fuse,
+            "Strip" => Decoder::Strip {
+                content: single_char("content")?,
+                start: count("start"),
+                stop: count("stop"),
+            },
+            "Replace" => Decoder::Replace {
+                pattern: get_pattern(value)?,
+                content: get_str(value, "content")?.to_owned(),
+            },
+            "Sequence" => Decoder::Sequence(
+                value
+                    .get("decoders")
+                    .and_then(Value::as_array)
+                    .ok_or_else(|| "missing decoders of Sequence".to_owned())?
+                    .iter()
+                    .map(Decoder::parse)
+                    .collect::<ParseResult<_>>()?,
+            ),
+            name => return Err(format!("unsupported decoder `{name}`")),
+        })
+    }
+
+    // Transforms the tokens, which are joined into the decoded text after every decoder
+    pub(super) fn decode(&self, tokens: Vec<String>) -> Vec<String> {
+        let last = tokens.len().saturating_sub(1);
+        match self {
+            Decoder::ByteLevel => {
+                let mut bytes = Vec::new();
+                for token in &tokens {
+                    match chars_to_bytes(token) {
+                        Some(token_bytes) => bytes.extend_from_slice(&token_bytes),
+                        None => bytes.extend_from_slice(token.as_bytes()),
+                    }
+                }
+                vec![String::from_utf8_lossy(&bytes).into_owned()]
+            }
+            Decoder::WordPiece { prefix, cleanup } => tokens
+                .into_iter()
+                .enumerate()
+                .map(|(i, token)| {
+                    let mut token = match token.strip_prefix(prefix.as_str()) {
+                        _ if i == 0 => token,
+                        Some(subword) => subword.to_owned(),
+                        None => format!(" {token}"),
+                    };
+                    if *cleanup {
+                        for (from, to) in CLEANUP_REPLACEMENTS {
+                            token = token.replace(from, to);
+                        }
+                    }
+                    token
+                })
+                .collect(),
+            Decoder::Metaspace {
+                replacement,
+                strip_first_space,
+            } => tokens
+                .into_iter()
+                .enumerate()
+                .map(|(i, token)| {
+                    let token = token.replace(*replacement, " ");
+                    match token.strip_prefix(' ') {
+                        Some(stripped) if i == 0 && *strip_first_space => stripped.to_owned(),
+                        _ => token,
+                    }
+                })
+                .collect(),
+            Decoder::Bpe { suffix } => tokens
+                .into_iter()
+                .enumerate()
+                .map(|(i, token)| token.replace(suffix.as_str(), if i == last { "" } else { " " }))
+                .collect(),
+            Decoder::ByteFallback => {
+                let mut decoded = Vec::with_capacity(tokens.len());
+                let mut bytes = Vec::new();
+                let flush = |bytes: &mut Vec<u8>, decoded: &mut Vec<String>| {
+                    if bytes.is_empty() {
+                        return;
+                    }
+                    match String::from_utf8(std::mem::take(bytes)) {
+                        Ok(text) => decoded.push(text),
+                        Err(e) => {
+                            decoded.extend(e.as_bytes().iter().map(|_| "\u{fffd}".to_owned()))
+                        }
+                    }
+                };
+                for token in tokens {
+                    let byte = token
+                        .strip_prefix("<0x")
+                        .and_then(|hex| hex.strip_suffix('>'))
+                        .filter(|hex| hex.len() == 2)
+                        .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+                    match byte {
+                        Some(byte) => bytes.push(byte),
+                        None => {
+                            flush(&mut bytes, &mut decoded);
+                            decoded.push(token);
+                        }
+                    }
+                }
+                flush(&mut bytes, &mut decoded);
+                decoded
+            }
+            Decoder::Fuse => vec![tokens.concat()],
+            Decoder::Strip {
+                content,
+                start,
+                stop,
+            } => tokens
+                .into_iter()
+                .map(|token| {
+                    let chars: Vec<char> = token.chars().collect();
+                    let leading = chars
+                        .iter()
+                        .take(*start)
+                        .take_while(|&ch| ch == content)
+                        .count();
+                    let trailing = chars[leading..]
+                        .iter()
+                        .rev()
+                        .take(*stop)
+                        .take_while(|&ch| ch == content)
+                        .count();
+                    chars[leading..chars.len() - trailing].iter().collect()
+                })
+                .collect(),
+            Decoder::Replace { pattern, content } => tokens
+                .into_iter()
+                .map(|token| pattern.replace_all(&token, NoExpand(content)).into_owned())
+                .collect(),
+            Decoder::Sequence(decoders) => decoders
+                .iter()
+                .fold(tokens, |tokens, decoder| decoder.decode(tokens)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn decode(decoder: Value, tokens: &[&str]) -> String {
+        Decoder::parse(&decoder)
+            .unwrap()
+            .decode(tokens.iter().map(|token| token.to_string()).collect())
+            .concat()
+    }
+
+    #[test]
+    fn decoders() {
+        assert_eq!(
+            decode(json!({"type": "ByteLevel"}), &["Hello", "Ġw", "Ã©"]),
+            "Hello wé"
+        );
+        assert_eq!(
+            decode(
+                json!({"type": "WordPiece", "prefix": "##", "cleanup": true}),
+                &["un", "##want", "##ed", ",", "it", "'s", "fine", "."]
+            ),
+            "unwanted, it's fine."
+        );
+        assert_eq!(
+            decode(
+                json!({"type": "BPEDecoder", "suffix": "</w>"}),
+                &["hel", "lo</w>", "you</w>"]
+            ),
+            "hello you"
+        );
+    }
+
+    #[test]
+    fn sentencepiece_decoders() {
+        let decoder = json!({"type": "Sequence", "decoders": [
+            {"type": "Replace", "pattern": {"String": "▁"}, "content": " "},
+            {"type": "ByteFallback"},
+            {"type": "Fuse"},
+            {"type": "Strip", "content": " ", "start": 1, "stop": 0},
+        ]});
+        assert_eq!(
+            decode(
+                decoder,
+                &["▁Hello", "<0xC3>", "<0xA9>", "▁", "<0xC3>", "<0xFF>"]
+            ),
+            "Helloé \u{fffd}\u{fffd}"
+        );
+        assert_eq!(
+            decode(
+                json!({"type": "Metaspace", "replacement": "▁", "prepend_scheme": "always"}),
+                &["▁Hello", "▁world"]
+            ),
+            "Hello world"
+        );
+    }
+}
@@ -0,0 +1,489 @@
+use super::{ParseResult, get_bool, get_type};
+use crate::unigram::{Piece, PieceType, Segmenter};
+use crate::wordpiece::{WordPieceTokenizer, WordPieceVocabulary};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::ops::Range;
+
+// Model of a Hugging Face pipeline, which splits every piece of pre-tokenized text into tokens
+#[derive(Debug, Clone)]
+pub(super) enum Model {
+    Bpe(Bpe),
+    WordPiece(Box<WordPieceTokenizer>),
+    Unigram(Unigram),
+    WordLevel {
+        vocabulary: Vocabulary,
+        unknown_id: Option<u32>,
+    },
+}
+
+// Tokens of a model along with their ids
+#[derive(Debug, Clone, Default)]
+pub(super) struct Vocabulary {
+    ids: HashMap<String, u32>,
+    tokens: HashMap<u32, String>,
+}
+
+impl Vocabulary {
+    // Vocabulary of a JSON object whose keys are tokens and whose values are their ids
+    fn parse(value: Option<&Value>) -> ParseResult<Self> {
+        let mut vocabulary = Vocabulary::default();
+        let vocab = value
+            .and_then(Value::as_object)
+            .ok_or_else(|| "missing vocab of the model".to_owned())?;
+        for (token, id) in vocab {
+            let id = id
+                .as_u64()
+                .and_then(|id| u32::try_from(id).ok())
+                .ok_or_else(|| format!("invalid id of token `{token}`"))?;
+            vocabulary.ids.insert(token.clone(), id);
+            vocabulary.tokens.insert(id, token.clone());
+        }
+        Ok(vocabulary)
+    }
+
+    fn id(&self, token: &str) -> Option<u32> {
+        self.ids.get(token).copied()
+    }
+
+    fn token(&self, id: u32) -> Option<&str> {
+        self.tokens.get(&id).map(String::as_str)
+    }
+
+    // Id of a token that is optional in the JSON file, which must be in the vocabulary when given
+    fn optional_id(&self, value: &Value, name: &str) -> ParseResult<Option<u32>> {
+        match value.get(name) {
+            Some(Value::String(token)) => self
+                .id(token)
+                .map(Some)
+                .ok_or_else(|| format!("the {name} `{token}` is not in the vocabulary")),
+            _ => Ok(None),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct Bpe {
+    vocabulary: Vocabulary,
+    // Priority and result of every pair of tokens that is merged
+    merges: HashMap<(u32, u32), (u32, u32)>,
+    unknown_id: Option<u32>,
+    continuing_subword_prefix: Option<String>,
+    end_of_word_suffix: Option<String>,
+    fuse_unknown: bool,
+    byte_fallback: bool,
+    ignore_merges: bool,
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct Unigram {
+    pieces: Vec<Piece>,
+    segmenter: Segmenter,
+    unknown_id: Option<u32>,
+    ids: HashMap<String, u32>,
+    byte_fallback: bool,
+}
+
+impl Model {
+    pub(super) fn parse(value: &Value) -> ParseResult<Self> {
+        // Files written by old versions of the library do not have the type of the model
+        let model_type = match value.get("type") {
+            Some(_) => get_type(value)?,
+            None if value.get("merges").is_some() => "BPE",
+            None if value.get("continuing_subword_prefix").is_some() => "WordPiece",
+            None if value.get("vocab").is_some_and(Value::is_array) => "Unigram",
+            None => "WordLevel",
+        };
+        match model_type {
+            "BPE" => Ok(Model::Bpe(Bpe::parse(value)?)),
+            "WordPiece" => {
+                let vocabulary = Vocabulary::parse(value.get("vocab"))?;
+                let len = vocabulary
+                    .tokens
+                    .keys()
+                    .max()
+                    .map_or(0, |&id| id as usize + 1);
+                let mut tokens = vec![String::new(); len];
+                for (id, token) in vocabulary.tokens {
+                    tokens[id as usize] = token;
+                }
+                let mut builder = WordPieceTokenizer::builder();
+                if let Some(Value::String(unknown_token)) = value.get("unk_token") {
+                    builder = builder.unknown_token(unknown_token);
+                }
+                if let Some(Value::String(prefix)) = value.get("continuing_subword_prefix") {
+                    builder = builder.continuing_prefix(prefix);
+                }
+                if let Some(max_chars) = value
+                    .get("max_input_chars_per_word")
+                    .and_then(Value::as_u64)
+                {
+                    builder = builder.max_chars_per_word(max_chars as usize);
+                }
+                let tokenizer = builder
+                    .build(WordPieceVocabulary::new(tokens))
+                    .map_err(|e| e.to_string())?;
+                Ok(Model::WordPiece(Box::new(tokenizer)))
+            }
+            "Unigram" => Ok(Model::Unigram(Unigram::parse(value)?)),
+            "WordLevel" => {
+                let vocabulary = Vocabulary::parse(value.get("vocab"))?;
+                let unknown_id = vocabulary.optional_id(value, "unk_token")?;
+                Ok(Model::WordLevel {
+                    vocabulary,
+                    unknown_id,
+                })
+            }
+            name => Err(format!("unsupported model `{name}`")),
+        }
+    }
+
+    // Byte ranges of the tokens of a piece along with their ids
+    pub(super) fn tokenize(&self, piece: &str) -> Vec<(Range<usize>, u32)> {
+        match self {
+            Model::Bpe(bpe) => bpe.tokenize(piece),
+            Model::WordPiece(tokenizer) => {
+                let encoding = tokenizer.encode_normalized_word(piece);
+                encoding
+                    .tokens
+                    .iter()
+                    .zip(encoding.ids)
+                    .map(|(token, id)| (token.start..token.end, id))
+                    .collect()
+            }
+            Model::Unigram(unigram) => unigram.tokenize(piece),
+            Model::WordLevel {
+                vocabulary,
+                unknown_id,
+            } => vocabulary
+                .id(piece)
+                .or(*unknown_id)
+                .map(|id| (0..piece.len(), id))
+                .into_iter()
+                .collect(),
+        }
+    }
+
+    pub(super) fn id(&self, token: &str) -> Option<u32> {
+        match self {
+            Model::Bpe(bpe) => bpe.vocabulary.id(token),
+            // Gaps between the ids of the vocabulary are empty tokens
+            Model::WordPiece(tokenizer) => tokenizer
+                .vocabulary()
+                .id(token)
+                .filter(|_| !token.is_empty()),
+            Model::Unigram(unigram) => unigram.ids.get(token).copied(),
+            Model::WordLevel { vocabulary, .. } => vocabulary.id(token),
+        }
+    }
+
+    pub(super) fn token(&self, id: u32) -> Option<&str> {
+        match self {
+            Model::Bpe(bpe) => bpe.vocabulary.token(id),
+            Model::WordPiece(tokenizer) => tokenizer
+                .vocabulary()
+                .token(id)
+                .filter(|token| !token.is_empty()),
+            Model::Unigram(unigram) => unigram
+                .pieces
+                .get(id as usize)
+                .map(|piece| piece.piece.as_str()),
+            Model::WordLevel { vocabulary, .. } => vocabulary.token(id),
+        }
+    }
+}
+
+impl Bpe {
+    fn parse(value: &Value) -> ParseResult<Self> {
+        let vocabulary = Vocabulary::parse(value.get("vocab"))?;
+        let optional_str = |name: &str| match value.get(name) {
+            Some(Value::String(text)) if !text.is_empty() => Some(text.clone()),
+            _ => None,
+        };
+        let continuing_subword_prefix = optional_str("continuing_subword_prefix");
+        let mut merges = HashMap::new();
+        let merge_values = value
+            .get("merges")
+            .and_then(Value::as_array)
+            .ok_or_else(|| "missing merges of the BPE model".to_owned())?;
+        for (rank, merge) in merge_values.iter().enumerate() {
+            // Merges are strings separated by a space in older files and pairs of strings in newer ones
+            let (left, right) = match merge {
+                Value::String(merge) => merge.split_once(' '),
+                Value::Array(pair) => match pair.as_slice() {
+                    [Value::String(left), Value::String(right)] => {
+                        Some((left.as_str(), right.as_str()))
+                    }
+                    _ => None,
+                },
+                _ => None,
+            }
+            .ok_or_else(|| format!("invalid merge {merge}"))?;
+            let right_text = match &continuing_subword_prefix {
+                Some(prefix) => right.strip_prefix(prefix.as_str()).unwrap_or(right),
+                None => right,
+            };
+            let merged = format!("{left}{right_text}");
+            let (Some(left_id), Some(right_id), Some(merged_id)) = (
+                vocabulary.id(left),
+                vocabulary.id(right),
+                vocabulary.id(&merged),
+            ) else {
+                return Err(format!(
+                    "merge `{left} {right}` has tokens that are not in the vocabulary"
+                ));
+            };
+            merges
+                .entry((left_id, right_id))
+                .or_insert((rank as u32, merged_id));
+        }
+        Ok(Bpe {
+            unknown_id: vocabulary.optional_id(value, "unk_token")?,
+            end_of_word_suffix: optional_str("end_of_word_suffix"),
+            fuse_unknown: get_bool(value, "fuse_unk", false)?,
+            byte_fallback: get_bool(value, "byte_fallback", false)?,
+            ignore_merges: get_bool(value, "ignore_merges", false)?,
+            continuing_subword_prefix,
+            vocabulary,
+            merges,
+        })
+    }
+
+    // Splits a piece into its characters, which are then merged from the pair with the highest priority, and the
+    // leftmost one among equals. Characters out of the vocabulary become their bytes with byte fallback, or else the
+    // unknown token, and are removed without one.
+    fn tokenize(&self, piece: &str) -> Vec<(Range<usize>, u32)> {
+        if self.ignore_merges
+            && let Some(id) = self.vocabulary.id(piece)
+        {
+            return vec![(0..piece.len(), id)];
+        }
+        let mut symbols: Vec<(Range<usize>, u32)> = Vec::with_capacity(piece.len());
+        let mut previous_unknown = false;
+        let mut symbol = String::new();
+        for (i, (position, ch)) in piece.char_indices().enumerate() {
+            let range = position..position + ch.len_utf8();
+            symbol.clear();
+            if i > 0
+                && let Some(prefix) = &self.continuing_subword_prefix
+            {
+                symbol.push_str(prefix);
+            }
+            symbol.push(ch);
+            if range.end == piece.len()
+                && let Some(suffix) = &self.end_of_word_suffix
+            {
+                symbol.push_str(suffix);
+            }
+            if let Some(id) = self.vocabulary.id(&symbol) {
+                symbols.push((range, id));
+                previous_unknown = false;
+                continue;
+            }
+            if self.byte_fallback {
+                let mut bytes = [0; 4];
+                let byte_ids: Option<Vec<u32>> = ch
+                    .encode_utf8(&mut bytes)
+                    .bytes()
+                    .map(|byte| self.vocabulary.id(&format!("<0x{byte:02X}>")))
+                    .collect();
+                if let Some(byte_ids) = byte_ids {
+                    symbols.extend(byte_ids.into_iter().map(|id| (range.clone(), id)));
+                    previous_unknown = false;
+                    continue;
+                }
+            }
+            let Some(unknown_id) = self.unknown_id else {
+                continue;
+            };
+            match symbols.last_mut() {
+                Some(last) if self.fuse_unknown && previous_unknown => last.0.end = range.end,
+                _ => symbols.push((range, unknown_id)),
+            }
+            previous_unknown = true;
+        }
+
+        while let Some((_, i, merged_id)) = symbols
+            .windows(2)
+            .enumerate()
+            .filter_map(|(i, pair)| {
+                self.merges
+                    .get(&(pair[0].1, pair[1].1))
+                    .map(|&(rank, merged_id)| (rank, i, merged_id))
+            })
+            .min()
+        {
+            let (range, _) = symbols.remove(i + 1);
+            symbols[i] = (symbols[i].0.start..range.end, merged_id);
+        }
+        symbols
+    }
+}
+
+impl Unigram {
+    fn parse(value: &Value) -> ParseResult<Self> {
+        let unknown_id = value
+            .get("unk_id")
+            .and_then(Value::as_u64)
+            .map(|id| id as u32);
+        let mut pieces = Vec::new();
+        let mut ids = HashMap::new();
+        let vocab = value
+            .get("vocab")
+            .and_then(Value::as_array)
+            .ok_or_else(|| "missing vocab of the Unigram model".to_owned())?;
+        for (id, entry) in vocab.iter().enumerate() {
+            let (Some(piece), Some(score)) = (
+                entry.get(0).and_then(Value::as_str),
+                entry.get(1).and_then(Value::as_f64),
+            ) else {
+                return Err(format!("invalid piece {entry}"));
+            };
+            let kind = if unknown_id == Some(id as u32) {
+                PieceType::Unknown
+            } else {
+                PieceType::Normal
+            };
+            pieces.push(Piece::new(piece, score as f32, kind));
+            ids.insert(piece.to_owned(), id as u32);
+        }
+        if unknown_id.is_some_and(|id| id as usize >= pieces.len()) {
+            return Err("the unk_id is out of the vocabulary".to_owned());
+        }
+        Ok(Unigram {
+            segmenter: Segmenter::new(&pieces, unknown_id.unwrap_or(u32::MAX)),
+            byte_fallback: get_bool(value, "byte_fallback", false)?,
+            pieces,
+            unknown_id,
+            ids,
+        })
+    }
+
+    // Best segmentation of a piece, where consecutive unknown characters are a single token, or their bytes with byte
+    // fallback, and are removed without an unknown token
+    fn tokenize(&self, piece: &str) -> Vec<(Range<usize>, u32)> {
+        let mut tokens: Vec<(Range<usize>, u32)> = Vec::new();
+        let mut previous_unknown = false;
+        for (range, id) in self.segmenter.viterbi(piece) {
+            if id != self.unknown_id.unwrap_or(u32::MAX) {
+                tokens.push((range, id));
+                previous_unknown = false;
+                continue;
+            }
+            if self.byte_fallback {
+                let byte_ids: Option<Vec<u32>> = piece[range.clone()]
+                    .bytes()
+                    .map(|byte| self.ids.get(&format!("<0x{byte:02X}>")).copied())
+                    .collect();
+                if let Some(byte_ids) = byte_ids {
+                    tokens.extend(byte_ids.into_iter().map(|id| (range.clone(), id)));
+                    previous_unknown = false;
+                    continue;
+                }
+            }
+            let Some(unknown_id) = self.unknown_id else {
+                continue;
+            };
+            match tokens.last_mut() {
+                Some(last) if previous_unknown => last.0.end = range.end,
+                _ => tokens.push((range, unknown_id)),
+            }
+            previous_unknown = true;
+        }
+        tokens
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn tokenize<'a>(model: &'a Model, piece: &str) -> Vec<(&'a str, Range<usize>)> {
+        model
+            .tokenize(piece)
+            .into_iter()
+            .map(|(range, id)| (model.token(id).unwrap(), range))
+            .collect()
+    }
+
+    #[test]
+    fn bpe() {
+        let model = Model::parse(&json!({
+            "type": "BPE",
+            "unk_token": "<unk>",
+            "fuse_unk": true,
+            "byte_fallback": true,
+            "vocab": {
+                "<unk>": 0, "<0xC3>": 1, "<0xA9>": 2, "l": 3, "o": 4, "w": 5, "e": 6,
+                "r": 7, "lo": 8, "low": 9, "er": 10, "lower": 11,
+            },
+            "merges": ["l o", ["lo", "w"], "e r", "low er"],
+        }))
+        .unwrap();
+        assert_eq!(
+            tokenize(&model, "lowerxyé"),
+            [
+                ("lower", 0..5),
+                ("<unk>", 5..7),
+                ("<0xC3>", 7..9),
+                ("<0xA9>", 7..9)
+            ]
+        );
+    }
+
+    #[test]
+    fn bpe_with_affixes() {
+        let model = Model::parse(&json!({
+            "type": "BPE",
+            "continuing_subword_prefix": "##",
+            "end_of_word_suffix": "</w>",
+            "vocab": {"a": 0, "##b": 1, "##c</w>": 2, "ab": 3, "abc</w>": 4, "##b</w>": 5},
+            "merges": ["a ##b", "ab ##c</w>"],
+        }))
+        .unwrap();
+        assert_eq!(tokenize(&model, "abc"), [("abc</w>", 0..3)]);
+        assert_eq!(tokenize(&model, "ab"), [("a", 0..1), ("##b</w>", 1..2)]);
+    }
+
+    #[test]
+    fn wordpiece_and_word_level() {
+        let model = Model::parse(&json!({
+            "type": "WordPiece",
+            "unk_token": "[UNK]",
+            "continuing_subword_prefix": "##",
+            "max_input_chars_per_word": 100,
+            "vocab": {"[UNK]": 0, "un": 1, "##want": 2, "##ed": 3},
+        }))
+        .unwrap();
+        assert_eq!(
+            tokenize(&model, "unwanted"),
+            [("un", 0..2), ("##want", 2..6), ("##ed", 6..8)]
+        );
+        assert_eq!(tokenize(&model, "wanted"), [("[UNK]", 0..6)]);
+
+        let model = Model::parse(&json!({
+            "type": "WordLevel",
+            "unk_token": "<unk>",
+            "vocab": {"<unk>": 0, "hello": 1},
+        }))
+        .unwrap();
+        assert_eq!(tokenize(&model, "hello"), [("hello", 0..5)]);
+        assert_eq!(tokenize(&model, "bye"), [("<unk>", 0..3)]);
+    }
+
+    #[test]
+    fn unigram() {
+        let model = Model::parse(&json!({
+            "type": "Unigram",
+            "unk_id": 0,
+            "vocab": [["<unk>", 0.0], ["▁he", -2.0], ["llo", -2.0], ["▁hello", -3.0], ["▁", -1.0], ["h", -5.0]],
+        }))
+        .unwrap();
+        assert_eq!(tokenize(&model, "▁hello"), [("▁hello", 0..8)]);
+        assert_eq!(
+            tokenize(&model, "▁hxyh"),
+            [("▁", 0..3), ("h", 3..4), ("<unk>", 4..6), ("h", 6..7)]
+        );
+    }
+}
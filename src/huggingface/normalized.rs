@@ -0,0 +1,248 @@
+use super::ParseResult;
+use std::iter;
+use std::ops::Range;
+
+// Text transformed by the stages of a pipeline, along with the byte range of the original text that every one of its
+// bytes comes from, so that tokens can be traced back to the original text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct NormalizedText {
+    text: String,
+    alignments: Vec<(usize, usize)>,
+}
+
+/// How the matches of a pattern are kept when splitting text with it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum SplitBehavior {
+    Removed,
+    Isolated,
+    MergedWithPrevious,
+    MergedWithNext,
+    Contiguous,
+}
+
+impl SplitBehavior {
+    pub(super) fn parse(name: &str) -> ParseResult<Self> {
+        match name {
+            "Removed" => Ok(SplitBehavior::Removed),
+            "Isolated" => Ok(SplitBehavior::Isolated),
+            "MergedWithPrevious" => Ok(SplitBehavior::MergedWithPrevious),
+            "MergedWithNext" => Ok(SplitBehavior::MergedWithNext),
+            "Contiguous" => Ok(SplitBehavior::Contiguous),
+            _ => Err(format!("unsupported split behavior `{name}`")),
+        }
+    }
+}
+
+impl NormalizedText {
+    // Text found at `offset` in the original text
+    pub(super) fn new(text: &str, offset: usize) -> Self {
+        let mut alignments = Vec::with_capacity(text.len());
+        for (position, ch) in text.char_indices() {
+            let alignment = (offset + position, offset + position + ch.len_utf8());
+            alignments.extend(iter::repeat_n(alignment, ch.len_utf8()));
+        }
+        NormalizedText {
+            text: text.to_owned(),
+            alignments,
+        }
+    }
+
+    pub(super) fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    pub(super) fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    // Range of the original text that a non-empty range of the text comes from
+    pub(super) fn original_range(&self, range: Range<usize>) -> Range<usize> {
+        self.alignments[range.start].0..self.alignments[range.end - 1].1
+    }
+
+    // Replaces every character with the text pushed by `f`, which comes from the original text of the character
+    pub(super) fn map_chars(&mut self, mut f: impl FnMut(char, &mut String)) {
+        let mut text = String::with_capacity(self.text.len());
+        let mut alignments = Vec::with_capacity(self.alignments.len());
+        let mut replacement = String::new();
+        for (position, ch) in self.text.char_indices() {
+            replacement.clear();
+            f(ch, &mut replacement);
+            let alignment = self.original_range(position..position + ch.len_utf8());
+            text.push_str(&replacement);
+            alignments.extend(iter::repeat_n(
+                (alignment.start, alignment.end),
+                replacement.len(),
+            ));
+        }
+        self.text = text;
+        self.alignments = alignments;
+    }
+
+    // Replaces the given ranges of the text, which are sorted and do not overlap, keeping the rest as it is. The text
+    // inserted in empty ranges comes from the original text of the character that follows it, or else precedes it.
+    pub(super) fn splice(
+        &mut self,
+        replacements: impl IntoIterator<Item = (Range<usize>, String)>,
+    ) {
+        if self.text.is_empty() {
+            return;
+        }
+        let mut text = String::with_capacity(self.text.len());
+        let mut alignments = Vec::with_capacity(self.alignments.len());
+        let mut end = 0;
+        for (range, replacement) in replacements {
+            text.push_str(&self.text[end..range.start]);
+            alignments.extend_from_slice(&self.alignments[end..range.start]);
+            let alignment = if !range.is_empty() {
+                let range = self.original_range(range.clone());
+                (range.start, range.end)
+            } else if range.start < self.text.len() {
+                self.alignments[range.start]
+            } else {
+                self.alignments[range.start - 1]
+            };
+            text.push_str(&replacement);
+            alignments.extend(iter::repeat_n(alignment, replacement.len()));
+            end = range.end;
+        }
+        text.push_str(&self.text[end..]);
+        alignments.extend_from_slice(&self.alignments[end..]);
+        self.text = text;
+        self.alignments = alignments;
+    }
+
+    pub(super) fn slice(&self, range: Range<usize>) -> Self {
+        NormalizedText {
+            text: self.text[range.clone()].to_owned(),
+            alignments: self.alignments[range].to_vec(),
+        }
+    }
+
+    // Splits the text with the given matches, which are sorted and do not overlap, like the splits of Hugging Face
+    // pre-tokenizers. When inverted, the text between the matches is split with instead.
+    pub(super) fn split(
+        &self,
+        matches: impl IntoIterator<Item = Range<usize>>,
+        behavior: SplitBehavior,
+        invert: bool,
+    ) -> Vec<Self> {
+        // Ranges covering the whole text, each one along with whether it is a match
+        let mut ranges = Vec::new();
+        let mut end = 0;
+        for range in matches {
+            if range.is_empty() {
+                continue;
+            }
+            if range.start > end {
+                ranges.push((end..range.start, invert));
+            }
+            end = range.end;
+            ranges.push((range, !invert));
+        }
+        if end < self.text.len() {
+            ranges.push((end..self.text.len(), invert));
+        }
+
+        let mut merged: Vec<Range<usize>> = Vec::with_capacity(ranges.len());
+        let mut previous_match = false;
+        match behavior {
+            SplitBehavior::Removed => {
+                merged.extend(
+                    ranges
+                        .into_iter()
+                        .filter(|(_, is_match)| !is_match)
+                        .map(|(range, _)| range),
+                );
+            }
+            SplitBehavior::Isolated => merged.extend(ranges.into_iter().map(|(range, _)| range)),
+            SplitBehavior::MergedWithPrevious => {
+                for (range, is_match) in ranges {
+                    match merged.last_mut() {
+                        Some(last) if is_match && !previous_match => last.end = range.end,
+                        _ => merged.push(range),
+                    }
+                    previous_match = is_match;
+                }
+            }
+            SplitBehavior::MergedWithNext => {
+                for (range, is_match) in ranges.into_iter().rev() {
+                    match merged.last_mut() {
+                        Some(last) if is_match && !previous_match => last.start = range.start,
+                        _ => merged.push(range),
+                    }
+                    previous_match = is_match;
+                }
+                merged.reverse();
+            }
+            SplitBehavior::Contiguous => {
+                for (range, is_match) in ranges {
+                    match merged.last_mut() {
+                        Some(last) if is_match == previous_match => last.end = range.end,
+                        _ => merged.push(range),
+                    }
+                    previous_match = is_match;
+                }
+            }
+        }
+        merged.into_iter().map(|range| self.slice(range)).collect()
+    }
+
+    // Splits the text with every character for which `is_match` is true as a match of its own
+    pub(super) fn split_chars(
+        &self,
+        is_match: impl Fn(char) -> bool,
+        behavior: SplitBehavior,
+    ) -> Vec<Self> {
+        let matches = self
+            .text
+            .char_indices()
+            .filter(|&(_, ch)| is_match(ch))
+            .map(|(position, ch)| position..position + ch.len_utf8());
+        self.split(matches, behavior, false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(pieces: &[NormalizedText]) -> Vec<&str> {
+        pieces.iter().map(NormalizedText::as_str).collect()
+    }
+
+    #[test]
+    fn split_behaviors() {
+        let text = NormalizedText::new("a-b--c", 0);
+        let split = |behavior| text.split_chars(|ch| ch == '-', behavior);
+        assert_eq!(texts(&split(SplitBehavior::Removed)), ["a", "b", "c"]);
+        assert_eq!(
+            texts(&split(SplitBehavior::Isolated)),
+            ["a", "-", "b", "-", "-", "c"]
+        );
+        assert_eq!(
+            texts(&split(SplitBehavior::MergedWithPrevious)),
+            ["a-", "b-", "-", "c"]
+        );
+        assert_eq!(
+            texts(&split(SplitBehavior::MergedWithNext)),
+            ["a", "-b", "-", "-c"]
+        );
+        assert_eq!(
+            texts(&split(SplitBehavior::Contiguous)),
+            ["a", "-", "b", "--", "c"]
+        );
+    }
+
+    #[test]
+    fn alignments() {
+        let mut text = NormalizedText::new("Éa b", 10);
+        text.map_chars(|ch, replacement| replacement.extend(ch.to_lowercase()));
+        text.splice([(0..0, "▁".to_owned()), (3..4, "▁".to_owned())]);
+        assert_eq!(text.as_str(), "▁éa▁b");
+        assert_eq!(text.original_range(0..3), 10..12);
+        assert_eq!(text.original_range(3..6), 10..13);
+        assert_eq!(text.original_range(6..9), 13..14);
+        assert_eq!(text.slice(6..10).original_range(3..4), 14..15);
+    }
+}
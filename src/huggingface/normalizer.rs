@@ -0,0 +1,287 @@
+use super::normalized::NormalizedText;
+use super::{ParseResult, get_bool, get_pattern, get_str, get_type};
+use crate::byte_bpe::{bytes_to_chars, decode_base64};
+use crate::unigram::PrecompiledCharsmap;
+use crate::wordpiece::{is_chinese_char, is_nonspacing_mark, is_removed, is_whitespace};
+use fancy_regex::Regex;
+use serde_json::Value;
+use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::{canonical_combining_class, compose};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum UnicodeForm {
+    Nfc,
+    Nfd,
+    Nfkc,
+    Nfkd,
+}
+
+// Normalizer of a Hugging Face pipeline, which transforms the text before it is pre-tokenized
+#[derive(Debug, Clone)]
+pub(super) enum Normalizer {
+    Bert {
+        clean_text: bool,
+        handle_chinese_chars: bool,
+        strip_accents: bool,
+        lowercase: bool,
+    },
+    Lowercase,
+    Strip {
+        left: bool,
+        right: bool,
+    },
+    StripAccents,
+    Unicode(UnicodeForm),
+    Replace {
+        pattern: Regex,
+        content: String,
+    },
+    Prepend(String),
+    Precompiled(Option<PrecompiledCharsmap>),
+    ByteLevel,
+    Sequence(Vec<Normalizer>),
+}
+
+impl Normalizer {
+    pub(super) fn parse(value: &Value) -> ParseResult<Self> {
+        Ok(match get_type(value)? {
+            "BertNormalizer" => {
+                let lowercase = get_bool(value, "lowercase", true)?;
+                Normalizer::Bert {
+                    clean_text: get_bool(value, "clean_text", true)?,
+                    handle_chinese_chars: get_bool(value, "handle_chinese_chars", true)?,
+                    strip_accents: get_bool(value, "strip_accents", lowercase)?,
+                    lowercase,
+                }
+            }
+            "Lowercase" => Normalizer::Lowercase,
+            "Strip" => Normalizer::Strip {
+                left: get_bool(value, "strip_left", true)?,
+                right: get_bool(value, "strip_right", true)?,
+            },
+            "StripAccents" => Normalizer::StripAccents,
+            "NFC" => Normalizer::Unicode(UnicodeForm::Nfc),
+            "NFD" => Normalizer::Unicode(UnicodeForm::Nfd),
+            "NFKC" => Normalizer::Unicode(UnicodeForm::Nfkc),
+            "NFKD" => Normalizer::Unicode(UnicodeForm::Nfkd),
+            "Replace" => Normalizer::Replace {
+                pattern: get_pattern(value)?,
+                content: get_str(value, "content")?.to_owned(),
+            },
+            "Prepend" => Normalizer::Prepend(get_str(value, "prepend")?.to_owned()),
+            "Precompiled" => {
+                let charsmap = match value.get("precompiled_charsmap") {
+                    Some(Value::String(charsmap)) if !charsmap.is_empty() => {
+                        let blob = decode_base64(charsmap)
+                            .ok_or_else(|| "invalid precompiled charsmap".to_owned())?;
+                        Some(PrecompiledCharsmap::new(blob)?)
+                    }
+                    _ => None,
+                };
+                Normalizer::Precompiled(charsmap)
+            }
+            "ByteLevel" => Normalizer::ByteLevel,
+            "Sequence" => Normalizer::Sequence(
+                value
+                    .get("normalizers")
+                    .and_then(Value::as_array)
+                    .ok_or_else(|| "missing normalizers of Sequence".to_owned())?
+                    .iter()
+                    .map(Normalizer::parse)
+                    .collect::<ParseResult<_>>()?,
+            ),
+            name => return Err(format!("unsupported normalizer `{name}`")),
+        })
+    }
+
+    pub(super) fn normalize(&self, text: &mut NormalizedText) {
+        match self {
+            Normalizer::Bert {
+                clean_text,
+                handle_chinese_chars,
+                strip_accents,
+                lowercase,
+            } => {
+                if *clean_text {
+                    text.map_chars(|ch, replacement| {
+                        if is_whitespace(ch) {
+                            replacement.push(' ');
+                        } else if !is_removed(ch) {
+                            replacement.push(ch);
+                        }
+                    });
+                }
+                if *handle_chinese_chars {
+                    text.map_chars(|ch, replacement| {
+                        if is_chinese_char(ch) {
+                            replacement.extend([' ', ch, ' ']);
+                        } else {
+                            replacement.push(ch);
+                        }
+                    });
+                }
+                if *strip_accents {
+                    normalize_unicode(text, UnicodeForm::Nfd);
+                    strip_nonspacing_marks(text);
+                }
+                if *lowercase {
+                    Normalizer::Lowercase.normalize(text);
+                }
+            }
+            Normalizer::Lowercase => {
+                text.map_chars(|ch, replacement| replacement.extend(ch.to_lowercase()));
+            }
+            Normalizer::Strip { left, right } => {
+                let trimmed_start = text.as_str().trim_start();
+                let start = if *left {
+                    text.as_str().len() - trimmed_start.len()
+                } else {
+                    0
+                };
+                let end = if *right {
+                    text.as_str().trim_end().len().max(start)
+                } else {
+                    text.as_str().len()
+                };
+                let len = text.as_str().len();
+                text.splice([(0..start, String::new()), (end..len, String::new())]);
+            }
+            Normalizer::StripAccents => strip_nonspacing_marks(text),
+            Normalizer::Unicode(form) => normalize_unicode(text, *form),
+            Normalizer::Replace { pattern, content } => {
+                let matches: Vec<_> = pattern
+                    .find_iter(text.as_str())
+                    .map_while(|found| found.ok())
+                    .filter(|found| !found.range().is_empty())
+                    .map(|found| (found.range(), content.clone()))
+                    .collect();
+                text.splice(matches);
+            }
+            Normalizer::Prepend(prefix) => text.splice([(0..0, prefix.clone())]),
+            Normalizer::Precompiled(Some(charsmap)) => {
+                let mut replacements = Vec::new();
+                let mut position = 0;
+                let input = text.as_str();
+                while position < input.len() {
+                    let ch_len = input[position..].chars().next().unwrap().len_utf8();
+                    match charsmap.longest_prefix(&input.as_bytes()[position..]) {
+                        Some((replacement, length))
+                            if input.is_char_boundary(position + length) =>
+                        {
+                            if replacement != &input[position..position + length] {
+                                replacements
+                                    .push((position..position + length, replacement.to_owned()));
+                            }
+                            position += length;
+                        }
+                        _ => position += ch_len,
+                    }
+                }
+                text.splice(replacements);
+            }
+            Normalizer::Precompiled(None) => {}
+            Normalizer::ByteLevel => {
+                let mut bytes = [0; 4];
+                text.map_chars(|ch, replacement| {
+                    replacement.push_str(&bytes_to_chars(ch.encode_utf8(&mut bytes).as_bytes()));
+                });
+            }
+            Normalizer::Sequence(normalizers) => {
+                for normalizer in normalizers {
+                    normalizer.normalize(text);
+                }
+            }
+        }
+    }
+}
+
+fn strip_nonspacing_marks(text: &mut NormalizedText) {
+    text.map_chars(|ch, replacement| {
+        if !is_nonspacing_mark(ch) {
+            replacement.push(ch);
+        }
+    });
+}
+
+// Normalizes every run of characters that may be composed or reordered together on its own, so that the result comes
+// from the original text of the run
+fn normalize_unicode(text: &mut NormalizedText, form: UnicodeForm) {
+    let input = text.as_str();
+    let mut replacements = Vec::new();
+    let mut start = 0;
+    let mut previous = None;
+    let normalize_run = |start: usize, end: usize, replacements: &mut Vec<_>| {
+        let run = &input[start..end];
+        let normalized: String = match form {
+            UnicodeForm::Nfc => run.nfc().collect(),
+            UnicodeForm::Nfd => run.nfd().collect(),
+            UnicodeForm::Nfkc => run.nfkc().collect(),
+            UnicodeForm::Nfkd => run.nfkd().collect(),
+        };
+        if normalized != run {
+            replacements.push((start..end, normalized));
+        }
+    };
+    for (position, ch) in input.char_indices() {
+        let starts_run = canonical_combining_class(ch) == 0
+            && previous.is_none_or(|previous| compose(previous, ch).is_none());
+        if starts_run && position > start {
+            normalize_run(start, position, &mut replacements);
+            start = position;
+        }
+        previous = Some(ch);
+    }
+    if start < input.len() {
+        normalize_run(start, input.len(), &mut replacements);
+    }
+    text.splice(replacements);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn normalize(normalizer: Value, input: &str) -> NormalizedText {
+        let mut text = NormalizedText::new(input, 0);
+        Normalizer::parse(&normalizer).unwrap().normalize(&mut text);
+        text
+    }
+
+    #[test]
+    fn bert_normalizer() {
+        let text = normalize(
+            json!({"type": "BertNormalizer", "strip_accents": null, "lowercase": true}),
+            "Héllo\u{0}\t世界",
+        );
+        assert_eq!(text.as_str(), "hello  世  界 ");
+        assert_eq!(text.original_range(1..2), 1..3);
+        assert_eq!(text.original_range(7..10), 8..11);
+    }
+
+    #[test]
+    fn unicode_forms() {
+        let nfc = normalize(json!({"type": "NFC"}), "e\u{0301}x\u{1100}\u{1161}");
+        assert_eq!(nfc.as_str(), "éx가");
+        assert_eq!(nfc.original_range(0..2), 0..3);
+        let nfkd = normalize(json!({"type": "NFKD"}), "ﬁé");
+        assert_eq!(nfkd.as_str(), "fie\u{0301}");
+        assert_eq!(nfkd.original_range(1..2), 0..3);
+    }
+
+    #[test]
+    fn sequence() {
+        let text = normalize(
+            json!({"type": "Sequence", "normalizers": [
+                {"type": "Strip", "strip_left": true, "strip_right": true},
+                {"type": "Replace", "pattern": {"String": " "}, "content": "▁"},
+                {"type": "Prepend", "prepend": "▁"},
+                {"type": "StripAccents"},
+            ]}),
+            "  a b\u{0301} ",
+        );
+        assert_eq!(text.as_str(), "▁a▁b");
+        assert_eq!(text.original_range(0..4), 2..3);
+        assert_eq!(text.original_range(7..8), 4..5);
+    }
+}
@@ -0,0 +1,289 @@
+use super::{ParseResult, PipelineToken, get_bool, get_type};
+use serde_json::Value;
+use std::collections::HashMap;
+
+// Part of a template, which is either one of the encoded texts or a special token
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum TemplatePiece {
+    Sequence(usize),
+    SpecialToken(String),
+}
+
+// Post-processor of a Hugging Face pipeline, which adds special tokens around the tokens of the encoded texts
+#[derive(Debug, Clone)]
+pub(super) enum PostProcessor {
+    Template {
+        single: Vec<TemplatePiece>,
+        pair: Vec<TemplatePiece>,
+        // Tokens and ids of every special token of the templates
+        special_tokens: HashMap<String, Vec<(String, u32)>>,
+    },
+    ByteLevel {
+        trim_offsets: bool,
+    },
+    Sequence(Vec<PostProcessor>),
+}
+
+impl PostProcessor {
+    pub(super) fn parse(value: &Value) -> ParseResult<Self> {
+        Ok(match get_type(value)? {
+            "TemplateProcessing" => {
+                let template = |name: &str| -> ParseResult<Vec<TemplatePiece>> {
+                    value
+                        .get(name)
+                        .and_then(Value::as_array)
+                        .ok_or_else(|| format!("missing {name} template"))?
+                        .iter()
+                        .map(parse_template_piece)
+                        .collect()
+                };
+                let mut special_tokens = HashMap::new();
+                if let Some(Value::Object(tokens)) = value.get("special_tokens") {
+                    for (name, token) in tokens {
+                        let ids = token.get("ids").and_then(Value::as_array);
+                        let texts = token.get("tokens").and_then(Value::as_array);
+                        let (Some(ids), Some(texts)) = (ids, texts) else {
+                            return Err(format!("invalid special token `{name}`"));
+                        };
+                        let token: Option<Vec<(String, u32)>> = texts
+                            .iter()
+                            .zip(ids)
+                            .map(|(text, id)| {
+                                Some((text.as_str()?.to_owned(), id.as_u64()? as u32))
+                            })
+                            .collect();
+                        let token =
+                            token.ok_or_else(|| format!("invalid special token `{name}`"))?;
+                        special_tokens.insert(name.clone(), token);
+                    }
+                }
+                let post_processor = PostProcessor::Template {
+                    single: template("single")?,
+                    pair: template("pair")?,
+                    special_tokens,
+                };
+                post_processor.validate()?;
+                post_processor
+            }
+            "BertProcessing" => PostProcessor::simple_template(
+                parse_token(value, "cls")?,
+                parse_token(value, "sep")?,
+                false,
+            ),
+            "RobertaProcessing" => PostProcessor::Sequence(vec![
+                PostProcessor::ByteLevel {
+                    trim_offsets: get_bool(value, "trim_offsets", true)?,
+                },
+                PostProcessor::simple_template(
+                    parse_token(value, "cls")?,
+                    parse_token(value, "sep")?,
+                    true,
+                ),
+            ]),
+            "ByteLevel" => PostProcessor::ByteLevel {
+                trim_offsets: get_bool(value, "trim_offsets", true)?,
+            },
+            "Sequence" => PostProcessor::Sequence(
+                value
+                    .get("processors")
+                    .and_then(Value::as_array)
+                    .ok_or_else(|| "missing processors of Sequence".to_owned())?
+                    .iter()
+                    .map(PostProcessor::parse)
+                    .collect::<ParseResult<_>>()?,
+            ),
+            name => return Err(format!("unsupported post-processor `{name}`")),
+        })
+    }
+
+    // Template `cls A sep` for single texts and `cls A sep B sep` for pairs, whose texts are separated by two
+    // separators in RoBERTa models
+    fn simple_template(cls: (String, u32), sep: (String, u32), double_separator: bool) -> Self {
+        let cls_piece = TemplatePiece::SpecialToken(cls.0.clone());
+        let sep_piece = TemplatePiece::SpecialToken(sep.0.clone());
+        let mut pair = vec![
+            cls_piece.clone(),
+            TemplatePiece::Sequence(0),
+            sep_piece.clone(),
+        ];
+        if double_separator {
+            pair.push(sep_piece.clone());
+        }
+        pair.extend([TemplatePiece::Sequence(1), sep_piece.clone()]);
+        PostProcessor::Template {
+            single: vec![cls_piece, TemplatePiece::Sequence(0), sep_piece],
+            pair,
+            special_tokens: HashMap::from([(cls.0.clone(), vec![cls]), (sep.0.clone(), vec![sep])]),
+        }
+    }
+
+    fn validate(&self) -> ParseResult<()> {
+        if let PostProcessor::Template {
+            single,
+            pair,
+            special_tokens,
+        } = self
+        {
+            for piece in single.iter().chain(pair) {
+                if let TemplatePiece::SpecialToken(name) = piece
+                    && !special_tokens.contains_key(name)
+                {
+                    return Err(format!("missing special token `{name}` of the template"));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // Processes the tokens of one or two texts, given in their order along with the texts. Special tokens do not
+    // come from the texts and have empty offsets at their beginning, like in the original library.
+    pub(super) fn process(&self, tokens: Vec<PipelineToken>, texts: &[&str]) -> Vec<PipelineToken> {
+        match self {
+            PostProcessor::Template {
+                single,
+                pair,
+                special_tokens,
+            } => {
+                let template = if texts.len() > 1 { pair } else { single };
+                let mut processed = Vec::with_capacity(tokens.len() + template.len());
+                for piece in template {
+                    match piece {
+                        TemplatePiece::Sequence(sequence) => processed.extend(
+                            tokens
+                                .iter()
+                                .filter(|token| token.sequence == Some(*sequence))
+                                .cloned(),
+                        ),
+                        TemplatePiece::SpecialToken(name) => {
+                            processed.extend(special_tokens[name].iter().map(|(text, id)| {
+                                PipelineToken {
+                                    id: *id,
+                                    text: text.clone(),
+                                    range: 0..0,
+                                    sequence: None,
+                                }
+                            }))
+                        }
+                    }
+                }
+                processed
+            }
+            PostProcessor::ByteLevel { trim_offsets } => {
+                let mut tokens = tokens;
+                if *trim_offsets {
+                    for token in &mut tokens {
+                        let Some(sequence) = token.sequence else {
+                            continue;
+                        };
+                        let text = &texts[sequence][token.range.clone()];
+                        let start =
+                            token.range.start + (text.len() - text.trim_start_matches(' ').len());
+                        let end = token.range.end - (text.len() - text.trim_end_matches(' ').len());
+                        token.range = start..end.max(start);
+                    }
+                }
+                tokens
+            }
+            PostProcessor::Sequence(post_processors) => post_processors
+                .iter()
+                .fold(tokens, |tokens, post_processor| {
+                    post_processor.process(tokens, texts)
+                }),
+        }
+    }
+}
+
+fn parse_template_piece(value: &Value) -> ParseResult<TemplatePiece> {
+    let name = |piece: &Value| piece.get("id").and_then(Value::as_str).map(str::to_owned);
+    if let Some(sequence) = value.get("Sequence") {
+        match name(sequence).as_deref() {
+            Some("A") => return Ok(TemplatePiece::Sequence(0)),
+            Some("B") => return Ok(TemplatePiece::Sequence(1)),
+            _ => {}
+        }
+    } else if let Some(name) = value.get("SpecialToken").and_then(name) {
+        return Ok(TemplatePiece::SpecialToken(name));
+    }
+    Err(format!("invalid template piece {value}"))
+}
+
+// Token of the BERT and RoBERTa post-processors, given as its text and its id
+fn parse_token(value: &Value, name: &str) -> ParseResult<(String, u32)> {
+    match value.get(name).and_then(Value::as_array).map(Vec::as_slice) {
+        Some([Value::String(text), id]) => id
+            .as_u64()
+            .map(|id| (text.clone(), id as u32))
+            .ok_or_else(|| format!("invalid {name} token")),
+        _ => Err(format!("invalid {name} token")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn token(text: &str, id: u32, start: usize, end: usize, sequence: usize) -> PipelineToken {
+        PipelineToken {
+            id,
+            text: text.to_owned(),
+            range: start..end,
+            sequence: Some(sequence),
+        }
+    }
+
+    fn texts(tokens: &[PipelineToken]) -> Vec<&str> {
+        tokens.iter().map(|token| token.text.as_str()).collect()
+    }
+
+    #[test]
+    fn templates() {
+        let template = PostProcessor::parse(&json!({
+            "type": "TemplateProcessing",
+            "single": [
+                {"SpecialToken": {"id": "[CLS]", "type_id": 0}},
+                {"Sequence": {"id": "A", "type_id": 0}},
+                {"SpecialToken": {"id": "[SEP]", "type_id": 0}},
+            ],
+            "pair": [
+                {"Sequence": {"id": "A", "type_id": 0}},
+                {"SpecialToken": {"id": "[SEP]", "type_id": 0}},
+                {"Sequence": {"id": "B", "type_id": 1}},
+            ],
+            "special_tokens": {
+                "[CLS]": {"id": "[CLS]", "ids": [1], "tokens": ["[CLS]"]},
+                "[SEP]": {"id": "[SEP]", "ids": [2], "tokens": ["[SEP]"]},
+            },
+        }))
+        .unwrap();
+        let tokens = vec![token("a", 5, 0, 1, 0), token("b", 6, 0, 1, 1)];
+        assert_eq!(
+            texts(&template.process(tokens[..1].to_vec(), &["a"])),
+            ["[CLS]", "a", "[SEP]"]
+        );
+        assert_eq!(
+            texts(&template.process(tokens, &["a", "b"])),
+            ["a", "[SEP]", "b"]
+        );
+    }
+
+    #[test]
+    fn roberta() {
+        let roberta = PostProcessor::parse(&json!({
+            "type": "RobertaProcessing",
+            "sep": ["</s>", 2],
+            "cls": ["<s>", 0],
+            "trim_offsets": true,
+            "add_prefix_space": false,
+        }))
+        .unwrap();
+        let tokens = vec![token("ĠHi", 5, 0, 3, 0), token("Ġyou", 6, 0, 4, 1)];
+        let processed = roberta.process(tokens, &[" Hi", " you"]);
+        assert_eq!(
+            texts(&processed),
+            ["<s>", "ĠHi", "</s>", "</s>", "Ġyou", "</s>"]
+        );
+        assert_eq!(processed[1].range, 1..3);
+        assert_eq!(processed[4].range, 1..4);
+    }
+}
@@ -0,0 +1,335 @@
+use super::normalized::{NormalizedText, SplitBehavior};
+use super::{ParseResult, get_bool, get_pattern, get_str, get_type};
+use crate::byte_bpe::bytes_to_chars;
+use crate::moses::{Language, MosesTokenizer};
+use crate::wordpiece::is_punctuation;
+use fancy_regex::Regex;
+use serde_json::Value;
+use std::ops::Range;
+use std::str::FromStr;
+use std::sync::LazyLock;
+
+// Pattern of the ByteLevel pre-tokenizer of Hugging Face, which differs from the one of tiktoken in its trailing
+// whitespace
+static BYTE_LEVEL_PATTERN: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"'s|'t|'re|'ve|'m|'ll|'d| ?\p{L}+| ?\p{N}+| ?[^\s\p{L}\p{N}]+|\s+(?!\S)|\s+")
+        .unwrap()
+});
+
+static WHITESPACE_PATTERN: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\w+|[^\w\s]+").unwrap());
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum PrependScheme {
+    Always,
+    First,
+    Never,
+}
+
+// Pre-tokenizer of a Hugging Face pipeline, which splits normalized text into the pieces given to the model. Besides
+// the ones of Hugging Face, the Moses tokenizer splits text into the tokens of `tokenizer.perl`.
+#[derive(Debug, Clone)]
+pub(super) enum PreTokenizer {
+    Bert,
+    Whitespace,
+    WhitespaceSplit,
+    CharDelimiterSplit(char),
+    Punctuation(SplitBehavior),
+    Digits {
+        individual_digits: bool,
+    },
+    Split {
+        pattern: Regex,
+        behavior: SplitBehavior,
+        invert: bool,
+    },
+    Metaspace {
+        replacement: char,
+        prepend_scheme: PrependScheme,
+        split: bool,
+    },
+    ByteLevel {
+        add_prefix_space: bool,
+        use_regex: bool,
+    },
+    Moses(Box<MosesTokenizer>),
+    Sequence(Vec<PreTokenizer>),
+}
+
+impl PreTokenizer {
+    pub(super) fn parse(value: &Value) -> ParseResult<Self> {
+        Ok(match get_type(value)? {
+            "BertPreTokenizer" => PreTokenizer::Bert,
+            "Whitespace" => PreTokenizer::Whitespace,
+            "WhitespaceSplit" => PreTokenizer::WhitespaceSplit,
+            "CharDelimiterSplit" => {
+                let delimiter = get_str(value, "delimiter")?;
+                let mut chars = delimiter.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(delimiter), None) => PreTokenizer::CharDelimiterSplit(delimiter),
+                    _ => return Err(format!("invalid delimiter `{delimiter}`")),
+                }
+            }
+            "Punctuation" => PreTokenizer::Punctuation(match value.get("behavior") {
+                Some(Value::String(behavior)) => SplitBehavior::parse(behavior)?,
+                _ => SplitBehavior::Isolated,
+            }),
+            "Digits" => PreTokenizer::Digits {
+                individual_digits: get_bool(value, "individual_digits", false)?,
+            },
+            "Split" => PreTokenizer::Split {
+                pattern: get_pattern(value)?,
+                behavior: SplitBehavior::parse(get_str(value, "behavior")?)?,
+                invert: get_bool(value, "invert", false)?,
+            },
+            "Metaspace" => {
+                let replacement = get_str(value, "replacement")?;
+                let mut chars = replacement.chars();
+                let (Some(replacement), None) = (chars.next(), chars.next()) else {
+                    return Err(format!("invalid replacement `{replacement}`"));
+                };
+                // Older files have `add_prefix_space` instead of `prepend_scheme`
+                let prepend_scheme = match value.get("prepend_scheme") {
+                    Some(Value::String(scheme)) => match scheme.as_str() {
+                        "always" => PrependScheme::Always,
+                        "first" => PrependScheme::First,
+                        "never" => PrependScheme::Never,
+                        _ => return Err(format!("unsupported prepend scheme `{scheme}`")),
+                    },
+                    _ if get_bool(value, "add_prefix_space", true)? => PrependScheme::Always,
+                    _ => PrependScheme::Never,
+                };
+                PreTokenizer::Metaspace {
+                    replacement,
+                    prepend_scheme,
+                    split: get_bool(value, "split", true)?,
+                }
+            }
+            "ByteLevel" => PreTokenizer::ByteLevel {
+                add_prefix_space: get_bool(value, "add_prefix_space", true)?,
+                use_regex: get_bool(value, "use_regex", true)?,
+            },
+            "Moses" => {
+                let language = match value.get("language") {
+                    Some(Value::String(language)) => {
+                        Language::from_str(language).map_err(|e| e.to_string())?
+                    }
+                    _ => Language::En,
+                };
+                let moses = MosesTokenizer::builder(language)
+                    .no_escaping(true)
+                    .aggressive_hyphen_splitting(get_bool(value, "aggressive", false)?)
+                    .threads(1)
+                    .build()
+                    .map_err(|e| e.to_string())?;
+                PreTokenizer::Moses(Box::new(moses))
+            }
+            "Sequence" => PreTokenizer::Sequence(
+                value
+                    .get("pretokenizers")
+                    .and_then(Value::as_array)
+                    .ok_or_else(|| "missing pretokenizers of Sequence".to_owned())?
+                    .iter()
+                    .map(PreTokenizer::parse)
+                    .collect::<ParseResult<_>>()?,
+            ),
+            name => return Err(format!("unsupported pre-tokenizer `{name}`")),
+        })
+    }
+
+    // Splits text into pieces, where `first` tells whether the text starts the input rather than following an added
+    // token
+    pub(super) fn pre_tokenize(&self, text: NormalizedText, first: bool) -> Vec<NormalizedText> {
+        match self {
+            PreTokenizer::Bert => text
+                .split_chars(char::is_whitespace, SplitBehavior::Removed)
+                .into_iter()
+                .flat_map(|piece| piece.split_chars(is_punctuation, SplitBehavior::Isolated))
+                .collect(),
+            PreTokenizer::Whitespace => {
+                split_pattern(&text, &WHITESPACE_PATTERN, SplitBehavior::Removed, true)
+            }
+            PreTokenizer::WhitespaceSplit => {
+                text.split_chars(char::is_whitespace, SplitBehavior::Removed)
+            }
+            PreTokenizer::CharDelimiterSplit(delimiter) => {
+                text.split_chars(|ch| ch == *delimiter, SplitBehavior::Removed)
+            }
+            PreTokenizer::Punctuation(behavior) => text.split_chars(is_punctuation, *behavior),
+            PreTokenizer::Digits { individual_digits } => {
+                let behavior = if *individual_digits {
+                    SplitBehavior::Isolated
+                } else {
+                    SplitBehavior::Contiguous
+                };
+                text.split_chars(char::is_numeric, behavior)
+            }
+            PreTokenizer::Split {
+                pattern,
+                behavior,
+                invert,
+            } => split_pattern(&text, pattern, *behavior, *invert),
+            PreTokenizer::Metaspace {
+                replacement,
+                prepend_scheme,
+                split,
+            } => {
+                let mut text = text;
+                let replacement_str = replacement.to_string();
+                text.map_chars(|ch, piece| {
+                    if ch == ' ' {
+                        piece.push(*replacement);
+                    } else {
+                        piece.push(ch);
+                    }
+                });
+                let prepend = match prepend_scheme {
+                    PrependScheme::Always => true,
+                    PrependScheme::First => first,
+                    PrependScheme::Never => false,
+                };
+                if prepend && !text.as_str().starts_with(*replacement) {
+                    text.splice([(0..0, replacement_str)]);
+                }
+                if *split {
+                    text.split_chars(|ch| ch == *replacement, SplitBehavior::MergedWithNext)
+                } else {
+                    vec![text]
+                }
+            }
+            PreTokenizer::ByteLevel {
+                add_prefix_space,
+                use_regex,
+            } => {
+                let mut text = text;
+                if *add_prefix_space && !text.as_str().starts_with(' ') {
+                    text.splice([(0..0, " ".to_owned())]);
+                }
+                let mut pieces = if *use_regex {
+                    split_pattern(&text, &BYTE_LEVEL_PATTERN, SplitBehavior::Isolated, false)
+                } else {
+                    vec![text]
+                };
+                let mut bytes = [0; 4];
+                for piece in &mut pieces {
+                    piece.map_chars(|ch, replacement| {
+                        replacement
+                            .push_str(&bytes_to_chars(ch.encode_utf8(&mut bytes).as_bytes()));
+                    });
+                }
+                pieces
+            }
+            PreTokenizer::Moses(moses) => {
+                let spans: Vec<Range<usize>> = moses
+                    .tokenize_spans(text.as_str())
+                    .into_iter()
+                    .filter(|token| token.start < token.end)
+                    .map(|token| token.start..token.end)
+                    .collect();
+                spans.into_iter().map(|span| text.slice(span)).collect()
+            }
+            PreTokenizer::Sequence(pre_tokenizers) => {
+                let mut pieces = vec![text];
+                for pre_tokenizer in pre_tokenizers {
+                    pieces = pieces
+                        .into_iter()
+                        .enumerate()
+                        .flat_map(|(i, piece)| pre_tokenizer.pre_tokenize(piece, first && i == 0))
+                        .collect();
+                }
+                pieces
+            }
+        }
+    }
+}
+
+// Splits text with the matches of a pattern, or with the text between them when inverted. Text left after the
+// backtracking limit of the pattern is exceeded is not matched.
+fn split_pattern(
+    text: &NormalizedText,
+    pattern: &Regex,
+    behavior: SplitBehavior,
+    invert: bool,
+) -> Vec<NormalizedText> {
+    let matches = pattern
+        .find_iter(text.as_str())
+        .map_while(|found| found.ok())
+        .map(|found| found.range());
+    text.split(matches, behavior, invert)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn pre_tokenize(pre_tokenizer: Value, input: &str) -> Vec<(String, Range<usize>)> {
+        PreTokenizer::parse(&pre_tokenizer)
+            .unwrap()
+            .pre_tokenize(NormalizedText::new(input, 0), true)
+            .into_iter()
+            .map(|piece| {
+                let range = piece.original_range(0..piece.as_str().len());
+                (piece.as_str().to_owned(), range)
+            })
+            .collect()
+    }
+
+    fn texts(pieces: &[(String, Range<usize>)]) -> Vec<&str> {
+        pieces.iter().map(|(text, _)| text.as_str()).collect()
+    }
+
+    #[test]
+    fn bert_and_whitespace() {
+        let input = "Hey  friend!  How's it going?";
+        assert_eq!(
+            texts(&pre_tokenize(json!({"type": "BertPreTokenizer"}), input)),
+            ["Hey", "friend", "!", "How", "'", "s", "it", "going", "?"]
+        );
+        assert_eq!(
+            texts(&pre_tokenize(json!({"type": "Whitespace"}), input)),
+            ["Hey", "friend", "!", "How", "'", "s", "it", "going", "?"]
+        );
+        assert_eq!(
+            texts(&pre_tokenize(
+                json!({"type": "Sequence", "pretokenizers": [
+                    {"type": "WhitespaceSplit"},
+                    {"type": "Digits", "individual_digits": true},
+                ]}),
+                "a12 b"
+            )),
+            ["a", "1", "2", "b"]
+        );
+    }
+
+    #[test]
+    fn metaspace_and_byte_level() {
+        let metaspace = json!({"type": "Metaspace", "replacement": "▁", "prepend_scheme": "first"});
+        let pieces = pre_tokenize(metaspace.clone(), "Hey friend");
+        assert_eq!(texts(&pieces), ["▁Hey", "▁friend"]);
+        assert_eq!(pieces[1].1, 3..10);
+        let pieces = PreTokenizer::parse(&metaspace)
+            .unwrap()
+            .pre_tokenize(NormalizedText::new("Hey", 0), false);
+        assert_eq!(pieces[0].as_str(), "Hey");
+
+        let byte_level = json!({"type": "ByteLevel", "add_prefix_space": false, "use_regex": true});
+        let pieces = pre_tokenize(byte_level, "Hello my\tfriend é");
+        assert_eq!(texts(&pieces), ["Hello", "Ġmy", "ĉ", "friend", "ĠÃ©"]);
+        assert_eq!(pieces[4].1, 15..18);
+    }
+
+    #[test]
+    fn split_and_moses() {
+        let split = json!({
+            "type": "Split",
+            "pattern": {"Regex": "\\s+"},
+            "behavior": "MergedWithPrevious",
+            "invert": false,
+        });
+        assert_eq!(texts(&pre_tokenize(split, "a b  c")), ["a ", "b  ", "c"]);
+        let moses = json!({"type": "Moses", "language": "en"});
+        let pieces = pre_tokenize(moses, "Hello, world's end.");
+        assert_eq!(texts(&pieces), ["Hello", ",", "world", "'s", "end", "."]);
+        assert_eq!(pieces[3].1, 12..14);
+    }
+}
@@ -1 +1,14 @@
+pub mod bpe;
+pub mod byte_bpe;
+pub mod error;
+pub mod huggingface;
 pub mod moses;
+mod parallel;
+mod stream;
+pub mod tokenizer;
+pub mod unigram;
+pub mod whitespace;
+pub mod wordpiece;
+
+pub use error::{Error, Result};
+pub use tokenizer::{Detokenizer, Encoding, Token, Tokenizer, TokenizerRegistry};
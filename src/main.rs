@@ -1,32 +1,588 @@
-use clap::Parser;
+use clap::builder::{PossibleValue, PossibleValuesParser};
+use clap::{Args, CommandFactory, Parser, Subcommand};
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
 use std::process;
-use tokenaisu::moses::{Language, moses_tokenize_file};
+use tokenaisu::bpe::{
+    BpeCodes, BpeLearner, BpeTokenizer, count_words_reader, load_vocabulary, write_vocabulary,
+};
+use tokenaisu::byte_bpe::{ByteBpeModel, ByteBpePreset, ByteBpeTokenizer};
+use tokenaisu::moses::{
+    DEFAULT_BATCH_LINES, Language, MosesTokenizer, NonbreakingPrefixes, ProtectedPreset,
+    SentenceSplitter, TruecaserModel, deescape_special_chars_reader, detruecase_reader,
+    escape_special_chars_reader, normalize_punctuation_reader, truecase_reader,
+};
+use tokenaisu::tokenizer::TokenizerOptions;
+use tokenaisu::unigram::UnigramTrainer;
+use tokenaisu::wordpiece::WordPieceTokenizer;
+use tokenaisu::{Error, TokenizerRegistry};
 
+/// Runs the Moses-like tokenizer when no subcommand is given
 #[derive(Parser, Debug)]
-#[command(version, about, long_about = None)]
-struct Args {
+#[command(version, about, long_about = None, args_conflicts_with_subcommands = true)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    tokenize: Option<TokenizeArgs>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Tokenize text (the default when no subcommand is given)
+    Tokenize(TokenizeArgs),
+    /// Normalize punctuation, like normalize-punctuation.perl
+    Normalize(NormalizeArgs),
+    /// Split paragraphs into one sentence per line, like split-sentences.perl
+    SplitSentences(SplitSentencesArgs),
+    /// Train a truecasing model from tokenized text, like train-truecaser.perl
+    TrainTruecaser(TrainTruecaserArgs),
+    /// Truecase tokenized text with a trained model, like truecase.perl
+    Truecase(TruecaseArgs),
+    /// Capitalize the first word of every sentence, like detruecase.perl
+    Detruecase(DetruecaseArgs),
+    /// Escape special characters, like escape-special-chars.perl
+    Escape(EscapeArgs),
+    /// Revert the escaping of special characters, like deescape-special-chars.perl
+    Deescape(EscapeArgs),
+    /// Learn BPE merges from tokenized text, like subword-nmt learn-bpe
+    LearnBpe(LearnBpeArgs),
+    /// Split tokenized text into subwords with BPE merges, like subword-nmt apply-bpe
+    ApplyBpe(ApplyBpeArgs),
+    /// Train a SentencePiece Unigram model from raw text, like spm_train --model_type=unigram
+    TrainUnigram(TrainUnigramArgs),
+    /// Count the byte-level BPE tokens of files, like len(tiktoken encode())
+    CountTokens(CountTokensArgs),
+}
+
+#[derive(Args, Debug)]
+struct TokenizeArgs {
+    /// Tokenizer to use, the rest of options apply to the Moses tokenizer unless stated otherwise
+    #[arg(short, long, default_value = "moses", value_parser = tokenizer_names())]
+    tokenizer: String,
+
+    /// Model file of the tokenizers that need one, such as the codes of bpe, the vocab.txt of wordpiece,
+    /// the .model of unigram, the vocab.json or .tiktoken file of byte_bpe or the tokenizer.json of huggingface
+    #[arg(short, long)]
+    model: Option<String>,
+
+    /// Keep the case and accents of the text, for the wordpiece tokenizer of cased models
+    #[arg(long)]
+    cased: bool,
+
+    /// Pre-tokenization pattern and special tokens of the byte_bpe tokenizer, detected from the model by default
+    #[arg(long)]
+    preset: Option<ByteBpePreset>,
+
+    /// Replace the pre-tokenizer of the huggingface pipeline with the Moses tokenizer and its options
+    #[arg(long)]
+    moses_pre_tokenizer: bool,
+
+    /// Language of the text, required by the moses tokenizer and --moses-pre-tokenizer
+    #[arg(short, long)]
+    language: Option<Language>,
+
+    /// Input file, or "-" to read from stdin
+    #[arg(short, long, default_value = "-")]
+    input_file_path: String,
+
+    /// Output file, or "-" to write to stdout
+    #[arg(short, long, default_value = "-")]
+    output_file_path: String,
+
+    /// Do not escape special characters (&, |, <, >, ', ", [, ])
+    #[arg(long)]
+    no_escape: bool,
+
+    /// Split hyphens between alphanumeric characters as " @-@ "
+    #[arg(short, long)]
+    aggressive: bool,
+
+    /// Use Penn Treebank tokenization conventions
+    #[arg(long)]
+    penn: bool,
+
+    /// Pass lines consisting solely of XML/SGML tags through unchanged
+    #[arg(short = 'x', long)]
+    skip_xml: bool,
+
+    /// Keep inline XML/SGML tags verbatim as separate tokens
+    #[arg(long)]
+    protect_xml_tags: bool,
+
+    /// Comma-separated list of built-in protected patterns
+    #[arg(long, value_delimiter = ',')]
+    protect: Vec<ProtectedPreset>,
+
+    /// Regex pattern whose matches are kept as single tokens (can be repeated)
+    #[arg(long = "protected-pattern")]
+    protected_patterns: Vec<String>,
+
+    /// File with one protected regex pattern per line
+    #[arg(long)]
+    protected_patterns_file: Option<String>,
+
+    /// Moses nonbreaking prefixes file, whose prefixes are added to the built-in ones of the language
+    #[arg(long)]
+    prefixes_file: Option<String>,
+
+    /// Use only the prefixes of --prefixes-file instead of adding them to the built-in ones
+    #[arg(long, requires = "prefixes_file")]
+    no_builtin_prefixes: bool,
+
+    /// Number of threads of any tokenizer, where 0 uses one per core and 1 tokenizes sequentially
+    #[arg(long, default_value_t = 0)]
+    threads: usize,
+
+    /// Number of lines tokenized by each thread at a time by any tokenizer, like the -lines option of
+    /// tokenizer.perl
+    #[arg(long, default_value_t = DEFAULT_BATCH_LINES)]
+    batch_lines: usize,
+}
+
+#[derive(Args, Debug)]
+struct NormalizeArgs {
+    #[arg(short, long)]
+    language: Language,
+
+    /// Input file, or "-" to read from stdin
+    #[arg(short, long, default_value = "-")]
+    input_file_path: String,
+
+    /// Output file, or "-" to write to stdout
+    #[arg(short, long, default_value = "-")]
+    output_file_path: String,
+
+    /// Keep backticks and double single quotes, as used in Penn Treebank style text
+    #[arg(long)]
+    penn: bool,
+
+    /// Replace full-width and CJK punctuation first, like replace-unicode-punctuation.perl
+    #[arg(long)]
+    pre_replace_unicode_punct: bool,
+
+    /// Remove control and other non-printing characters last
+    #[arg(long)]
+    post_remove_control_chars: bool,
+}
+
+#[derive(Args, Debug)]
+struct SplitSentencesArgs {
     #[arg(short, long)]
     language: Language,
 
+    /// Input file, or "-" to read from stdin
+    #[arg(short, long, default_value = "-")]
+    input_file_path: String,
+
+    /// Output file, or "-" to write to stdout
+    #[arg(short, long, default_value = "-")]
+    output_file_path: String,
+
+    /// Do not write a <P> line at the end of each paragraph
     #[arg(short, long)]
+    no_paragraph_markers: bool,
+
+    /// Moses nonbreaking prefixes file, whose prefixes are added to the built-in ones of the language
+    #[arg(long)]
+    prefixes_file: Option<String>,
+
+    /// Use only the prefixes of --prefixes-file instead of adding them to the built-in ones
+    #[arg(long, requires = "prefixes_file")]
+    no_builtin_prefixes: bool,
+}
+
+#[derive(Args, Debug)]
+struct TrainTruecaserArgs {
+    /// Output model file
+    #[arg(short, long)]
+    model: String,
+
+    /// Tokenized training corpus, or "-" to read from stdin
+    #[arg(short, long, default_value = "-")]
     input_file_path: String,
 
+    /// Also count the first word of a sentence when it is not capitalized or is alone in its line
+    #[arg(long)]
+    possibly_use_first_token: bool,
+}
+
+#[derive(Args, Debug)]
+struct TruecaseArgs {
+    /// Model file trained with train-truecaser
     #[arg(short, long)]
+    model: String,
+
+    /// Input file, or "-" to read from stdin
+    #[arg(short, long, default_value = "-")]
+    input_file_path: String,
+
+    /// Output file, or "-" to write to stdout
+    #[arg(short, long, default_value = "-")]
+    output_file_path: String,
+}
+
+#[derive(Args, Debug)]
+struct DetruecaseArgs {
+    /// Input file, or "-" to read from stdin
+    #[arg(short, long, default_value = "-")]
+    input_file_path: String,
+
+    /// Output file, or "-" to write to stdout
+    #[arg(short, long, default_value = "-")]
+    output_file_path: String,
+}
+
+#[derive(Args, Debug)]
+struct EscapeArgs {
+    /// Input file, or "-" to read from stdin
+    #[arg(short, long, default_value = "-")]
+    input_file_path: String,
+
+    /// Output file, or "-" to write to stdout
+    #[arg(short, long, default_value = "-")]
     output_file_path: String,
 }
 
+#[derive(Args, Debug)]
+struct LearnBpeArgs {
+    /// Tokenized training corpus, or "-" to read from stdin
+    #[arg(short, long, default_value = "-")]
+    input_file_path: String,
+
+    /// Output codes file, or "-" to write to stdout
+    #[arg(short, long, default_value = "-")]
+    output_file_path: String,
+
+    /// Number of merge operations to learn
+    #[arg(short, long, default_value_t = 10000)]
+    symbols: usize,
+
+    /// Stop learning when the most frequent pair is seen fewer times than this
+    #[arg(long, default_value_t = 2)]
+    min_frequency: u64,
+
+    /// Also write the subwords of the training corpus along with their counts, for --vocabulary of apply-bpe
+    #[arg(long)]
+    write_vocabulary: Option<String>,
+}
+
+#[derive(Args, Debug)]
+struct ApplyBpeArgs {
+    /// Codes file written by learn-bpe or subword-nmt
+    #[arg(short, long)]
+    codes: String,
+
+    /// Input file, or "-" to read from stdin
+    #[arg(short, long, default_value = "-")]
+    input_file_path: String,
+
+    /// Output file, or "-" to write to stdout
+    #[arg(short, long, default_value = "-")]
+    output_file_path: String,
+
+    /// Separator appended to every subword that does not end a word
+    #[arg(short, long, default_value = "@@")]
+    separator: String,
+
+    /// Vocabulary file written by --write-vocabulary of learn-bpe, whose subwords are the only ones produced
+    #[arg(long)]
+    vocabulary: Option<String>,
+
+    /// Leave out the subwords of --vocabulary seen fewer times than this
+    #[arg(long, requires = "vocabulary")]
+    vocabulary_threshold: Option<u64>,
+}
+
+#[derive(Args, Debug)]
+struct TrainUnigramArgs {
+    /// Output .model file
+    #[arg(short, long)]
+    model: String,
+
+    /// Raw training corpus, or "-" to read from stdin
+    #[arg(short, long, default_value = "-")]
+    input_file_path: String,
+
+    /// Number of pieces of the model, including <unk>, <s>, </s>, the user-defined symbols and the byte pieces
+    #[arg(long, default_value_t = 8000)]
+    vocab_size: usize,
+
+    /// Fraction of the characters of the corpus that are pieces, leaving out the rarest ones
+    #[arg(long, default_value_t = 0.9995)]
+    character_coverage: f64,
+
+    /// Length of the longest pieces in characters
+    #[arg(long, default_value_t = 16)]
+    max_piece_length: usize,
+
+    /// Split unknown characters into byte pieces instead of <unk>
+    #[arg(long)]
+    byte_fallback: bool,
+
+    /// Pieces that are always produced when found in the text, separated by commas
+    #[arg(long, value_delimiter = ',')]
+    user_defined_symbols: Vec<String>,
+}
+
+#[derive(Args, Debug)]
+struct CountTokensArgs {
+    /// GPT-2 vocab.json file, with merges.txt in the same directory, or tiktoken rank file
+    #[arg(short, long)]
+    model: String,
+
+    /// Pre-tokenization pattern and special tokens, detected from the model by default
+    #[arg(long)]
+    preset: Option<ByteBpePreset>,
+
+    /// Input files, or "-" to read from stdin
+    #[arg(default_value = "-")]
+    input_file_paths: Vec<String>,
+}
+
+// Names of the tokenizers of the registry, along with their descriptions for the help
+fn tokenizer_names() -> PossibleValuesParser {
+    PossibleValuesParser::new(
+        TokenizerRegistry::builtin()
+            .entries()
+            .iter()
+            .map(|entry| PossibleValue::new(entry.name).help(entry.about)),
+    )
+}
+
 fn main() {
-    let args = Args::parse();
-
-    if let Err(e) = moses_tokenize_file(
-        &args.input_file_path,
-        &args.output_file_path,
-        Language::En,
-        true,
-        false,
-        &[],
-    ) {
-        println!("Application error: {e}");
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Some(Command::Tokenize(args)) => tokenize(args),
+        Some(Command::Normalize(args)) => normalize(args),
+        Some(Command::SplitSentences(args)) => split(args),
+        Some(Command::TrainTruecaser(args)) => train_truecaser(args),
+        Some(Command::Truecase(args)) => truecase(args),
+        Some(Command::Detruecase(args)) => detruecase(args),
+        Some(Command::Escape(args)) => escape(args),
+        Some(Command::Deescape(args)) => deescape(args),
+        Some(Command::LearnBpe(args)) => learn_bpe(args),
+        Some(Command::ApplyBpe(args)) => apply_bpe(args),
+        Some(Command::TrainUnigram(args)) => train_unigram(args),
+        Some(Command::CountTokens(args)) => count_tokens(args),
+        None => match cli.tokenize {
+            Some(args) => tokenize(args),
+            // Flattened arguments are optional so that subcommands can be used, which makes clap skip the
+            // `--language` requirement when no argument is given at all
+            None => Cli::command()
+                .error(
+                    clap::error::ErrorKind::MissingRequiredArgument,
+                    "the following required arguments were not provided:\n  --language <LANGUAGE>",
+                )
+                .exit(),
+        },
+    };
+    if let Err(e) = result {
+        eprintln!("Application error: {e}");
         process::exit(1);
     }
 }
+
+fn tokenize(args: TokenizeArgs) -> Result<(), Error> {
+    let mut protected_patterns = args.protected_patterns;
+    if let Some(protected_patterns_file) = &args.protected_patterns_file {
+        protected_patterns.extend(
+            fs::read_to_string(protected_patterns_file)
+                .map_err(|e| Error::io(protected_patterns_file, e))?
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .map(str::to_owned),
+        );
+    }
+    let protected_patterns: Vec<&str> = protected_patterns.iter().map(String::as_str).collect();
+
+    // Build the tokenizer before opening any file so that invalid patterns do not truncate the output
+    // The registry sets the language of the Moses tokenizer, and requires it only when it is built
+    let mut builder = MosesTokenizer::builder(args.language.clone().unwrap_or(Language::Generic));
+    if let Some(prefixes_file) = &args.prefixes_file {
+        let prefixes = NonbreakingPrefixes::load(prefixes_file)?;
+        builder = if args.no_builtin_prefixes {
+            builder.nonbreaking_prefixes(prefixes)
+        } else {
+            builder.extra_nonbreaking_prefixes(&prefixes)
+        };
+    }
+    let moses = builder
+        .no_escaping(args.no_escape)
+        .aggressive_hyphen_splitting(args.aggressive)
+        .penn(args.penn)
+        .skip_xml(args.skip_xml)
+        .protect_xml_tags(args.protect_xml_tags)
+        .protected_presets(&args.protect)
+        .protected_patterns(&protected_patterns);
+    let mut byte_bpe = ByteBpeTokenizer::builder();
+    if let Some(preset) = args.preset {
+        byte_bpe = byte_bpe.preset(preset);
+    }
+    let options = TokenizerOptions {
+        language: args.language,
+        moses,
+        wordpiece: WordPieceTokenizer::builder().lowercase(!args.cased),
+        byte_bpe,
+        moses_pre_tokenizer: args.moses_pre_tokenizer,
+        model: args.model,
+        threads: args.threads,
+        batch_lines: args.batch_lines,
+    };
+    let tokenizer = TokenizerRegistry::builtin().build(&args.tokenizer, &options)?;
+
+    tokenizer.tokenize_reader(
+        &mut open_reader(&args.input_file_path)?,
+        &mut open_writer(&args.output_file_path)?,
+    )
+}
+
+fn normalize(args: NormalizeArgs) -> Result<(), Error> {
+    normalize_punctuation_reader(
+        open_reader(&args.input_file_path)?,
+        open_writer(&args.output_file_path)?,
+        args.language,
+        args.penn,
+        args.pre_replace_unicode_punct,
+        args.post_remove_control_chars,
+    )
+}
+
+fn split(args: SplitSentencesArgs) -> Result<(), Error> {
+    let mut splitter = SentenceSplitter::new(args.language);
+    if let Some(prefixes_file) = &args.prefixes_file {
+        let prefixes = NonbreakingPrefixes::load(prefixes_file)?;
+        splitter = if args.no_builtin_prefixes {
+            splitter.nonbreaking_prefixes(prefixes)
+        } else {
+            splitter.extra_nonbreaking_prefixes(&prefixes)
+        };
+    }
+    splitter.split_reader(
+        open_reader(&args.input_file_path)?,
+        open_writer(&args.output_file_path)?,
+        !args.no_paragraph_markers,
+    )
+}
+
+fn train_truecaser(args: TrainTruecaserArgs) -> Result<(), Error> {
+    let model = if args.input_file_path == "-" {
+        TruecaserModel::train_reader(open_reader("-")?, args.possibly_use_first_token)?
+    } else {
+        TruecaserModel::train_file(&args.input_file_path, args.possibly_use_first_token)?
+    };
+    model.save(&args.model)
+}
+
+fn truecase(args: TruecaseArgs) -> Result<(), Error> {
+    // Load the model before opening any file so that an invalid model does not truncate the output
+    let model = TruecaserModel::load(&args.model)?;
+    truecase_reader(
+        open_reader(&args.input_file_path)?,
+        open_writer(&args.output_file_path)?,
+        &model,
+    )
+}
+
+fn detruecase(args: DetruecaseArgs) -> Result<(), Error> {
+    detruecase_reader(
+        open_reader(&args.input_file_path)?,
+        open_writer(&args.output_file_path)?,
+    )
+}
+
+fn escape(args: EscapeArgs) -> Result<(), Error> {
+    escape_special_chars_reader(
+        open_reader(&args.input_file_path)?,
+        open_writer(&args.output_file_path)?,
+    )
+}
+
+fn deescape(args: EscapeArgs) -> Result<(), Error> {
+    deescape_special_chars_reader(
+        open_reader(&args.input_file_path)?,
+        open_writer(&args.output_file_path)?,
+    )
+}
+
+fn learn_bpe(args: LearnBpeArgs) -> Result<(), Error> {
+    let word_counts = count_words_reader(open_reader(&args.input_file_path)?)?;
+    let codes = BpeLearner::new(args.symbols)
+        .min_frequency(args.min_frequency)
+        .learn_counts(&word_counts);
+    codes.write(open_writer(&args.output_file_path)?)?;
+    if let Some(vocabulary_file_path) = &args.write_vocabulary {
+        let subword_counts = BpeTokenizer::new(&codes).subword_counts(&word_counts);
+        write_vocabulary(&subword_counts, open_writer(vocabulary_file_path)?)?;
+    }
+    Ok(())
+}
+
+fn apply_bpe(args: ApplyBpeArgs) -> Result<(), Error> {
+    // Load the codes before opening any file so that invalid codes do not truncate the output
+    let mut tokenizer = BpeTokenizer::new(&BpeCodes::load(&args.codes)?).separator(&args.separator);
+    if let Some(vocabulary_file_path) = &args.vocabulary {
+        tokenizer = tokenizer.vocabulary(load_vocabulary(
+            vocabulary_file_path,
+            args.vocabulary_threshold,
+        )?);
+    }
+    tokenizer.apply_reader(
+        open_reader(&args.input_file_path)?,
+        open_writer(&args.output_file_path)?,
+    )
+}
+
+fn train_unigram(args: TrainUnigramArgs) -> Result<(), Error> {
+    let trainer = UnigramTrainer::new(args.vocab_size)
+        .character_coverage(args.character_coverage)
+        .max_piece_length(args.max_piece_length)
+        .byte_fallback(args.byte_fallback)
+        .user_defined_symbols(args.user_defined_symbols);
+    let model = if args.input_file_path == "-" {
+        trainer.train_reader(open_reader("-")?)?
+    } else {
+        trainer.train_file(&args.input_file_path)?
+    };
+    model.save(&args.model)
+}
+
+fn count_tokens(args: CountTokensArgs) -> Result<(), Error> {
+    let mut builder = ByteBpeTokenizer::builder();
+    if let Some(preset) = args.preset {
+        builder = builder.preset(preset);
+    }
+    let tokenizer = builder.build(ByteBpeModel::load(&args.model)?)?;
+    let mut writer = open_writer("-")?;
+    let mut total = 0;
+    for input_file_path in &args.input_file_paths {
+        let count = if input_file_path == "-" {
+            tokenizer.count_reader(open_reader("-")?)?
+        } else {
+            tokenizer.count_file(input_file_path)?
+        };
+        writeln!(writer, "{count}\t{input_file_path}")?;
+        total += count;
+    }
+    writeln!(writer, "{total}\ttotal")?;
+    writer.flush()?;
+    Ok(())
+}
+
+fn open_reader(input_file_path: &str) -> Result<Box<dyn BufRead>, Error> {
+    if input_file_path == "-" {
+        return Ok(Box::new(io::stdin().lock()));
+    }
+    let file = File::open(input_file_path).map_err(|e| Error::io(input_file_path, e))?;
+    Ok(Box::new(BufReader::new(file)))
+}
+
+fn open_writer(output_file_path: &str) -> Result<Box<dyn Write>, Error> {
+    if output_file_path == "-" {
+        return Ok(Box::new(BufWriter::new(io::stdout().lock())));
+    }
+    let file = File::create(output_file_path).map_err(|e| Error::io(output_file_path, e))?;
+    Ok(Box::new(BufWriter::new(file)))
+}
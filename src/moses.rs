@@ -1,11 +1,52 @@
-use nonbreaking_prefixes::{NONBREAKING_PREFIXES, PrefixType};
+use crate::error::{Error, Result};
+use crate::parallel::Executor;
+use crate::stream::map_lines_batched;
+pub use crate::tokenizer::Token;
+use crate::tokenizer::{Detokenizer, Tokenizer};
+use escape::{ESCAPES, PENN_ESCAPES, escape_chars};
+use penn::PENN_SYMBOLS;
 use regex::Regex;
-use std::collections::HashMap;
-use std::fs;
+use std::borrow::Cow;
+use std::collections::BTreeSet;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
 use strum_macros;
+mod detokenizer;
+mod engine;
+mod escape;
 mod nonbreaking_prefixes;
-use rayon::prelude::*;
-use std::sync::LazyLock;
+mod normalizer;
+mod penn;
+mod prefix_file;
+mod prefixes;
+mod protected_presets;
+#[cfg(any(test, feature = "bench-reference"))]
+mod reference;
+mod splitter;
+mod truecase;
+use clap::ValueEnum;
+pub use detokenizer::{moses_detokenize, moses_detokenize_file, moses_detokenize_line};
+pub use escape::{
+    deescape_special_chars, deescape_special_chars_file, deescape_special_chars_reader,
+    escape_special_chars, escape_special_chars_file, escape_special_chars_reader,
+};
+pub use normalizer::{
+    normalize_punctuation, normalize_punctuation_file, normalize_punctuation_line,
+    normalize_punctuation_reader, replace_unicode_punctuation,
+};
+pub use prefix_file::PrefixType;
+pub use prefixes::NonbreakingPrefixes;
+pub use protected_presets::ProtectedPreset;
+use rayon::ThreadPool;
+pub use splitter::{
+    SentenceSplitter, split_sentences, split_sentences_file, split_sentences_reader,
+};
+use std::str::FromStr;
+use std::sync::{Arc, LazyLock};
+pub use truecase::{
+    TruecaserModel, detruecase_file, detruecase_line, detruecase_reader, truecase_file,
+    truecase_line, truecase_reader,
+};
 
 #[derive(Debug, PartialEq, strum_macros::AsRefStr, Clone, clap::ValueEnum)]
 #[strum(serialize_all = "lowercase")]
@@ -49,367 +90,587 @@ pub enum Language {
     Te,
     Yue,
     Zh,
+    /// Any other language. Like unknown language codes in the original Moses scripts, apostrophes are split on both
+    /// sides, but unlike them, which fall back to the English nonbreaking prefixes, no nonbreaking prefixes are used
+    /// so that no rules meant for another language are applied
+    #[strum(serialize = "xx")]
+    #[value(name = "xx")]
+    Generic,
 }
 
-pub fn moses_tokenize_file(
-    input_file_path: &str,
-    output_file_path: &str,
-    language: Language,
-    no_escaping: bool,
-    aggresive_hyphen_splitting: bool,
-    protected_patterns: &[&str],
-) -> Result<(), std::io::Error> {
-    let contents = fs::read_to_string(input_file_path)?;
-    let tokenized_contents = moses_tokenize(
-        &contents,
-        language,
-        no_escaping,
-        aggresive_hyphen_splitting,
-        protected_patterns,
-    );
-    fs::write(output_file_path, tokenized_contents)
+impl FromStr for Language {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Language::value_variants()
+            .iter()
+            .find(|language| language.as_ref() == s)
+            .cloned()
+            .ok_or_else(|| Error::UnsupportedLanguage(s.to_owned()))
+    }
 }
 
-pub fn moses_tokenize(
-    text: &str,
+#[derive(Debug, Clone)]
+pub struct MosesTokenizerBuilder {
     language: Language,
     no_escaping: bool,
     aggresive_hyphen_splitting: bool,
-    protected_patterns: &[&str],
-) -> String {
-    let protected_patterns_regexes: Vec<Regex> = protected_patterns
-        .iter()
-        .map(|t| Regex::new(t).unwrap())
-        .collect();
-    text.par_lines()
-        .map(|line| {
-            moses_tokenize_line(
-                line,
-                language.clone(),
-                no_escaping,
-                aggresive_hyphen_splitting,
-                &protected_patterns_regexes,
-            )
+    penn: bool,
+    skip_xml: bool,
+    protect_xml_tags: bool,
+    protected_presets: BTreeSet<ProtectedPreset>,
+    protected_patterns: Vec<String>,
+    nonbreaking_prefixes: Option<NonbreakingPrefixes>,
+    extra_nonbreaking_prefixes: NonbreakingPrefixes,
+    threads: usize,
+    thread_pool: Option<Arc<ThreadPool>>,
+    batch_lines: usize,
+}
+
+/// Number of lines tokenized per parallel task by default
+pub const DEFAULT_BATCH_LINES: usize = 100;
+
+impl MosesTokenizerBuilder {
+    pub fn new(language: Language) -> Self {
+        MosesTokenizerBuilder {
+            language,
+            no_escaping: false,
+            aggresive_hyphen_splitting: false,
+            penn: false,
+            skip_xml: false,
+            protect_xml_tags: false,
+            protected_presets: BTreeSet::new(),
+            protected_patterns: Vec::new(),
+            nonbreaking_prefixes: None,
+            extra_nonbreaking_prefixes: NonbreakingPrefixes::new(),
+            threads: 0,
+            thread_pool: None,
+            batch_lines: DEFAULT_BATCH_LINES,
+        }
+    }
+
+    pub fn language(mut self, language: Language) -> Self {
+        self.language = language;
+        self
+    }
+
+    pub fn no_escaping(mut self, no_escaping: bool) -> Self {
+        self.no_escaping = no_escaping;
+        self
+    }
+
+    pub fn aggressive_hyphen_splitting(mut self, aggresive_hyphen_splitting: bool) -> Self {
+        self.aggresive_hyphen_splitting = aggresive_hyphen_splitting;
+        self
+    }
+
+    /// Use Penn Treebank conventions (``/'' quotes, -LRB-/-RRB- brackets, n't splitting...) instead of the default
+    /// Moses ones, like the `-penn` option of the original tokenizer
+    pub fn penn(mut self, penn: bool) -> Self {
+        self.penn = penn;
+        self
+    }
+
+    /// Pass lines consisting solely of XML/SGML tags through unchanged, like the `-x` option of the original tokenizer
+    pub fn skip_xml(mut self, skip_xml: bool) -> Self {
+        self.skip_xml = skip_xml;
+        self
+    }
+
+    /// Keep inline XML/SGML tags verbatim as separate tokens instead of tokenizing and escaping them
+    pub fn protect_xml_tags(mut self, protect_xml_tags: bool) -> Self {
+        self.protect_xml_tags = protect_xml_tags;
+        self
+    }
+
+    /// Built-in protected patterns are always applied before custom ones, in the order of [`ProtectedPreset`]
+    pub fn protected_preset(mut self, protected_preset: ProtectedPreset) -> Self {
+        self.protected_presets.insert(protected_preset);
+        self
+    }
+
+    pub fn protected_presets(mut self, protected_presets: &[ProtectedPreset]) -> Self {
+        self.protected_presets.extend(protected_presets);
+        self
+    }
+
+    pub fn protected_pattern(mut self, protected_pattern: &str) -> Self {
+        self.protected_patterns.push(protected_pattern.to_owned());
+        self
+    }
+
+    pub fn protected_patterns(mut self, protected_patterns: &[&str]) -> Self {
+        self.protected_patterns
+            .extend(protected_patterns.iter().map(|t| t.to_string()));
+        self
+    }
+
+    /// Replaces the built-in nonbreaking prefixes of the language
+    pub fn nonbreaking_prefixes(mut self, nonbreaking_prefixes: NonbreakingPrefixes) -> Self {
+        self.nonbreaking_prefixes = Some(nonbreaking_prefixes);
+        self
+    }
+
+    /// Adds nonbreaking prefixes on top of the built-in ones of the language (or the ones given with
+    /// [`Self::nonbreaking_prefixes`])
+    pub fn extra_nonbreaking_prefixes(
+        mut self,
+        nonbreaking_prefixes: &NonbreakingPrefixes,
+    ) -> Self {
+        self.extra_nonbreaking_prefixes.merge(nonbreaking_prefixes);
+        self
+    }
+
+    /// Number of threads used to tokenize texts, files and readers on a dedicated pool. 0 (the default) uses
+    /// Rayon's global pool, and 1 tokenizes sequentially on the calling thread, which suits callers that are
+    /// already parallel
+    pub fn threads(mut self, threads: usize) -> Self {
+        self.threads = threads;
+        self
+    }
+
+    /// Tokenizes texts, files and readers on an existing pool, which takes precedence over [`Self::threads`]
+    pub fn thread_pool(mut self, thread_pool: Arc<ThreadPool>) -> Self {
+        self.thread_pool = Some(thread_pool);
+        self
+    }
+
+    /// Number of lines tokenized by each parallel task, like the `-lines` option of the original tokenizer.
+    /// Larger batches reduce the scheduling overhead of short lines. Defaults to [`DEFAULT_BATCH_LINES`]
+    pub fn batch_lines(mut self, batch_lines: usize) -> Self {
+        self.batch_lines = batch_lines.max(1);
+        self
+    }
+
+    pub fn build(self) -> Result<MosesTokenizer> {
+        let mut protected_patterns: Vec<Regex> = self
+            .protected_presets
+            .iter()
+            .map(|preset| preset.regex().clone())
+            .collect();
+        for (index, pattern) in self.protected_patterns.iter().enumerate() {
+            protected_patterns.push(Regex::new(pattern).map_err(|source| {
+                Error::InvalidPattern {
+                    pattern: pattern.clone(),
+                    index,
+                    source,
+                }
+            })?);
+        }
+        let mut nonbreaking_prefixes = self
+            .nonbreaking_prefixes
+            .unwrap_or_else(|| NonbreakingPrefixes::builtin(&self.language));
+        nonbreaking_prefixes.merge(&self.extra_nonbreaking_prefixes);
+        let executor = match self.thread_pool {
+            Some(thread_pool) => Executor::Pool(thread_pool),
+            None => Executor::with_threads(self.threads)?,
+        };
+        Ok(MosesTokenizer {
+            language: self.language,
+            no_escaping: self.no_escaping,
+            aggresive_hyphen_splitting: self.aggresive_hyphen_splitting,
+            penn: self.penn,
+            skip_xml: self.skip_xml,
+            protect_xml_tags: self.protect_xml_tags,
+            protected_patterns,
+            nonbreaking_prefixes,
+            executor,
+            batch_lines: self.batch_lines,
         })
-        .collect::<String>()
+    }
 }
 
-pub fn moses_tokenize_line(
-    text: &str,
+/// Moses tokenizer holding its configuration and compiled protected patterns, meant to be built once
+/// with [`MosesTokenizerBuilder`] and shared across threads
+#[derive(Debug, Clone)]
+pub struct MosesTokenizer {
     language: Language,
     no_escaping: bool,
     aggresive_hyphen_splitting: bool,
-    protected_patterns: &Vec<Regex>,
-) -> String {
-    let mut tokenized_text = text
-        // Remove trailing newline character
-        .trim_end_matches('\n')
-        // Replace all sequences of whitespaces with a single ASCII whitespace while trimming text
-        // This is done for any type of Unicode space (incl. tabs)
-        .split_whitespace()
-        .collect::<Vec<&str>>()
-        .join(" ");
-
-    // Add spaces at the beginning and end of the text
-    tokenized_text.insert(0, ' ');
-    tokenized_text.push(' ');
-
-    // Remove ASCII characters 0-31 (works because the first 128 ASCII chars match the first 128 unicode chars)
-    tokenized_text = tokenized_text.chars().filter(|&ch| ch as u8 > 31).collect();
-
-    // Capture protected patterns and replace them with unique substitution strings
-    let mut found_protected_patterns: HashMap<String, String> = HashMap::new();
-    for re_pattern in protected_patterns {
-        tokenized_text = re_pattern
-            .replace_all(&text, |caps: &regex::Captures| {
-                let substitution = format!("THISISPROTECTED{:03}", found_protected_patterns.len());
-                found_protected_patterns.insert(substitution.clone(), caps[0].to_owned());
-                substitution
+    penn: bool,
+    skip_xml: bool,
+    protect_xml_tags: bool,
+    protected_patterns: Vec<Regex>,
+    nonbreaking_prefixes: NonbreakingPrefixes,
+    executor: Executor,
+    batch_lines: usize,
+}
+
+static RE_XML_LINE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\s*(<[^<>\s][^<>]*>\s*)+$").unwrap());
+static RE_XML_TAG: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"<[^<>\s][^<>]*>").unwrap());
+
+impl MosesTokenizer {
+    pub fn builder(language: Language) -> MosesTokenizerBuilder {
+        MosesTokenizerBuilder::new(language)
+    }
+
+    pub fn language(&self) -> &Language {
+        &self.language
+    }
+
+    pub fn tokenize_file(&self, input_file_path: &str, output_file_path: &str) -> Result<()> {
+        let reader =
+            BufReader::new(File::open(input_file_path).map_err(|e| Error::io(input_file_path, e))?);
+        let writer = BufWriter::new(
+            File::create(output_file_path).map_err(|e| Error::io(output_file_path, e))?,
+        );
+        map_lines_batched(
+            reader,
+            writer,
+            Some(input_file_path),
+            Some(output_file_path),
+            &self.executor,
+            self.batch_lines,
+            |line| self.tokenize_line_string(line),
+        )
+    }
+
+    pub fn tokenize_reader(&self, reader: impl BufRead, writer: impl Write) -> Result<()> {
+        map_lines_batched(
+            reader,
+            writer,
+            None,
+            None,
+            &self.executor,
+            self.batch_lines,
+            |line| self.tokenize_line_string(line),
+        )
+    }
+
+    pub fn tokenize(&self, text: &str) -> String {
+        let lines: Vec<&str> = text.lines().collect();
+        self.executor
+            .map_batches(&lines, self.batch_lines, |line| {
+                self.tokenize_line_string(line)
             })
-            .to_string();
-    }
-    // After substituting protected patterns, replace all sequences of whitespaces with a single whitespace and trim the text
-    tokenized_text = tokenized_text
-        .split_whitespace()
-        .collect::<Vec<&str>>()
-        .join(" ");
-
-    // Separate out all other special characters depending on the language
-    match language {
-        Language::Fi | Language::Sv => {
-            // In Finnish and Swedish, the colon can be used inside words as an apostrophe-like character:
-            // TODO (applies for all LazyLock regexes) this has some overhead when multithreading because of the read access, cloning the regexes for each thread is technically faster
-            // TODO chain regexes or use alternate intermediate results to pass around Cow<str> between replacing regexes
-            static RE_GENERAL: LazyLock<Regex> =
-                LazyLock::new(|| Regex::new(r"([^\p{L}\p{N}\s\.\:\'\`\,\-])").unwrap());
-            tokenized_text = RE_GENERAL.replace_all(&tokenized_text, " $1 ").to_string();
-            // If a colon is not immediately followed by lower-case characters, separate it out anyway
-            static RE_COLON: LazyLock<Regex> =
-                LazyLock::new(|| Regex::new(r"(:)(?=$|[^\p{Ll}])").unwrap());
-            tokenized_text = RE_COLON.replace_all(&tokenized_text, " $1 ").to_string();
-        }
-        Language::Tdt => {
-            // # In Tetun, the apostrophe can be used inside words as an apostrophe-like character:
-            static RE_GENERAL: LazyLock<Regex> =
-                LazyLock::new(|| Regex::new(r"([^\p{L}\p{N}\s\.\'\`\,\-])").unwrap());
-            tokenized_text = RE_GENERAL.replace_all(&tokenized_text, " $1 ").to_string();
-            // If an apostrophe is not immediately followed by lower-case characters, separate it out anyway
-            static RE_APOSTROPHE: LazyLock<Regex> =
-                LazyLock::new(|| Regex::new(r"(')(?=$|[^\p{Ll}])").unwrap());
-            tokenized_text = RE_APOSTROPHE
-                .replace_all(&tokenized_text, " $1 ")
-                .to_string();
+            .concat()
+    }
+
+    /// Tokenizes a single line and returns its tokens instead of a space-joined string
+    pub fn tokenize_to_vec(&self, text: &str) -> Vec<String> {
+        self.tokenize_pairs(text)
+            .into_iter()
+            .map(|(_, token)| token)
+            .collect()
+    }
+
+    /// Tokenizes a single line into its tokens separated by spaces and followed by a newline, as written by
+    /// [`Self::tokenize_reader`]. Unlike the tokens of [`Tokenizer::tokenize_line`], lines passed through by
+    /// `skip_xml` are kept as they are
+    pub fn tokenize_line_string(&self, text: &str) -> String {
+        let text = text.trim_end_matches('\n');
+        // Lines consisting solely of XML/SGML tags are passed through unchanged
+        if self.skip_xml && RE_XML_LINE.is_match(text) {
+            return format!("{}\n", text);
         }
-        Language::Ca => {
-            // In Catalan, the middle dot can be used inside words:
-            static RE_GENERAL: LazyLock<Regex> =
-                LazyLock::new(|| Regex::new(r"([^\p{L}\p{N}\s\.\u{00B7}'\`\,\-])").unwrap());
-            tokenized_text = RE_GENERAL.replace_all(&tokenized_text, " $1 ").to_string();
-            // If a middot is not immediately followed by lower-case characters, separate it out anywa
-            static RE_MIDDOT: LazyLock<Regex> =
-                LazyLock::new(|| Regex::new(r"(\u{00B7})(?=$|[^\p{Ll}])").unwrap());
-            tokenized_text = RE_MIDDOT.replace_all(&tokenized_text, " $1 ").to_string();
+        let mut tokenized_text = self.tokenize_to_vec(text).join(" ");
+        tokenized_text.push('\n');
+        tokenized_text
+    }
+
+    // Tokenizes a single line into pairs of unescaped and escaped tokens, keeping XML/SGML tags verbatim when
+    // configured to do so
+    fn tokenize_pairs(&self, text: &str) -> Vec<(String, String)> {
+        let text = text.trim_end_matches('\n');
+        let keep_tags = self.protect_xml_tags || (self.skip_xml && RE_XML_LINE.is_match(text));
+        if !keep_tags {
+            return self
+                .tokenize_raw(text)
+                .into_iter()
+                .map(|token| (token.clone(), self.escape_token(token)))
+                .collect();
         }
-        _ => {
-            static RE_GENERAL: LazyLock<Regex> =
-                LazyLock::new(|| Regex::new(r"([^\p{L}\p{N}\s\.\'\`\,\-])").unwrap());
-            tokenized_text = RE_GENERAL.replace_all(&tokenized_text, " $1 ").to_string();
+        let mut tokens = Vec::new();
+        let mut last_tag_end = 0;
+        for tag in RE_XML_TAG.find_iter(text) {
+            tokens.extend(
+                self.tokenize_raw(&text[last_tag_end..tag.start()])
+                    .into_iter()
+                    .map(|token| (token.clone(), self.escape_token(token))),
+            );
+            tokens.push((tag.as_str().to_owned(), tag.as_str().to_owned()));
+            last_tag_end = tag.end();
         }
+        tokens.extend(
+            self.tokenize_raw(&text[last_tag_end..])
+                .into_iter()
+                .map(|token| (token.clone(), self.escape_token(token))),
+        );
+        tokens
     }
 
-    // Optional aggressive hyphen splitting
-    if aggresive_hyphen_splitting {
-        static RE_AGGRESSIVE_HYPHEN_SPLITTING: LazyLock<Regex> =
-            LazyLock::new(|| Regex::new(r"([\p{L}\p{N}])-(?=[\p{L}\p{N}])").unwrap());
-        tokenized_text = RE_AGGRESSIVE_HYPHEN_SPLITTING
-            .replace_all(&tokenized_text, "$1 @-@ ")
-            .to_string();
-    }
-
-    // Multi-dot tagging
-    static RE_NEW_MULTI_DOT: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\.([\.]+)").unwrap());
-    tokenized_text = RE_NEW_MULTI_DOT
-        .replace_all(&tokenized_text, " DOTMULTI$1")
-        .to_string();
-    static RE_DOTMULTI_LEFT: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"DOTMULTI\.").unwrap());
-    static RE_DOTMULTI_PLUS_NONDOT: LazyLock<Regex> =
-        LazyLock::new(|| Regex::new(r"DOTMULTI\.([^\.])").unwrap());
-    static RE_DOTMULTI_EXPAND: LazyLock<Regex> =
-        LazyLock::new(|| Regex::new(r"DOTMULTI\.").unwrap());
-    while RE_DOTMULTI_LEFT.is_match(&tokenized_text) {
-        // Replace DOTMULTI. followed by non-dot with DOTDOTMULTI plus that character
-        tokenized_text = RE_DOTMULTI_PLUS_NONDOT
-            .replace_all(&tokenized_text, "DOTDOTMULTI $1")
-            .to_string();
-        // Replace any remaining DOTMULTI. with DOTDOTMULTI
-        tokenized_text = RE_DOTMULTI_EXPAND
-            .replace_all(&tokenized_text, "DOTDOTMULTI")
-            .to_string();
-    }
-
-    // Separate out "," except if within numbers (5,300)
-    static RE_COMMA_AFTER_NON_NUMERIC: LazyLock<Regex> =
-        LazyLock::new(|| Regex::new(r"([^\p{N}]),").unwrap());
-    tokenized_text = RE_COMMA_AFTER_NON_NUMERIC
-        .replace_all(&tokenized_text, "$1 , ")
-        .to_string();
-    static RE_COMMA_BEFORE_NON_NUMERIC: LazyLock<Regex> =
-        LazyLock::new(|| Regex::new(r",([^\p{N}])").unwrap());
-    tokenized_text = RE_COMMA_BEFORE_NON_NUMERIC
-        .replace_all(&tokenized_text, " , $1")
-        .to_string();
-
-    // Separate "," after a number if it's the end of a sentence
-    static RE_COMMA_AFTER_NUMBER_END_OF_SENTENCE: LazyLock<Regex> =
-        LazyLock::new(|| Regex::new(r"([\p{N}]),$").unwrap());
-    tokenized_text = RE_COMMA_AFTER_NUMBER_END_OF_SENTENCE
-        .replace_all(&tokenized_text, "$1 ,")
-        .to_string();
-
-    // Split contractions
-    match language {
-        Language::En => {
-            // Split contractions right
-            // Non-alpha + apostrophe + non-alpha -> add spaces around apostrophe
-            static RE_SPACE_AROUND_APHOSTROPHE: LazyLock<Regex> =
-                LazyLock::new(|| Regex::new(r"([^\p{L}])[']([^\p{L}])").unwrap());
-            tokenized_text = RE_SPACE_AROUND_APHOSTROPHE
-                .replace_all(&tokenized_text, "$1 ' $2")
-                .to_string();
-            // Non-alpha/non-numeric + apostrophe + alpha -> space before apostrophe
-            static RE_SPACE_BEFORE_APHOSTROPHE: LazyLock<Regex> =
-                LazyLock::new(|| Regex::new(r"([^\p{L}\p{N}])[']([\p{L}])").unwrap());
-            tokenized_text = RE_SPACE_BEFORE_APHOSTROPHE
-                .replace_all(&tokenized_text, "$1 ' $2")
-                .to_string();
-            // Alpha + apostrophe + non-alpha -> space after apostrophe
-            static RE_SPACE_AFTER_APHOSTROPHE: LazyLock<Regex> =
-                LazyLock::new(|| Regex::new(r"([\p{L}])[']([^\p{L}])").unwrap());
-            tokenized_text = RE_SPACE_AFTER_APHOSTROPHE
-                .replace_all(&tokenized_text, "$1 ' $2")
-                .to_string();
-            // Alpha + apostrophe + alpha -> space before apostrophe (e.g., "don't" -> "don ' t")
-            static RE_SPACE_BEFORE_APHOSTROPHE_ALPHA: LazyLock<Regex> =
-                LazyLock::new(|| Regex::new(r"([\p{L}])[']([\p{L}])").unwrap());
-            tokenized_text = RE_SPACE_BEFORE_APHOSTROPHE_ALPHA
-                .replace_all(&tokenized_text, "$1 '$2")
-                .to_string();
-
-            // Special case for "1990's" - numeric + apostrophe + 's'
-            static RE_NUMERIC_APOSTROPHE: LazyLock<Regex> =
-                LazyLock::new(|| Regex::new(r"([\p{N}])[']([s])").unwrap());
-            tokenized_text = RE_NUMERIC_APOSTROPHE
-                .replace_all(&tokenized_text, "$1 '$2")
-                .to_string();
+    // Tokenizes a single line into tokens that have not been escaped yet
+    fn tokenize_raw(&self, text: &str) -> Vec<String> {
+        if self.penn {
+            return self.tokenize_penn_raw(text);
         }
-        Language::Fr | Language::It | Language::Ga | Language::Ca => {
-            // Split contractions left
-            // Non-alpha + apostrophe + non-alpha -> add spaces around apostrophe
-            static RE_SPACE_AROUND_APOSTROPHE: LazyLock<Regex> =
-                LazyLock::new(|| Regex::new(r"([^\p{L}])[']([^\p{L}])").unwrap());
-            tokenized_text = RE_SPACE_AROUND_APOSTROPHE
-                .replace_all(&tokenized_text, "$1 ' $2")
-                .to_string();
-            // Non-alpha + apostrophe + alpha -> space before apostrophe
-            static RE_SPACE_BEFORE_APOSTROPHE: LazyLock<Regex> =
-                LazyLock::new(|| Regex::new(r"([^\p{L}])[']([\p{L}])").unwrap());
-            tokenized_text = RE_SPACE_BEFORE_APOSTROPHE
-                .replace_all(&tokenized_text, "$1 ' $2")
-                .to_string();
-            // Alpha + apostrophe + non-alpha -> space after apostrophe
-            static RE_SPACE_AFTER_APOSTROPHE: LazyLock<Regex> =
-                LazyLock::new(|| Regex::new(r"([\p{L}])[']([^\p{L}])").unwrap());
-            tokenized_text = RE_SPACE_AFTER_APOSTROPHE
-                .replace_all(&tokenized_text, "$1 ' $2")
-                .to_string();
-            // Alpha + apostrophe + alpha -> space after apostrophe (e.g., "l'eau" -> "l' eau")
-            static RE_SPACE_BEFORE_APHOSTROPHE_ALPHA: LazyLock<Regex> =
-                LazyLock::new(|| Regex::new(r"([\p{L}])[']([\p{L}])").unwrap());
-            tokenized_text = RE_SPACE_BEFORE_APHOSTROPHE_ALPHA
-                .replace_all(&tokenized_text, "$1' $2")
-                .to_string();
+        self.tokenize_moses_raw(text)
+    }
+
+    // Splits the final period of words unless they are nonbreaking prefixes or are followed by lowercase words
+    // (or numbers for numeric-only prefixes)
+    fn split_periods(&self, text: &str, split_last_word: bool) -> String {
+        let words: Vec<&str> = text.split_whitespace().collect();
+        let mut word_tokenized_text: String = String::new();
+        for (i, word) in words.iter().enumerate() {
+            match word.strip_suffix('.') {
+                Some(pre)
+                    if !pre.is_empty()
+                        && ((split_last_word && i == words.len() - 1)
+                            || !self.keeps_period(pre, words.get(i + 1).copied())) =>
+                {
+                    word_tokenized_text.push_str(pre);
+                    word_tokenized_text.push_str(" .");
+                }
+                _ => word_tokenized_text.push_str(word),
+            }
+            word_tokenized_text.push(' ');
         }
-        Language::So | Language::Tdt => {
-            // Don't split glottals (no alpha + apostrophe + alpha rule)
-            // Non-alpha + apostrophe + non-alpha -> add spaces around apostrophe
-            static RE_SPACE_AROUND_APOSTROPHE: LazyLock<Regex> =
-                LazyLock::new(|| Regex::new(r"([^\p{L}])[']([^\p{L}])").unwrap());
-            tokenized_text = RE_SPACE_AROUND_APOSTROPHE
-                .replace_all(&tokenized_text, "$1 ' $2")
-                .to_string();
-            // Non-alpha + apostrophe + alpha -> space before apostrophe
-            static RE_SPACE_BEFORE_APOSTROPHE: LazyLock<Regex> =
-                LazyLock::new(|| Regex::new(r"([^\p{L}])[']([\p{L}])").unwrap());
-            tokenized_text = RE_SPACE_BEFORE_APOSTROPHE
-                .replace_all(&tokenized_text, "$1 ' $2")
-                .to_string();
-            // Alpha + apostrophe + non-alpha -> space after apostrophe
-            static RE_SPACE_AFER_APOSTROPHE: LazyLock<Regex> =
-                LazyLock::new(|| Regex::new(r"([\p{L}])[']([^\p{L}])").unwrap());
-            tokenized_text = RE_SPACE_AFER_APOSTROPHE
-                .replace_all(&tokenized_text, "$1 ' $2")
-                .to_string();
+        word_tokenized_text
+    }
+
+    // Whether a word ending in a period keeps it, given the word without the period and the word that follows
+    fn keeps_period(&self, pre: &str, next_word: Option<&str>) -> bool {
+        let next_char = next_word.and_then(|word| word.chars().next());
+        (pre.contains('.') && pre.chars().any(|c| c.is_alphabetic()))
+            || self.nonbreaking_prefixes.get(pre) == Some(PrefixType::Always)
+            || next_char.is_some_and(|c| c.is_lowercase())
+            // Numbered items, such as "No. 1"
+            || (self.nonbreaking_prefixes.get(pre) == Some(PrefixType::NumericOnly)
+                && next_char.is_some_and(|c| c.is_ascii_digit()))
+    }
+
+    // Escapes special characters unless escaping is disabled
+    fn escape_token(&self, token: String) -> String {
+        if self.no_escaping {
+            return token;
         }
-        _ => {
-            // Default: add spaces around all apostrophes
-            static RE_APOSTROPHE_SPACE: LazyLock<Regex> =
-                LazyLock::new(|| Regex::new(r"'").unwrap());
-            tokenized_text = RE_APOSTROPHE_SPACE
-                .replace_all(&tokenized_text, " ' ")
-                .to_string();
+        let escapes: &[(char, &str)] = if self.penn { &PENN_ESCAPES } else { &ESCAPES };
+        match escape_chars(&token, escapes) {
+            Cow::Borrowed(_) => token,
+            Cow::Owned(escaped_token) => escaped_token,
         }
     }
 
-    // Word tokenization
-    let words: Vec<&str> = tokenized_text.split_whitespace().collect();
-    let mut word_tokenized_text: String = String::new();
-    static RE_PERIOD_CAPTURE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(\S+)\.$").unwrap());
-    for (i, word) in words.iter().enumerate() {
-        let mut processed_word = word.to_string();
-        if let Some(caps) = RE_PERIOD_CAPTURE.captures(word) {
-            let pre = &caps[1];
-            if i == words.len() - 1 {
-                // Last word: split period
-                processed_word = format!("{} .", pre);
-            } else if (pre.contains('.') && pre.chars().any(|c| c.is_alphabetic()))
-                || (NONBREAKING_PREFIXES
-                    .get(language.as_ref())
-                    .and_then(|h| h.get(pre))
-                    == Some(&PrefixType::Always))
-                || (i < words.len() - 1
-                    && words[i + 1]
-                        .chars()
-                        .next()
-                        .map_or(false, |c| c.is_lowercase()))
+    /// Tokenizes a single line and returns its tokens along with their byte and char offsets into `text`
+    pub fn tokenize_spans(&self, text: &str) -> Vec<Token> {
+        let mut tokens = Vec::new();
+        // Current byte and char positions in the original text
+        let mut chars = text.char_indices().peekable();
+        let mut char_position = 0;
+        for (raw_token, token) in self.tokenize_pairs(text) {
+            // Skip whitespace collapsed between tokens and control characters removed by the tokenizer
+            while chars
+                .peek()
+                .is_some_and(|&(_, c)| c.is_whitespace() || is_removed_control_char(c))
             {
-                // Keep period attached
-            } else if NONBREAKING_PREFIXES
-                .get(language.as_ref())
-                .and_then(|h| h.get(pre))
-                == Some(&PrefixType::NumericOnly)
-                && i < words.len() - 1
-                && words[i + 1]
-                    .chars()
-                    .next()
-                    .map_or(false, |c| c.is_ascii_digit())
+                chars.next();
+                char_position += 1;
+            }
+            let start = chars.peek().map_or(text.len(), |&(i, _)| i);
+            let char_start = char_position;
+            // Symbols inserted by the tokenizer stand for a different piece of the original text
+            let original_forms = match raw_token.as_str() {
+                "@-@" => Some(&["-"][..]),
+                "@/@" => Some(&["/"][..]),
+                _ if self.penn => PENN_SYMBOLS
+                    .iter()
+                    .find(|(symbol, _)| *symbol == raw_token)
+                    .map(|(_, forms)| *forms),
+                _ => None,
+            };
+            if let Some(form) = original_forms
+                .and_then(|forms| forms.iter().find(|form| text[start..].starts_with(**form)))
             {
-                // Keep period attached for numbered items
-            } else {
-                // Split period
-                processed_word = format!("{} .", pre);
+                for _ in form.chars() {
+                    chars.next();
+                    char_position += 1;
+                }
+                let end = chars.peek().map_or(text.len(), |&(i, _)| i);
+                tokens.push(Token {
+                    text: token,
+                    start,
+                    end,
+                    char_start,
+                    char_end: char_position,
+                });
+                continue;
+            }
+            for token_char in raw_token.chars() {
+                if token_char.is_whitespace() {
+                    // Whitespace inside protected patterns was collapsed into a single space
+                    while chars
+                        .peek()
+                        .is_some_and(|&(_, c)| c.is_whitespace() || is_removed_control_char(c))
+                    {
+                        chars.next();
+                        char_position += 1;
+                    }
+                    continue;
+                }
+                // Control characters inside a token were removed, so they are skipped when aligning
+                while chars
+                    .peek()
+                    .is_some_and(|&(_, c)| c != token_char && is_removed_control_char(c))
+                {
+                    chars.next();
+                    char_position += 1;
+                }
+                if chars.peek().is_some_and(|&(_, c)| c == token_char) {
+                    chars.next();
+                    char_position += 1;
+                }
             }
+            let end = chars.peek().map_or(text.len(), |&(i, _)| i);
+            tokens.push(Token {
+                text: token,
+                start,
+                end,
+                char_start,
+                char_end: char_position,
+            });
         }
-        word_tokenized_text.push_str(&processed_word);
-        word_tokenized_text.push(' ');
+        tokens
     }
-    tokenized_text = word_tokenized_text.clone();
+}
 
-    // Clean up extraneous spaces
-    tokenized_text = tokenized_text
-        .split_ascii_whitespace()
-        .collect::<Vec<&str>>()
-        .join(" ");
+impl Tokenizer for MosesTokenizer {
+    fn name(&self) -> &str {
+        "moses"
+    }
 
-    // .' at end of sentence is missed
-    static RE_PERIOD: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\.\' ?$").unwrap());
-    tokenized_text = RE_PERIOD.replace(&tokenized_text, ". ' ").to_string();
+    fn tokenize_line(&self, line: &str) -> Vec<String> {
+        self.tokenize_to_vec(line)
+    }
 
-    // Restore protected patterns
-    // TODO could use some optimization
-    for (substitution, pattern) in found_protected_patterns {
-        tokenized_text = tokenized_text.replace(&substitution, &pattern);
+    fn tokenize_spans(&self, line: &str) -> Vec<Token> {
+        MosesTokenizer::tokenize_spans(self, line)
     }
 
-    // Restore multi-dots
-    while tokenized_text.contains("DOTDOTMULTI") {
-        tokenized_text = tokenized_text.replace("DOTDOTMULTI", "DOTMULTI.");
+    fn tokenize_line_string(&self, line: &str) -> String {
+        MosesTokenizer::tokenize_line_string(self, line)
     }
-    tokenized_text = tokenized_text.replace("DOTMULTI", ".");
 
-    // Escape special characters
-    if !no_escaping {
-        tokenized_text = tokenized_text
-            .replace("&", "&amp;") // escape escape
-            .replace("|", "&#124;") // factor separator
-            .replace("<", "&lt;") // xml
-            .replace(">", "&gt;") // xml
-            .replace("'", "&apos;") // xml
-            .replace("\"", "&quot;") // xml
-            .replace("[", "&#91;") // syntax non-terminal
-            .replace("]", "&#93;"); // syntax non-terminal
+    fn tokenize_batch(&self, lines: &[&str]) -> Vec<Vec<String>> {
+        self.executor
+            .map_batches(lines, self.batch_lines, |line| self.tokenize_to_vec(line))
     }
 
-    // Ensure final line break
-    if !tokenized_text.ends_with('\n') {
-        tokenized_text.push('\n');
+    // Lines are written as by `MosesTokenizer::tokenize_line_string`, which passes XML lines through unchanged
+    fn tokenize_reader(&self, reader: &mut dyn BufRead, writer: &mut dyn Write) -> Result<()> {
+        MosesTokenizer::tokenize_reader(self, reader, writer)
     }
+}
 
-    tokenized_text
+impl Detokenizer for MosesTokenizer {
+    fn detokenize_line(&self, tokens: &[String]) -> String {
+        let mut detokenized_line = moses_detokenize_line(&tokens.join(" "), self.language.clone());
+        detokenized_line.pop();
+        detokenized_line
+    }
+}
+
+pub fn moses_tokenize_file(
+    input_file_path: &str,
+    output_file_path: &str,
+    language: Language,
+    no_escaping: bool,
+    aggresive_hyphen_splitting: bool,
+    protected_patterns: &[&str],
+) -> Result<()> {
+    MosesTokenizer::builder(language)
+        .no_escaping(no_escaping)
+        .aggressive_hyphen_splitting(aggresive_hyphen_splitting)
+        .protected_patterns(protected_patterns)
+        .build()?
+        .tokenize_file(input_file_path, output_file_path)
+}
+
+pub fn moses_tokenize_reader(
+    reader: impl BufRead,
+    writer: impl Write,
+    language: Language,
+    no_escaping: bool,
+    aggresive_hyphen_splitting: bool,
+    protected_patterns: &[&str],
+) -> Result<()> {
+    MosesTokenizer::builder(language)
+        .no_escaping(no_escaping)
+        .aggressive_hyphen_splitting(aggresive_hyphen_splitting)
+        .protected_patterns(protected_patterns)
+        .build()?
+        .tokenize_reader(reader, writer)
+}
+
+pub fn moses_tokenize(
+    text: &str,
+    language: Language,
+    no_escaping: bool,
+    aggresive_hyphen_splitting: bool,
+    protected_patterns: &[&str],
+) -> Result<String> {
+    Ok(MosesTokenizer::builder(language)
+        .no_escaping(no_escaping)
+        .aggressive_hyphen_splitting(aggresive_hyphen_splitting)
+        .protected_patterns(protected_patterns)
+        .build()?
+        .tokenize(text))
+}
+
+pub fn moses_tokenize_line(
+    text: &str,
+    language: Language,
+    no_escaping: bool,
+    aggresive_hyphen_splitting: bool,
+    protected_patterns: &[Regex],
+) -> String {
+    MosesTokenizer {
+        nonbreaking_prefixes: NonbreakingPrefixes::builtin(&language),
+        language,
+        no_escaping,
+        aggresive_hyphen_splitting,
+        penn: false,
+        skip_xml: false,
+        protect_xml_tags: false,
+        protected_patterns: protected_patterns.to_vec(),
+        executor: Executor::Sequential,
+        batch_lines: DEFAULT_BATCH_LINES,
+    }
+    .tokenize_line_string(text)
+}
+
+pub fn moses_tokenize_line_with_offsets(
+    text: &str,
+    language: Language,
+    no_escaping: bool,
+    aggresive_hyphen_splitting: bool,
+    protected_patterns: &[Regex],
+) -> Vec<Token> {
+    MosesTokenizer {
+        nonbreaking_prefixes: NonbreakingPrefixes::builtin(&language),
+        language,
+        no_escaping,
+        aggresive_hyphen_splitting,
+        penn: false,
+        skip_xml: false,
+        protect_xml_tags: false,
+        protected_patterns: protected_patterns.to_vec(),
+        executor: Executor::Sequential,
+        batch_lines: DEFAULT_BATCH_LINES,
+    }
+    .tokenize_spans(text)
+}
+
+// ASCII characters 0-31 (the first 128 ASCII chars match the first 128 unicode chars)
+fn is_removed_control_char(ch: char) -> bool {
+    (ch as u32) < 32
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rayon::ThreadPoolBuilder;
 
     #[test]
     fn english_double_quotes() {
@@ -418,32 +679,22 @@ mod tests {
             Language::En,
             true,
             false,
-            &vec![],
+            &[],
         );
         assert_eq!(result, "This is a somewhat \" less simple \" test .\n");
     }
 
     #[test]
     fn french_simple() {
-        let result = moses_tokenize_line(
-            "Voici une phrase simple.",
-            Language::Fr,
-            true,
-            false,
-            &vec![],
-        );
+        let result =
+            moses_tokenize_line("Voici une phrase simple.", Language::Fr, true, false, &[]);
         assert_eq!(result, "Voici une phrase simple .\n");
     }
 
     #[test]
     fn french_apostrophe() {
-        let result = moses_tokenize_line(
-            "Moi, j'ai une apostrophe.",
-            Language::Fr,
-            true,
-            false,
-            &vec![],
-        );
+        let result =
+            moses_tokenize_line("Moi, j'ai une apostrophe.", Language::Fr, true, false, &[]);
         assert_eq!(result, "Moi , j' ai une apostrophe .\n");
     }
 
@@ -454,7 +705,7 @@ mod tests {
             Language::Fr,
             true,
             false,
-            &vec![],
+            &[],
         );
         assert_eq!(result, "de musique rap issus de l' immigration\n");
     }
@@ -466,11 +717,54 @@ mod tests {
             Language::En,
             true,
             false,
-            &vec![],
+            &[],
         );
         assert_eq!(result, "Ich hoffe , daß Sie schöne Ferien hatten .\n");
     }
 
+    #[test]
+    fn latvian_non_ascii_control_range() {
+        // "ā" is U+0101, whose lowest byte is an ASCII control character
+        let result = moses_tokenize_line("Māja ir liela.", Language::Lv, true, false, &[]);
+        assert_eq!(result, "Māja ir liela .\n");
+    }
+
+    #[test]
+    fn custom_nonbreaking_prefixes() {
+        let prefixes = NonbreakingPrefixes::parse("approx\nFig #NUMERIC_ONLY#\n");
+        let tokenizer = MosesTokenizer::builder(Language::En)
+            .extra_nonbreaking_prefixes(&prefixes)
+            .build()
+            .unwrap();
+        assert_eq!(
+            tokenizer.tokenize_line_string("Mr. Smith is approx. Ten, see Fig. 3 or Fig. A."),
+            "Mr. Smith is approx. Ten , see Fig. 3 or Fig . A .\n"
+        );
+        let tokenizer = MosesTokenizer::builder(Language::En)
+            .nonbreaking_prefixes(prefixes)
+            .build()
+            .unwrap();
+        assert_eq!(
+            tokenizer.tokenize_line_string("Mr. Smith is approx. Ten."),
+            "Mr . Smith is approx. Ten .\n"
+        );
+    }
+
+    #[test]
+    fn generic_language() {
+        // All apostrophes are split and no nonbreaking prefixes are known
+        let result = moses_tokenize_line(
+            "It's l'amour, e.g. Dr. Smith.",
+            Language::Generic,
+            true,
+            false,
+            &[],
+        );
+        assert_eq!(result, "It ' s l ' amour , e.g. Dr . Smith .\n");
+        assert_eq!("xx".parse::<Language>().unwrap(), Language::Generic);
+        assert_eq!(Language::Generic.as_ref(), "xx");
+    }
+
     // TODO Japanese/Korean/Chinese CJK characters are handle by Moses detokenizer (https://github.com/moses-smt/mosesdecoder/blob/master/scripts/tokenizer/detokenizer.perl), but not by the tokenizer
     // #[test]
     // fn chinese_simple() {
@@ -490,8 +784,7 @@ mod tests {
         // In English, these would normally be contractions that are separated by default
         let text = "Some text containing the protected pattern $'$ and /'/.";
 
-        let result_without_protected =
-            moses_tokenize_line(text, Language::En, true, false, &vec![]);
+        let result_without_protected = moses_tokenize_line(text, Language::En, true, false, &[]);
         assert_eq!(
             result_without_protected,
             "Some text containing the protected pattern $ ' $ and / ' / .\n"
@@ -502,11 +795,194 @@ mod tests {
             Language::En,
             true,
             false,
-            &vec![Regex::new(r"([^\p{L}])[']([^\p{L}])").unwrap()],
+            &[Regex::new(r"([^\p{L}])[']([^\p{L}])").unwrap()],
         );
         assert_eq!(
             result_with_protected,
             "Some text containing the protected pattern $'$ and /'/ .\n"
         );
     }
+
+    #[test]
+    fn tokenizer_builder() {
+        let tokenizer = MosesTokenizer::builder(Language::En)
+            .aggressive_hyphen_splitting(true)
+            .protected_pattern(r"https?://\S+")
+            .build()
+            .unwrap();
+        assert_eq!(
+            tokenizer.tokenize_line_string("A well-known \"test\" at https://example.com/a-b ."),
+            "A well @-@ known &quot; test &quot; at https://example.com/a-b .\n"
+        );
+        assert_eq!(
+            tokenizer.tokenize_to_vec("Moi, j'ai une apostrophe."),
+            vec!["Moi", ",", "j", "&apos;ai", "une", "apostrophe", "."]
+        );
+    }
+
+    #[test]
+    fn tokenizer_shared_across_threads() {
+        let tokenizer = MosesTokenizer::builder(Language::Fr)
+            .no_escaping(true)
+            .build()
+            .unwrap();
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| {
+                    assert_eq!(
+                        tokenizer.tokenize_line_string("Moi, j'ai une apostrophe."),
+                        "Moi , j' ai une apostrophe .\n"
+                    );
+                });
+            }
+        });
+    }
+
+    #[test]
+    fn threads_and_batches() {
+        let text: String = (0..250)
+            .map(|i| format!("Line {i}, with \"quotes\" and l'apostrophe.\n"))
+            .collect();
+        let expected = MosesTokenizer::builder(Language::Fr)
+            .build()
+            .unwrap()
+            .tokenize(&text);
+        assert_eq!(expected.lines().count(), 250);
+        let pool = Arc::new(ThreadPoolBuilder::new().num_threads(2).build().unwrap());
+        let builders = [
+            MosesTokenizer::builder(Language::Fr).threads(1),
+            MosesTokenizer::builder(Language::Fr)
+                .threads(3)
+                .batch_lines(7),
+            MosesTokenizer::builder(Language::Fr)
+                .thread_pool(pool)
+                .batch_lines(1000),
+            MosesTokenizer::builder(Language::Fr).batch_lines(0),
+        ];
+        for builder in builders {
+            let tokenizer = builder.build().unwrap();
+            assert_eq!(tokenizer.tokenize(&text), expected);
+            let mut output = Vec::new();
+            tokenizer
+                .tokenize_reader(text.as_bytes(), &mut output)
+                .unwrap();
+            assert_eq!(String::from_utf8(output).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn tokenizer_trait() {
+        let tokenizer: Box<dyn Tokenizer> =
+            Box::new(MosesTokenizer::builder(Language::En).build().unwrap());
+        assert_eq!(
+            tokenizer.tokenize_batch(&["Hello, world.", "It's \"fine\"."]),
+            vec![
+                vec!["Hello", ",", "world", "."],
+                vec!["It", "&apos;s", "&quot;", "fine", "&quot;", "."]
+            ]
+        );
+        let tokenizer = MosesTokenizer::builder(Language::En).build().unwrap();
+        let tokens = Tokenizer::tokenize_line(&tokenizer, "It's \"fine\", isn't it?");
+        assert_eq!(
+            tokenizer.detokenize_line(&tokens),
+            "It's \"fine\", isn't it?"
+        );
+    }
+
+    fn spans(tokens: &[Token]) -> Vec<(&str, usize, usize, usize, usize)> {
+        tokens
+            .iter()
+            .map(|t| (t.text.as_str(), t.start, t.end, t.char_start, t.char_end))
+            .collect()
+    }
+
+    #[test]
+    fn offsets_whitespace_and_escaping() {
+        let tokenizer = MosesTokenizer::builder(Language::De).build().unwrap();
+        let tokens = tokenizer.tokenize_spans("  Ich hoffe,\t daß \"Sie\" kommen.\n");
+        assert_eq!(
+            spans(&tokens),
+            vec![
+                ("Ich", 2, 5, 2, 5),
+                ("hoffe", 6, 11, 6, 11),
+                (",", 11, 12, 11, 12),
+                ("daß", 14, 18, 14, 17),
+                ("&quot;", 19, 20, 18, 19),
+                ("Sie", 20, 23, 19, 22),
+                ("&quot;", 23, 24, 22, 23),
+                ("kommen", 25, 31, 24, 30),
+                (".", 31, 32, 30, 31),
+            ]
+        );
+    }
+
+    #[test]
+    fn offsets_multi_dots_and_protected_patterns() {
+        let tokenizer = MosesTokenizer::builder(Language::En)
+            .no_escaping(true)
+            .protected_pattern(r"New York")
+            .build()
+            .unwrap();
+        let text = "Wait... New  York?";
+        let tokens = tokenizer.tokenize_spans(text);
+        assert_eq!(
+            spans(&tokens),
+            vec![
+                ("Wait", 0, 4, 0, 4),
+                ("...", 4, 7, 4, 7),
+                ("New York", 8, 17, 8, 17),
+                ("?", 17, 18, 17, 18),
+            ]
+        );
+        // Offsets point back into the original text
+        assert_eq!(&text[tokens[2].start..tokens[2].end], "New  York");
+    }
+
+    #[test]
+    fn offsets_match_tokenized_line() {
+        let text = "It's a well-known fact, isn't it? \u{0007}Yes.";
+        let tokens = moses_tokenize_line_with_offsets(text, Language::En, false, true, &[]);
+        let joined: Vec<&str> = tokens.iter().map(|t| t.text.as_str()).collect();
+        assert_eq!(
+            format!("{}\n", joined.join(" ")),
+            moses_tokenize_line(text, Language::En, false, true, &[])
+        );
+        assert_eq!(&text[tokens.last().unwrap().start..], ".");
+    }
+
+    #[test]
+    fn skip_xml_lines() {
+        let tokenizer = MosesTokenizer::builder(Language::En)
+            .skip_xml(true)
+            .build()
+            .unwrap();
+        assert_eq!(
+            tokenizer.tokenize_line_string("<doc docid=\"a-b\" genre=\"news\">  <p>\n"),
+            "<doc docid=\"a-b\" genre=\"news\">  <p>\n"
+        );
+        // Lines with text are still tokenized, tags included
+        assert_eq!(
+            tokenizer.tokenize_line_string("<b>Hello</b>"),
+            "&lt; b &gt; Hello &lt; / b &gt;\n"
+        );
+    }
+
+    #[test]
+    fn protect_xml_tags() {
+        let tokenizer = MosesTokenizer::builder(Language::En)
+            .protect_xml_tags(true)
+            .build()
+            .unwrap();
+        assert_eq!(
+            tokenizer.tokenize_line_string("<seg id=\"1\">Hello, <b>\"world\"</b>!</seg>"),
+            "<seg id=\"1\"> Hello , <b> &quot; world &quot; </b> ! </seg>\n"
+        );
+        let text = "<seg id=\"1\">A test.</seg>";
+        let tokens: Vec<&str> = tokenizer
+            .tokenize_spans(text)
+            .iter()
+            .map(|t| &text[t.start..t.end])
+            .collect();
+        assert_eq!(tokens, vec!["<seg id=\"1\">", "A", "test", ".", "</seg>"]);
+    }
 }
@@ -0,0 +1,302 @@
+use super::Language;
+use super::escape::deescape_special_chars;
+use crate::error::{Error, Result};
+use crate::stream::map_lines_parallel;
+use rayon::prelude::*;
+use regex::Regex;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::sync::LazyLock;
+
+pub fn moses_detokenize_file(
+    input_file_path: &str,
+    output_file_path: &str,
+    language: Language,
+) -> Result<()> {
+    let reader =
+        BufReader::new(File::open(input_file_path).map_err(|e| Error::io(input_file_path, e))?);
+    let writer =
+        BufWriter::new(File::create(output_file_path).map_err(|e| Error::io(output_file_path, e))?);
+    map_lines_parallel(
+        reader,
+        writer,
+        Some(input_file_path),
+        Some(output_file_path),
+        |line| moses_detokenize_line(line, language.clone()),
+    )
+}
+
+pub fn moses_detokenize(text: &str, language: Language) -> String {
+    text.par_lines()
+        .map(|line| moses_detokenize_line(line, language.clone()))
+        .collect::<String>()
+}
+
+pub fn moses_detokenize_line(text: &str, language: Language) -> String {
+    let text = text.trim_end_matches('\n');
+
+    // Lines consisting only of an XML/SGML tag or whitespace are passed through unchanged
+    static RE_XML_LINE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^<.+>$").unwrap());
+    if RE_XML_LINE.is_match(text) || text.trim().is_empty() {
+        return format!("{}\n", text);
+    }
+
+    // Undo aggressive hyphen splitting
+    let mut detokenized_text = format!(" {} ", text).replace(" @-@ ", "-");
+
+    // De-escape special characters
+    detokenized_text = deescape_special_chars(&detokenized_text).into_owned();
+
+    static RE_RIGHT_SHIFT: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"^[\p{Sc}\(\[\{¿¡]+$").unwrap());
+    static RE_LEFT_SHIFT: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"^[,\.\?!:;\\%\}\]\)]+$").unwrap());
+    static RE_FRENCH_SPACED_PUNCTUATION: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"^[\?!:;\\%]$").unwrap());
+    static RE_ENGLISH_CONTRACTION: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"^'\p{Alphabetic}").unwrap());
+    static RE_ENDS_WITH_ALPHANUMERIC: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"[\p{Alphabetic}\p{Nd}]$").unwrap());
+    static RE_NUMBER: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[0-9]+$").unwrap());
+    static RE_LEFT_CONTRACTION: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"\p{Alphabetic}'$").unwrap());
+    static RE_STARTS_WITH_ALPHABETIC: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"^\p{Alphabetic}").unwrap());
+    static RE_CZECH_DASHED_SUFFIX: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"(?i)^li$|^mail").unwrap());
+    static RE_QUOTES: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"^['"„“`]+$"#).unwrap());
+    static RE_DOUBLE_QUOTES: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[„“”]+$").unwrap());
+    static RE_FINNISH_CASE_SUFFIX: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(r"^(N|n|A|a|Ä|ä|ssa|Ssa|ssä|Ssä|sta|stä|Sta|Stä|hun|Hun|hyn|Hyn|han|Han|hän|Hän|hön|Hön|un|Un|yn|Yn|an|An|än|Än|ön|Ön|seen|Seen|lla|Lla|llä|Llä|lta|Lta|ltä|Ltä|lle|Lle|ksi|Ksi|kse|Kse|tta|Tta|ine|Ine)(ni|si|mme|nne|nsa)?(ko|kö|han|hän|pa|pä|kaan|kään|kin)?$").unwrap()
+    });
+
+    // Join tokens back together, deciding for each one whether it attaches to its left or right neighbour
+    let words: Vec<&str> = detokenized_text.split_whitespace().collect();
+    let mut text = String::new();
+    let mut prepend_space = " ";
+    let mut quote_count: HashMap<&str, usize> = HashMap::new();
+    let mut i = 0;
+    while i < words.len() {
+        let word = words[i];
+        if word.chars().next().is_some_and(is_cjk) {
+            // Left shift if this is a second consecutive CJK word
+            if i == 0 || !words[i - 1].chars().last().is_some_and(is_cjk) {
+                text.push_str(prepend_space);
+            }
+            text.push_str(word);
+            prepend_space = " ";
+        } else if RE_RIGHT_SHIFT.is_match(word) {
+            // Right shift on currency and other opening punctuation
+            text.push_str(prepend_space);
+            text.push_str(word);
+            prepend_space = "";
+        } else if RE_LEFT_SHIFT.is_match(word) {
+            // These punctuations are prefixed with a non-breakable space in French
+            if language == Language::Fr && RE_FRENCH_SPACED_PUNCTUATION.is_match(word) {
+                text.push(' ');
+            }
+            // Left shift on closing punctuation
+            text.push_str(word);
+            prepend_space = " ";
+        } else if language == Language::En
+            && i > 0
+            && RE_ENGLISH_CONTRACTION.is_match(word)
+            && RE_ENDS_WITH_ALPHANUMERIC.is_match(words[i - 1])
+        {
+            // Left shift the contraction for English
+            text.push_str(word);
+            prepend_space = " ";
+        } else if language == Language::Cs
+            && i > 1
+            && RE_NUMBER.is_match(words[i - 2])
+            && (words[i - 1] == "." || words[i - 1] == ",")
+            && RE_NUMBER.is_match(word)
+        {
+            // Left shift floats in Czech
+            text.push_str(word);
+            prepend_space = " ";
+        } else if matches!(language, Language::Fr | Language::It | Language::Ga)
+            && i + 1 < words.len()
+            && RE_LEFT_CONTRACTION.is_match(word)
+            && RE_STARTS_WITH_ALPHABETIC.is_match(words[i + 1])
+        {
+            // Right shift the contraction for French, Italian and Irish
+            text.push_str(prepend_space);
+            text.push_str(word);
+            prepend_space = "";
+        } else if language == Language::Cs
+            && i + 2 < words.len()
+            && word.chars().last().is_some_and(|c| c.is_alphabetic())
+            && (words[i + 1] == "-" || words[i + 1] == "–")
+            && RE_CZECH_DASHED_SUFFIX.is_match(words[i + 2])
+        {
+            // Right shift "-li" and a few dashed words (e-mail) in Czech
+            text.push_str(prepend_space);
+            text.push_str(word);
+            text.push_str(words[i + 1]);
+            i += 1;
+            prepend_space = "";
+        } else if RE_QUOTES.is_match(word) {
+            // Combine quotes smartly, alternating between opening and closing ones
+            let normalized_quote = if RE_DOUBLE_QUOTES.is_match(word) {
+                "\""
+            } else {
+                word
+            };
+            let count = quote_count.entry(normalized_quote).or_insert(0);
+            if language == Language::Cs && word == "„" {
+                // This is always the starting quote in Czech
+                *count = 0;
+            }
+            if language == Language::Cs && word == "“" {
+                // This is usually the ending quote in Czech
+                *count = 1;
+            }
+            if count.is_multiple_of(2) {
+                if language == Language::En && word == "'" && i > 0 && words[i - 1].ends_with('s') {
+                    // Single quote for possessives ending in s ("The Jones' house"), left shift
+                    text.push_str(word);
+                    prepend_space = " ";
+                } else {
+                    // Right shift
+                    text.push_str(prepend_space);
+                    text.push_str(word);
+                    prepend_space = "";
+                    *count += 1;
+                }
+            } else {
+                // Left shift
+                text.push_str(word);
+                prepend_space = " ";
+                *count += 1;
+            }
+        } else if language == Language::Fi
+            && i > 0
+            && words[i - 1].ends_with(':')
+            && RE_FINNISH_CASE_SUFFIX.is_match(word)
+        {
+            // Finnish colon without intervening space if followed by a case suffix (EU:N, EU:ssa, EU:hun...)
+            text.push_str(&word.to_lowercase());
+            prepend_space = " ";
+        } else {
+            text.push_str(prepend_space);
+            text.push_str(word);
+            prepend_space = " ";
+        }
+        i += 1;
+    }
+
+    // Clean up extraneous spaces and ensure final line break
+    let mut detokenized_text = text
+        .split(' ')
+        .filter(|w| !w.is_empty())
+        .collect::<Vec<&str>>()
+        .join(" ");
+    detokenized_text.push('\n');
+    detokenized_text
+}
+
+// Ranges of Unicode blocks used by Chinese, Japanese and Korean scripts, as listed in the original Moses detokenizer
+fn is_cjk(c: char) -> bool {
+    matches!(c as u32,
+        0x1100..=0x11FF // Hangul Jamo
+        | 0x2E80..=0x2FDF // CJK Radicals Supplement, Kangxi Radicals
+        | 0x2FF0..=0x2FFF // Ideographic Description Characters
+        | 0x3000..=0x303F // CJK Symbols and Punctuation
+        | 0x3040..=0x309F // Hiragana
+        | 0x30A0..=0x30FF // Katakana
+        | 0x3100..=0x312F // Bopomofo
+        | 0x3130..=0x318F // Hangul Compatibility Jamo
+        | 0x3190..=0x319F // Kanbun
+        | 0x31A0..=0x31BF // Bopomofo Extended
+        | 0x31C0..=0x31EF // CJK Strokes
+        | 0x31F0..=0x31FF // Katakana Phonetic Extensions
+        | 0x3200..=0x32FF // Enclosed CJK Letters and Months
+        | 0x3300..=0x33FF // CJK Compatibility
+        | 0x3400..=0x4DBF // CJK Unified Ideographs Extension A
+        | 0x4DC0..=0x4DFF // Yijing Hexagram Symbols
+        | 0x4E00..=0x9FFF // CJK Unified Ideographs
+        | 0xA000..=0xA48F // Yi Syllables
+        | 0xA490..=0xA4CF // Yi Radicals
+        | 0xAC00..=0xD7AF // Hangul Syllables
+        | 0xF900..=0xFAFF // CJK Compatibility Ideographs
+        | 0xFE30..=0xFE4F // CJK Compatibility Forms
+        | 0xFF65..=0xFFDC // Halfwidth Katakana and Hangul
+        | 0x20000..=0x2A6DF // CJK Unified Ideographs Extension B
+        | 0x2A700..=0x2B73F // CJK Unified Ideographs Extension C
+        | 0x2B740..=0x2B81F // CJK Unified Ideographs Extension D
+        | 0x2F800..=0x2FA1F // CJK Compatibility Ideographs Supplement
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn english_simple() {
+        let result = moses_detokenize_line("This is a simple test .", Language::En);
+        assert_eq!(result, "This is a simple test.\n");
+    }
+
+    #[test]
+    fn english_escaped_characters() {
+        let result = moses_detokenize_line(
+            "This is a somewhat &quot; less simple &quot; test , isn &apos;t it ?",
+            Language::En,
+        );
+        assert_eq!(
+            result,
+            "This is a somewhat \"less simple\" test, isn't it?\n"
+        );
+    }
+
+    #[test]
+    fn english_possessive() {
+        let result = moses_detokenize_line("The Jones ' house is ( very ) big", Language::En);
+        assert_eq!(result, "The Jones' house is (very) big\n");
+    }
+
+    #[test]
+    fn english_currency_and_hyphens() {
+        let result = moses_detokenize_line("It costs $ 5 for a two @-@ way ticket .", Language::En);
+        assert_eq!(result, "It costs $5 for a two-way ticket.\n");
+    }
+
+    #[test]
+    fn french_apostrophe() {
+        let result = moses_detokenize_line("Moi , j' ai une apostrophe !", Language::Fr);
+        assert_eq!(result, "Moi, j'ai une apostrophe !\n");
+    }
+
+    #[test]
+    fn czech_float() {
+        let result = moses_detokenize_line("Stojí to 3 , 50 korun .", Language::Cs);
+        assert_eq!(result, "Stojí to 3,50 korun.\n");
+    }
+
+    #[test]
+    fn czech_dashed_suffix_at_end_of_line() {
+        let result = moses_detokenize_line("Přijde , je - li", Language::Cs);
+        assert_eq!(result, "Přijde, je-li\n");
+    }
+
+    #[test]
+    fn finnish_case_suffix() {
+        let result = moses_detokenize_line("EU : N päätös", Language::Fi);
+        assert_eq!(result, "EU:n päätös\n");
+    }
+
+    #[test]
+    fn chinese_simple() {
+        let result = moses_detokenize_line("这 是 一个 简单 的 句子 。", Language::Zh);
+        assert_eq!(result, "这是一个简单的句子。\n");
+    }
+
+    #[test]
+    fn xml_line_untouched() {
+        let result = moses_detokenize_line("<seg id=\"1\">", Language::En);
+        assert_eq!(result, "<seg id=\"1\">\n");
+    }
+}
@@ -0,0 +1,536 @@
+use super::{Language, MosesTokenizer, is_removed_control_char};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+// `LETTERS`, the ranges of the Unicode letter category (`\p{L}`), generated by build.rs
+include!(concat!(env!("OUT_DIR"), "/letters.rs"));
+
+fn is_letter(ch: char) -> bool {
+    if ch.is_ascii() {
+        return ch.is_ascii_alphabetic();
+    }
+    LETTERS
+        .binary_search_by(|&(start, end)| {
+            if end < ch {
+                Ordering::Less
+            } else if start > ch {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+// Characters that are not separated from their neighbours by default (`[\p{L}\p{N}\s\.\'\`\,\-]` in the original
+// tokenizer), along with the language-specific ones
+fn is_kept(ch: char, language: &Language) -> bool {
+    is_letter(ch)
+        || ch.is_numeric()
+        || ch.is_whitespace()
+        || matches!(ch, '.' | '\'' | '`' | ',' | '-')
+        || match language {
+            Language::Fi | Language::Sv => ch == ':',
+            Language::Ca => ch == '\u{00B7}',
+            _ => false,
+        }
+}
+
+// Characters that can be used inside words in some languages, and are only separated when they are not followed
+// by a lowercase character
+fn inner_char(language: &Language) -> Option<char> {
+    match language {
+        Language::Fi | Language::Sv => Some(':'),
+        Language::Tdt => Some('\''),
+        Language::Ca => Some('\u{00B7}'),
+        _ => None,
+    }
+}
+
+// Piece of a line between two possible token boundaries
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Cell {
+    Char(char),
+    // `@-@` of aggressive hyphen splitting
+    Hyphen,
+    // Sequence of two or more periods, which the original tokenizer replaces with DOTMULTI placeholders
+    MultiDot(usize),
+}
+
+impl Cell {
+    // First and last characters of the cell as seen by the rules of the original tokenizer
+    fn first_char(self) -> char {
+        match self {
+            Cell::Char(ch) => ch,
+            Cell::Hyphen => '@',
+            Cell::MultiDot(_) => 'D',
+        }
+    }
+
+    fn last_char(self) -> char {
+        match self {
+            Cell::Char(ch) => ch,
+            Cell::Hyphen => '@',
+            Cell::MultiDot(_) => 'I',
+        }
+    }
+}
+
+// Line being tokenized, as the cells of the original text and the number of spaces inserted before each of them.
+// Every rule of the original tokenizer inserts spaces around some characters depending on their neighbours, so
+// the rules are applied by walking the cells instead of rewriting the text with regular expressions. `spaces` has
+// one more element than `cells`, for the spaces at the end of the line.
+struct Line {
+    cells: Vec<Cell>,
+    spaces: Vec<u32>,
+}
+
+impl Line {
+    // Splits out the characters that are not kept inside words, hyphens with aggressive hyphen splitting and
+    // multi-dots, given a text with words separated by single spaces
+    fn new(text: &str, language: &Language, aggressive_hyphen_splitting: bool) -> Self {
+        let mut chars = Vec::with_capacity(text.len());
+        let mut spaces = Vec::with_capacity(text.len() + 1);
+        let mut pending_spaces = 0;
+        for ch in text.chars() {
+            if ch == ' ' {
+                pending_spaces += 1;
+            } else {
+                chars.push(ch);
+                spaces.push(pending_spaces);
+                pending_spaces = 0;
+            }
+        }
+        spaces.push(pending_spaces);
+
+        // Characters followed by a lowercase character once the other characters have been split out
+        let followed_by_lowercase = |i: usize| {
+            chars.get(i + 1).is_some_and(|&next| {
+                spaces[i + 1] == 0 && is_kept(next, language) && next.is_lowercase()
+            })
+        };
+        let inner_char = inner_char(language);
+        let split: Vec<bool> = chars
+            .iter()
+            .enumerate()
+            .map(|(i, &ch)| {
+                !is_kept(ch, language) || (Some(ch) == inner_char && !followed_by_lowercase(i))
+            })
+            .collect();
+
+        let mut line = Line {
+            cells: Vec::with_capacity(chars.len()),
+            spaces: Vec::with_capacity(chars.len() + 1),
+        };
+        let mut i = 0;
+        while i < chars.len() {
+            let ch = chars[i];
+            let joined_to_previous = i > 0 && spaces[i] == 0 && !split[i - 1];
+            let joined_to_next = i + 1 < chars.len() && spaces[i + 1] == 0 && !split[i + 1];
+            if split[i] {
+                line.push(spaces[i] + 1, Cell::Char(ch));
+                spaces[i + 1] += 1;
+            } else if ch == '-'
+                && aggressive_hyphen_splitting
+                && joined_to_previous
+                && (is_letter(chars[i - 1]) || chars[i - 1].is_numeric())
+                && joined_to_next
+                && chars[i + 1].is_alphanumeric()
+            {
+                line.push(spaces[i] + 1, Cell::Hyphen);
+                spaces[i + 1] += 1;
+            } else if ch == '.' && chars.get(i + 1) == Some(&'.') && spaces[i + 1] == 0 {
+                let start = i;
+                while chars.get(i + 1) == Some(&'.') && spaces[i + 1] == 0 {
+                    i += 1;
+                }
+                line.push(spaces[start] + 1, Cell::MultiDot(i - start + 1));
+                if i + 1 < chars.len() {
+                    spaces[i + 1] += 1;
+                }
+            } else {
+                line.push(spaces[i], Cell::Char(ch));
+            }
+            i += 1;
+        }
+        line.spaces.push(spaces[chars.len()]);
+        line
+    }
+
+    fn push(&mut self, spaces: u32, cell: Cell) {
+        self.spaces.push(spaces);
+        self.cells.push(cell);
+    }
+
+    fn contains(&self, ch: char) -> bool {
+        self.cells.contains(&Cell::Char(ch))
+    }
+
+    // Character before a cell, given the spaces before it
+    fn previous_char(&self, i: usize, spaces: u32) -> Option<char> {
+        if spaces > 0 {
+            Some(' ')
+        } else if i > 0 {
+            Some(self.cells[i - 1].last_char())
+        } else {
+            None
+        }
+    }
+
+    fn next_char(&self, i: usize) -> Option<char> {
+        if self.spaces[i + 1] > 0 {
+            Some(' ')
+        } else {
+            self.cells.get(i + 1).map(|cell| cell.first_char())
+        }
+    }
+
+    // Inserts spaces before and/or after every occurrence of `target` whose neighbours match, like a regular
+    // expression replacement of `target` along with the characters before and/or after it. Matches do not overlap,
+    // so a character that is part of a match cannot be the neighbour of the next one.
+    fn split_matches(
+        &mut self,
+        target: char,
+        with_previous: bool,
+        with_next: bool,
+        (space_before, space_after): (bool, bool),
+        matches: impl Fn(Option<char>, Option<char>) -> bool,
+    ) {
+        // Positions count every cell and space as a single character
+        let mut position = 0;
+        let mut matched_until = 0;
+        let mut carried_space = 0;
+        for i in 0..self.cells.len() {
+            let spaces = self.spaces[i];
+            self.spaces[i] += carried_space;
+            carried_space = 0;
+            position += spaces as usize;
+            if self.cells[i] == Cell::Char(target) {
+                let previous = self.previous_char(i, spaces);
+                let next = self.next_char(i);
+                let start = if with_previous {
+                    position.checked_sub(1)
+                } else {
+                    Some(position)
+                };
+                if start.is_some_and(|start| start >= matched_until)
+                    && (!with_previous || previous.is_some())
+                    && (!with_next || next.is_some())
+                    && matches(previous, next)
+                {
+                    self.spaces[i] += u32::from(space_before);
+                    carried_space = u32::from(space_after);
+                    matched_until = position + 1 + usize::from(with_next);
+                }
+            }
+            position += 1;
+        }
+        *self.spaces.last_mut().unwrap() += carried_space;
+    }
+
+    // Separates commas, except inside numbers (5,300)
+    fn split_commas(&mut self) {
+        if !self.contains(',') {
+            return;
+        }
+        let is_numeric = |ch: Option<char>| ch.is_some_and(char::is_numeric);
+        self.split_matches(',', true, false, (true, true), |previous, _| {
+            !is_numeric(previous)
+        });
+        self.split_matches(',', false, true, (true, true), |_, next| !is_numeric(next));
+        // A comma after a number at the end of the line
+        let last = self.cells.len() - 1;
+        if self.cells[last] == Cell::Char(',')
+            && self.spaces[last + 1] == 0
+            && is_numeric(self.previous_char(last, self.spaces[last]))
+        {
+            self.spaces[last] += 1;
+        }
+    }
+
+    // Splits contractions depending on the language
+    fn split_apostrophes(&mut self, language: &Language) {
+        if !self.contains('\'') {
+            return;
+        }
+        let is_letter = |ch: Option<char>| ch.is_some_and(is_letter);
+        let is_numeric = |ch: Option<char>| ch.is_some_and(char::is_numeric);
+        let both = (true, true);
+        match language {
+            Language::En => {
+                // Split contractions right
+                self.split_matches('\'', true, true, both, |previous, next| {
+                    !is_letter(previous) && !is_letter(next)
+                });
+                self.split_matches('\'', true, true, both, |previous, next| {
+                    !is_letter(previous) && !is_numeric(previous) && is_letter(next)
+                });
+                self.split_matches('\'', true, true, both, |previous, next| {
+                    is_letter(previous) && !is_letter(next)
+                });
+                self.split_matches('\'', true, true, (true, false), |previous, next| {
+                    is_letter(previous) && is_letter(next)
+                });
+                // Special case for "1990's"
+                self.split_matches('\'', true, true, (true, false), |previous, next| {
+                    is_numeric(previous) && next == Some('s')
+                });
+            }
+            Language::Fr | Language::It | Language::Ga | Language::Ca => {
+                // Split contractions left
+                self.split_matches('\'', true, true, both, |previous, next| {
+                    !is_letter(previous) && !is_letter(next)
+                });
+                self.split_matches('\'', true, true, both, |previous, next| {
+                    !is_letter(previous) && is_letter(next)
+                });
+                self.split_matches('\'', true, true, both, |previous, next| {
+                    is_letter(previous) && !is_letter(next)
+                });
+                self.split_matches('\'', true, true, (false, true), |previous, next| {
+                    is_letter(previous) && is_letter(next)
+                });
+            }
+            Language::So | Language::Tdt => {
+                // Don't split glottals
+                self.split_matches('\'', true, true, both, |previous, next| {
+                    !is_letter(previous) && !is_letter(next)
+                });
+                self.split_matches('\'', true, true, both, |previous, next| {
+                    !is_letter(previous) && is_letter(next)
+                });
+                self.split_matches('\'', true, true, both, |previous, next| {
+                    is_letter(previous) && !is_letter(next)
+                });
+            }
+            _ => self.split_matches('\'', false, false, both, |_, _| true),
+        }
+    }
+
+    // Words of the line, along with whether they are multi-dots
+    fn words(&self) -> Vec<(String, bool)> {
+        let mut words: Vec<(String, bool)> = Vec::new();
+        for (i, cell) in self.cells.iter().enumerate() {
+            if i == 0 || self.spaces[i] > 0 {
+                words.push((String::new(), false));
+            }
+            let (word, multi_dot) = words.last_mut().unwrap();
+            match *cell {
+                Cell::Char(ch) => word.push(ch),
+                Cell::Hyphen => word.push_str("@-@"),
+                Cell::MultiDot(len) => {
+                    word.extend(std::iter::repeat_n('.', len));
+                    *multi_dot = true;
+                }
+            }
+        }
+        words
+    }
+}
+
+impl MosesTokenizer {
+    // Default tokenization, which applies the rules of the original tokenizer in a few walks over every line
+    pub(super) fn tokenize_moses_raw(&self, text: &str) -> Vec<String> {
+        let (text, found_protected_patterns) = self.protect_patterns(text);
+        let mut line = Line::new(&text, &self.language, self.aggresive_hyphen_splitting);
+        if line.cells.is_empty() {
+            return Vec::new();
+        }
+        line.split_commas();
+        line.split_apostrophes(&self.language);
+
+        // Word tokenization
+        let words = line.words();
+        let mut tokens: Vec<String> = Vec::with_capacity(words.len() + 1);
+        for (i, (word, multi_dot)) in words.iter().enumerate() {
+            match word.strip_suffix('.') {
+                Some(pre)
+                    if !multi_dot
+                        && !pre.is_empty()
+                        && (i == words.len() - 1
+                            || !self
+                                .keeps_period(pre, words.get(i + 1).map(|(w, _)| w.as_str()))) =>
+                {
+                    tokens.push(pre.to_owned());
+                    tokens.push(".".to_owned());
+                }
+                _ => tokens.push(word.clone()),
+            }
+        }
+
+        // .' at end of sentence is missed
+        let last = tokens.last_mut().unwrap();
+        if let Some(pre) = last.strip_suffix(".'") {
+            *last = format!("{pre}.");
+            tokens.push("'".to_owned());
+        }
+
+        // Restore protected patterns
+        if !found_protected_patterns.is_empty() {
+            for token in &mut tokens {
+                for (substitution, pattern) in &found_protected_patterns {
+                    if token.contains(substitution.as_str()) {
+                        *token = token.replace(substitution, pattern);
+                    }
+                }
+            }
+        }
+        tokens
+    }
+
+    // Replaces all sequences of whitespaces with a single ASCII whitespace, removes ASCII characters 0-31 and
+    // replaces protected patterns with unique substitution strings
+    fn protect_patterns(&self, text: &str) -> (String, HashMap<String, String>) {
+        let mut found_protected_patterns: HashMap<String, String> = HashMap::new();
+        if self.protected_patterns.is_empty() {
+            let mut normalized_text = String::with_capacity(text.len());
+            // Words consisting solely of control characters are removed along with their space
+            let mut pending_space = false;
+            for word in text.split_whitespace() {
+                for ch in word.chars().filter(|&ch| !is_removed_control_char(ch)) {
+                    if pending_space {
+                        normalized_text.push(' ');
+                        pending_space = false;
+                    }
+                    normalized_text.push(ch);
+                }
+                pending_space = !normalized_text.is_empty();
+            }
+            return (normalized_text, found_protected_patterns);
+        }
+
+        // Protected patterns are matched with spaces at the beginning and end of the text
+        let mut protected_text: String = format!(
+            " {} ",
+            text.split_whitespace().collect::<Vec<&str>>().join(" ")
+        )
+        .chars()
+        .filter(|&ch| !is_removed_control_char(ch))
+        .collect();
+        for re_pattern in &self.protected_patterns {
+            protected_text = re_pattern
+                .replace_all(&protected_text, |caps: &regex::Captures| {
+                    let substitution =
+                        format!("THISISPROTECTED{:03}", found_protected_patterns.len());
+                    found_protected_patterns.insert(substitution.clone(), caps[0].to_owned());
+                    substitution
+                })
+                .to_string();
+        }
+        let normalized_text = protected_text
+            .split_whitespace()
+            .collect::<Vec<&str>>()
+            .join(" ");
+        (normalized_text, found_protected_patterns)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::ValueEnum;
+    use rayon::prelude::*;
+    use std::fs;
+    use std::path::Path;
+
+    // Pieces the random lines are made of, chosen for the rules of the tokenizer: letters of different cases and
+    // scripts, marks and symbols that count as letters or lowercase in some Unicode properties but not others,
+    // numbers, nonbreaking prefixes, punctuation, whitespace and control characters
+    const PIECES: [&str; 58] = [
+        "a", "b", "s", "t", "Z", "É", "ß", "ǅ", "中", "ि", "ⓐ", "Ⅷ", "1", "9", "٣", "½", "Dr",
+        "No", "etc", "e.g", "U.S", "Mr", "pp", "l", "'", "'", "''", "`", ".", ".", "..", ",", ",",
+        ",,", "-", "--", ":", "·", "$", "\"", "(", ")", "&", "|", "<", "[", "@", "%", " ", " ",
+        " ", "  ", "\t", "\u{a0}", "\u{7}", "\u{1f}", "\n", "0",
+    ];
+
+    // Small xorshift generator, so that the random lines are the same on every run
+    struct RandomLines(u64);
+
+    impl Iterator for RandomLines {
+        type Item = String;
+
+        fn next(&mut self) -> Option<String> {
+            let mut next = || {
+                self.0 ^= self.0 << 13;
+                self.0 ^= self.0 >> 7;
+                self.0 ^= self.0 << 17;
+                self.0 as usize
+            };
+            let len = next() % 24;
+            Some((0..len).map(|_| PIECES[next() % PIECES.len()]).collect())
+        }
+    }
+
+    fn tokenizers(language: &Language) -> Vec<MosesTokenizer> {
+        let builder = MosesTokenizer::builder(language.clone());
+        vec![
+            builder.clone().build().unwrap(),
+            builder
+                .clone()
+                .aggressive_hyphen_splitting(true)
+                .build()
+                .unwrap(),
+            builder
+                .protected_patterns(&[r"\d+", r"a[ ,]b", r"'s\b"])
+                .build()
+                .unwrap(),
+        ]
+    }
+
+    fn assert_same_tokens(tokenizer: &MosesTokenizer, line: &str) {
+        assert_eq!(
+            tokenizer.tokenize_moses_raw(line),
+            tokenizer.tokenize_regex_raw(line),
+            "{:?} {line:?}",
+            tokenizer.language()
+        );
+    }
+
+    #[test]
+    fn same_tokens_as_regex_cascade_on_random_lines() {
+        Language::value_variants()
+            .par_iter()
+            .enumerate()
+            .for_each(|(i, language)| {
+                let mut lines = RandomLines(0x2545_f491_4f6c_dd1d + i as u64);
+                for tokenizer in tokenizers(language) {
+                    for line in lines.by_ref().take(1000) {
+                        assert_same_tokens(&tokenizer, &line);
+                    }
+                }
+            });
+    }
+
+    #[test]
+    fn same_tokens_as_regex_cascade_on_real_text() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let read = |path: &str| fs::read_to_string(root.join(path)).unwrap_or_default();
+        let texts = read("tests/untokenized_text.txt") + &read("README.md");
+        Language::value_variants().par_iter().for_each(|language| {
+            // The vendored prefixes files have comments in the language and its script
+            let prefixes_file = read(&format!(
+                "src/nonbreaking_prefixes/nonbreaking_prefix.{}",
+                language.as_ref()
+            ));
+            for tokenizer in tokenizers(language) {
+                // The original tokenizer also turns DOTMULTI placeholders found in the text into periods
+                for line in texts
+                    .lines()
+                    .chain(prefixes_file.lines())
+                    .filter(|line| !line.contains("DOTMULTI"))
+                {
+                    assert_same_tokens(&tokenizer, line);
+                }
+            }
+        });
+    }
+
+    #[test]
+    fn multi_dot_placeholders_in_text() {
+        let tokenizer = MosesTokenizer::builder(Language::En).build().unwrap();
+        assert_eq!(
+            tokenizer.tokenize_line_string("DOTMULTI and DOTDOTMULTI... x"),
+            "DOTMULTI and DOTDOTMULTI ... x\n"
+        );
+    }
+}
@@ -0,0 +1,176 @@
+use crate::error::{Error, Result};
+use crate::stream::map_lines_parallel;
+use std::borrow::Cow;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+
+// Special characters of Moses and their escaped forms, from
+// https://github.com/moses-smt/mosesdecoder/blob/master/scripts/tokenizer/escape-special-chars.perl
+pub(super) const ESCAPES: [(char, &str); 8] = [
+    ('&', "&amp;"),   // escape escape
+    ('|', "&#124;"),  // factor separator
+    ('<', "&lt;"),    // xml
+    ('>', "&gt;"),    // xml
+    ('\'', "&apos;"), // xml
+    ('"', "&quot;"),  // xml
+    ('[', "&#91;"),   // syntax non-terminal
+    (']', "&#93;"),   // syntax non-terminal
+];
+
+// The Penn Treebank mode has already replaced quotes and brackets with their own symbols, so only the remaining
+// special characters are escaped
+pub(super) const PENN_ESCAPES: [(char, &str); 4] = [
+    ('&', "&amp;"),  // escape escape
+    ('|', "&#124;"), // factor separator
+    ('<', "&lt;"),   // xml
+    ('>', "&gt;"),   // xml
+];
+
+// Escaped forms and their special characters, including the legacy ones, from
+// https://github.com/moses-smt/mosesdecoder/blob/master/scripts/tokenizer/deescape-special-chars.perl
+const DEESCAPES: [(&str, char); 11] = [
+    ("&bar;", '|'),   // factor separator (legacy)
+    ("&#124;", '|'),  // factor separator
+    ("&lt;", '<'),    // xml
+    ("&gt;", '>'),    // xml
+    ("&bra;", '['),   // syntax non-terminal (legacy)
+    ("&ket;", ']'),   // syntax non-terminal (legacy)
+    ("&quot;", '"'),  // xml
+    ("&apos;", '\''), // xml
+    ("&#91;", '['),   // syntax non-terminal
+    ("&#93;", ']'),   // syntax non-terminal
+    ("&amp;", '&'),   // escape escape
+];
+
+/// Escapes the characters with a special meaning for Moses (`&`, `|`, `<`, `>`, `'`, `"`, `[` and `]`) as XML
+/// entities, borrowing the text when there is nothing to escape
+pub fn escape_special_chars(text: &str) -> Cow<'_, str> {
+    escape_chars(text, &ESCAPES)
+}
+
+/// Reverts `escape_special_chars`, also accepting the legacy `&bar;`, `&bra;` and `&ket;` entities. Every entity
+/// is replaced once, so `&amp;lt;` becomes `&lt;`.
+pub fn deescape_special_chars(text: &str) -> Cow<'_, str> {
+    let Some(first) = text.find('&') else {
+        return Cow::Borrowed(text);
+    };
+    let mut deescaped = String::with_capacity(text.len());
+    deescaped.push_str(&text[..first]);
+    let mut rest = &text[first..];
+    while let Some(index) = rest.find('&') {
+        deescaped.push_str(&rest[..index]);
+        rest = &rest[index..];
+        match DEESCAPES
+            .iter()
+            .find(|(entity, _)| rest.starts_with(entity))
+        {
+            Some((entity, ch)) => {
+                deescaped.push(*ch);
+                rest = &rest[entity.len()..];
+            }
+            None => {
+                deescaped.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    deescaped.push_str(rest);
+    Cow::Owned(deescaped)
+}
+
+// Replaces the given characters with their escaped forms in a single pass
+pub(super) fn escape_chars<'a>(text: &'a str, escapes: &[(char, &str)]) -> Cow<'a, str> {
+    let escaped_form = |ch: char| {
+        escapes
+            .iter()
+            .find(|(special_char, _)| *special_char == ch)
+            .map(|(_, escaped)| *escaped)
+    };
+    let Some(first) = text.find(|ch| escaped_form(ch).is_some()) else {
+        return Cow::Borrowed(text);
+    };
+    let mut escaped = String::with_capacity(text.len() + 8);
+    escaped.push_str(&text[..first]);
+    for ch in text[first..].chars() {
+        match escaped_form(ch) {
+            Some(escaped_ch) => escaped.push_str(escaped_ch),
+            None => escaped.push(ch),
+        }
+    }
+    Cow::Owned(escaped)
+}
+
+pub fn escape_special_chars_file(input_file_path: &str, output_file_path: &str) -> Result<()> {
+    let reader =
+        BufReader::new(File::open(input_file_path).map_err(|e| Error::io(input_file_path, e))?);
+    let writer =
+        BufWriter::new(File::create(output_file_path).map_err(|e| Error::io(output_file_path, e))?);
+    map_lines_parallel(
+        reader,
+        writer,
+        Some(input_file_path),
+        Some(output_file_path),
+        |line| format!("{}\n", escape_special_chars(line)),
+    )
+}
+
+pub fn escape_special_chars_reader(reader: impl BufRead, writer: impl Write) -> Result<()> {
+    map_lines_parallel(reader, writer, None, None, |line| {
+        format!("{}\n", escape_special_chars(line))
+    })
+}
+
+pub fn deescape_special_chars_file(input_file_path: &str, output_file_path: &str) -> Result<()> {
+    let reader =
+        BufReader::new(File::open(input_file_path).map_err(|e| Error::io(input_file_path, e))?);
+    let writer =
+        BufWriter::new(File::create(output_file_path).map_err(|e| Error::io(output_file_path, e))?);
+    map_lines_parallel(
+        reader,
+        writer,
+        Some(input_file_path),
+        Some(output_file_path),
+        |line| format!("{}\n", deescape_special_chars(line)),
+    )
+}
+
+pub fn deescape_special_chars_reader(reader: impl BufRead, writer: impl Write) -> Result<()> {
+    map_lines_parallel(reader, writer, None, None, |line| {
+        format!("{}\n", deescape_special_chars(line))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escape_and_deescape() {
+        let text = "a|b <c> 'd' \"e\" [f] & g";
+        let escaped = "a&#124;b &lt;c&gt; &apos;d&apos; &quot;e&quot; &#91;f&#93; &amp; g";
+        assert_eq!(escape_special_chars(text), escaped);
+        assert_eq!(deescape_special_chars(escaped), text);
+        assert_eq!(deescape_special_chars("&bar; &bra;x&ket;"), "| [x]");
+    }
+
+    #[test]
+    fn escape_single_pass() {
+        // Escaped entities are escaped again, and de-escaped only once
+        assert_eq!(escape_special_chars("&amp;"), "&amp;amp;");
+        assert_eq!(deescape_special_chars("&amp;lt; &amp;amp;"), "&lt; &amp;");
+        assert_eq!(deescape_special_chars("AT&T &x; &"), "AT&T &x; &");
+    }
+
+    #[test]
+    fn escape_borrows_unchanged_text() {
+        assert!(matches!(
+            escape_special_chars("plain text"),
+            Cow::Borrowed(_)
+        ));
+        assert!(matches!(
+            deescape_special_chars("plain text"),
+            Cow::Borrowed(_)
+        ));
+        assert!(matches!(escape_special_chars("a & b"), Cow::Owned(_)));
+    }
+}
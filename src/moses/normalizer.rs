@@ -0,0 +1,399 @@
+use super::Language;
+use crate::error::{Error, Result};
+use crate::stream::map_lines_parallel;
+use rayon::prelude::*;
+use regex::Regex;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::sync::LazyLock;
+
+// Substitutions from https://github.com/moses-smt/mosesdecoder/blob/master/scripts/tokenizer/normalize-punctuation.perl,
+// following the Sacremoses port (https://github.com/hplt-project/sacremoses/blob/master/sacremoses/normalize.py)
+// where the two differ
+type Substitutions = LazyLock<Vec<(Regex, &'static str)>>;
+
+fn compile(substitutions: &[(&str, &'static str)]) -> Vec<(Regex, &'static str)> {
+    substitutions
+        .iter()
+        .map(|(pattern, replacement)| (Regex::new(pattern).unwrap(), *replacement))
+        .collect()
+}
+
+// Remove extra spaces
+static EXTRA_WHITESPACE: Substitutions = LazyLock::new(|| {
+    compile(&[
+        (r"\r", ""),
+        (r"\(", " ("),
+        (r"\)", ") "),
+        (r" +", " "),
+        (r"\) ([\.!:\?;,])", ")$1"),
+        (r"\( ", "("),
+        (r" \)", ")"),
+        (r"(\d) %", "$1%"),
+        (r" :", ":"),
+        (r" ;", ";"),
+    ])
+});
+
+// Backticks and double single quotes are kept as they are in Penn Treebank style text
+static NORMALIZE_UNICODE_IF_NOT_PENN: Substitutions =
+    LazyLock::new(|| compile(&[(r"`", "'"), (r"''", " \" ")]));
+
+// Normalize unicode punctuation
+static NORMALIZE_UNICODE: Substitutions = LazyLock::new(|| {
+    compile(&[
+        ("„", "\""),
+        ("“", "\""),
+        ("”", "\""),
+        ("–", "-"),
+        ("—", " - "),
+        (r" +", " "),
+        ("´", "'"),
+        ("([a-zA-Z])‘([a-zA-Z])", "$1'$2"),
+        ("([a-zA-Z])’([a-zA-Z])", "$1'$2"),
+        ("‘", "'"),
+        ("‚", "'"),
+        ("’", "'"),
+        (r"''", "\""),
+        ("´´", "\""),
+        ("…", "..."),
+    ])
+});
+
+static FRENCH_QUOTES: Substitutions = LazyLock::new(|| {
+    compile(&[
+        ("\u{00A0}«\u{00A0}", "\""),
+        ("«\u{00A0}", "\""),
+        ("«", "\""),
+        ("\u{00A0}»\u{00A0}", "\""),
+        ("\u{00A0}»", "\""),
+        ("»", "\""),
+    ])
+});
+
+static HANDLE_PSEUDO_SPACES: Substitutions = LazyLock::new(|| {
+    compile(&[
+        ("\u{00A0}%", "%"),
+        ("nº\u{00A0}", "nº "),
+        ("\u{00A0}:", ":"),
+        ("\u{00A0}ºC", " ºC"),
+        ("\u{00A0}cm", " cm"),
+        ("\u{00A0}\\?", "?"),
+        ("\u{00A0}!", "!"),
+        ("\u{00A0};", ";"),
+        (",\u{00A0}", ", "),
+        (r" +", " "),
+    ])
+});
+
+// English "quotation," followed by comma, style
+static EN_QUOTATION_FOLLOWED_BY_COMMA: Substitutions =
+    LazyLock::new(|| compile(&[(r#""([,\.]+)"#, "$1\"")]));
+
+// German/Spanish/French "quotation", followed by comma, style
+static OTHER_QUOTATION_FOLLOWED_BY_COMMA: Substitutions = LazyLock::new(|| {
+    compile(&[
+        (r#",""#, "\","),
+        // Don't fix period at end of sentence
+        (r#"(\.+)"(\s*[^<])"#, "\"$1$2"),
+    ])
+});
+
+static DE_ES_CS_FR_NUMBERS: Substitutions =
+    LazyLock::new(|| compile(&[("(\\d)\u{00A0}(\\d)", "$1,$2")]));
+
+static OTHER_NUMBERS: Substitutions = LazyLock::new(|| compile(&[("(\\d)\u{00A0}(\\d)", "$1.$2")]));
+
+// Substitutions from https://github.com/moses-smt/mosesdecoder/blob/master/scripts/tokenizer/replace-unicode-punctuation.perl
+static REPLACE_UNICODE_PUNCTUATION: Substitutions = LazyLock::new(|| {
+    compile(&[
+        ("，", ","),
+        (r"。\s*", ". "),
+        ("、", ","),
+        ("”", "\""),
+        ("“", "\""),
+        ("∶", ":"),
+        ("：", ":"),
+        ("？", "?"),
+        ("《", "\""),
+        ("》", "\""),
+        ("）", ")"),
+        ("！", "!"),
+        ("（", "("),
+        ("；", ";"),
+        ("」", "\""),
+        ("「", "\""),
+        ("０", "0"),
+        ("１", "1"),
+        ("２", "2"),
+        ("３", "3"),
+        ("４", "4"),
+        ("５", "5"),
+        ("６", "6"),
+        ("７", "7"),
+        ("８", "8"),
+        ("９", "9"),
+        (r"．\s*", ". "),
+        ("～", "~"),
+        ("’", "'"),
+        ("…", "..."),
+        ("━", "-"),
+        ("〈", "<"),
+        ("〉", ">"),
+        ("【", "["),
+        ("】", "]"),
+        ("％", "%"),
+    ])
+});
+
+fn apply(text: String, substitutions: &[(Regex, &'static str)]) -> String {
+    substitutions
+        .iter()
+        .fold(text, |text, (regex, replacement)| {
+            regex.replace_all(&text, *replacement).into_owned()
+        })
+}
+
+/// Replaces full-width and CJK punctuation with its ASCII counterpart, like `replace-unicode-punctuation.perl`
+pub fn replace_unicode_punctuation(text: &str) -> String {
+    apply(text.to_owned(), &REPLACE_UNICODE_PUNCTUATION)
+}
+
+pub fn normalize_punctuation_file(
+    input_file_path: &str,
+    output_file_path: &str,
+    language: Language,
+    penn: bool,
+    pre_replace_unicode_punct: bool,
+    post_remove_control_chars: bool,
+) -> Result<()> {
+    let reader =
+        BufReader::new(File::open(input_file_path).map_err(|e| Error::io(input_file_path, e))?);
+    let writer =
+        BufWriter::new(File::create(output_file_path).map_err(|e| Error::io(output_file_path, e))?);
+    map_lines_parallel(
+        reader,
+        writer,
+        Some(input_file_path),
+        Some(output_file_path),
+        |line| {
+            normalize_punctuation_line(
+                line,
+                language.clone(),
+                penn,
+                pre_replace_unicode_punct,
+                post_remove_control_chars,
+            )
+        },
+    )
+}
+
+pub fn normalize_punctuation_reader(
+    reader: impl BufRead,
+    writer: impl Write,
+    language: Language,
+    penn: bool,
+    pre_replace_unicode_punct: bool,
+    post_remove_control_chars: bool,
+) -> Result<()> {
+    map_lines_parallel(reader, writer, None, None, |line| {
+        normalize_punctuation_line(
+            line,
+            language.clone(),
+            penn,
+            pre_replace_unicode_punct,
+            post_remove_control_chars,
+        )
+    })
+}
+
+pub fn normalize_punctuation(
+    text: &str,
+    language: Language,
+    penn: bool,
+    pre_replace_unicode_punct: bool,
+    post_remove_control_chars: bool,
+) -> String {
+    text.par_lines()
+        .map(|line| {
+            normalize_punctuation_line(
+                line,
+                language.clone(),
+                penn,
+                pre_replace_unicode_punct,
+                post_remove_control_chars,
+            )
+        })
+        .collect::<String>()
+}
+
+pub fn normalize_punctuation_line(
+    text: &str,
+    language: Language,
+    penn: bool,
+    pre_replace_unicode_punct: bool,
+    post_remove_control_chars: bool,
+) -> String {
+    let mut normalized_text = text.trim_end_matches('\n').to_owned();
+    if pre_replace_unicode_punct {
+        normalized_text = replace_unicode_punctuation(&normalized_text);
+    }
+
+    normalized_text = apply(normalized_text, &EXTRA_WHITESPACE);
+    if !penn {
+        normalized_text = apply(normalized_text, &NORMALIZE_UNICODE_IF_NOT_PENN);
+    }
+    normalized_text = apply(normalized_text, &NORMALIZE_UNICODE);
+    normalized_text = apply(normalized_text, &FRENCH_QUOTES);
+    normalized_text = apply(normalized_text, &HANDLE_PSEUDO_SPACES);
+
+    // Czech is confused about the position of quotation marks and commas, so it is left untouched
+    match language {
+        Language::En => {
+            normalized_text = apply(normalized_text, &EN_QUOTATION_FOLLOWED_BY_COMMA);
+        }
+        Language::Cs => {}
+        _ => {
+            normalized_text = apply(normalized_text, &OTHER_QUOTATION_FOLLOWED_BY_COMMA);
+        }
+    }
+    match language {
+        Language::De | Language::Es | Language::Cs | Language::Fr => {
+            normalized_text = apply(normalized_text, &DE_ES_CS_FR_NUMBERS);
+        }
+        _ => {
+            normalized_text = apply(normalized_text, &OTHER_NUMBERS);
+        }
+    }
+
+    if post_remove_control_chars {
+        static RE_CONTROL_CHARS: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\p{C}").unwrap());
+        normalized_text = RE_CONTROL_CHARS
+            .replace_all(&normalized_text, "")
+            .into_owned();
+    }
+
+    format!("{}\n", normalized_text.trim())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test cases ported from https://github.com/hplt-project/sacremoses/blob/master/sacremoses/test/test_normalizer.py
+
+    #[test]
+    fn normalize_documents() {
+        let inputs = [
+            "The United States in 1805 (color map)                 _Facsimile of Letter Sent to",
+            "The very expression “the Convention” is used in the message",
+            "Comparison of the prior seizing powers ... power of a subordinate 'to\n",
+        ];
+        let expected = [
+            "The United States in 1805 (color map) _Facsimile of Letter Sent to\n",
+            "The very expression \"the Convention\" is used in the message\n",
+            "Comparison of the prior seizing powers ... power of a subordinate 'to\n",
+        ];
+        for (text, expect) in inputs.iter().zip(expected) {
+            assert_eq!(
+                normalize_punctuation_line(text, Language::En, false, false, false),
+                expect
+            );
+        }
+    }
+
+    #[test]
+    fn normalize_quote_comma() {
+        let text = "THIS EBOOK IS OTHERWISE PROVIDED TO YOU \"AS-IS\".";
+        assert_eq!(
+            normalize_punctuation_line(text, Language::En, false, false, false),
+            "THIS EBOOK IS OTHERWISE PROVIDED TO YOU \"AS-IS.\"\n"
+        );
+    }
+
+    #[test]
+    fn normalize_numbers() {
+        assert_eq!(
+            normalize_punctuation_line("12\u{00A0}123", Language::En, false, false, false),
+            "12.123\n"
+        );
+        assert_eq!(
+            normalize_punctuation_line("12\u{00A0}123", Language::De, false, false, false),
+            "12,123\n"
+        );
+        assert_eq!(
+            normalize_punctuation_line("12 123", Language::En, false, false, false),
+            "12 123\n"
+        );
+    }
+
+    #[test]
+    fn normalize_single_apostrophe() {
+        assert_eq!(
+            normalize_punctuation_line("yesterday ’s reception", Language::En, false, false, false),
+            "yesterday 's reception\n"
+        );
+    }
+
+    #[test]
+    fn normalize_multiple_lines() {
+        assert_eq!(
+            normalize_punctuation(
+                "«Bonjour»\n12\u{00A0}000 €",
+                Language::Fr,
+                false,
+                false,
+                false
+            ),
+            "\"Bonjour\"\n12,000 €\n"
+        );
+    }
+
+    #[test]
+    fn replace_unicode_punct() {
+        assert_eq!(
+            replace_unicode_punctuation("０《１２３》 ４５６％ 【７８９】"),
+            "0\"123\" 456% [789]"
+        );
+    }
+
+    #[test]
+    fn normalization_pipeline() {
+        assert_eq!(
+            normalize_punctuation_line(
+                "０《１２３》      ４５６％  '' 【７８９】",
+                Language::En,
+                false,
+                true,
+                true
+            ),
+            "0\"123\" 456% \" [789]\n"
+        );
+    }
+
+    #[test]
+    fn normalize_french_quotes_and_spaces() {
+        assert_eq!(
+            normalize_punctuation_line(
+                "Il a dit «\u{00A0}bonjour\u{00A0}»\u{00A0}!",
+                Language::Fr,
+                false,
+                false,
+                false
+            ),
+            "Il a dit \"bonjour\"!\n"
+        );
+    }
+
+    #[test]
+    fn normalize_penn_keeps_backticks() {
+        assert_eq!(
+            normalize_punctuation_line("``Hello'' , he said", Language::En, true, false, false),
+            "``Hello\" , he said\n"
+        );
+        assert_eq!(
+            normalize_punctuation_line("``Hello'' , he said", Language::En, false, false, false),
+            "\" Hello \" , he said\n"
+        );
+    }
+}
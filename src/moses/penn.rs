@@ -0,0 +1,261 @@
+use super::{MosesTokenizer, is_removed_control_char};
+use regex::Regex;
+use std::sync::LazyLock;
+
+// Penn Treebank symbols that replace characters of the original text, along with the forms they can come from
+pub(super) const PENN_SYMBOLS: [(&str, &[&str]); 9] = [
+    ("``", &["``", "\""]),
+    ("''", &["''", "\""]),
+    ("`", &["`", "'"]),
+    ("-LRB-", &["("]),
+    ("-RRB-", &[")"]),
+    ("-LSB-", &["["]),
+    ("-RSB-", &["]"]),
+    ("-LCB-", &["{"]),
+    ("-RCB-", &["}"]),
+];
+
+impl MosesTokenizer {
+    // Tokenization compatible with the Penn Treebank, adapted in the original Moses tokenizer from Robert MacIntyre's
+    // sed script (http://www.cis.upenn.edu/~treebank/tokenizer.sed). Protected patterns and aggressive hyphen splitting
+    // do not apply in this mode.
+    pub(super) fn tokenize_penn_raw(&self, text: &str) -> Vec<String> {
+        // Replace all sequences of whitespaces with a single ASCII whitespace and remove ASCII characters 0-31
+        let mut tokenized_text: String = text
+            .trim_end_matches('\n')
+            .split_whitespace()
+            .collect::<Vec<&str>>()
+            .join(" ")
+            .chars()
+            .filter(|&ch| !is_removed_control_char(ch))
+            .collect();
+
+        // Attempt to get correct directional quotes, close quotes are handled at the end
+        static RE_START_DOUBLE_BACKTICK: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(r"^``").unwrap());
+        tokenized_text = RE_START_DOUBLE_BACKTICK
+            .replace(&tokenized_text, "`` ")
+            .to_string();
+        static RE_START_DOUBLE_QUOTE: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(r#"^""#).unwrap());
+        tokenized_text = RE_START_DOUBLE_QUOTE
+            .replace(&tokenized_text, "`` ")
+            .to_string();
+        static RE_START_BACKTICK: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(r"^`([^`])").unwrap());
+        tokenized_text = RE_START_BACKTICK
+            .replace(&tokenized_text, "` $1")
+            .to_string();
+        static RE_START_SINGLE_QUOTE: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(r"^'").unwrap());
+        tokenized_text = RE_START_SINGLE_QUOTE
+            .replace(&tokenized_text, "`  ")
+            .to_string();
+        static RE_OPENING_DOUBLE_QUOTE: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(r#"([ (\[{<])""#).unwrap());
+        tokenized_text = RE_OPENING_DOUBLE_QUOTE
+            .replace_all(&tokenized_text, "$1 `` ")
+            .to_string();
+        static RE_OPENING_DOUBLE_BACKTICK: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(r"([ (\[{<])``").unwrap());
+        tokenized_text = RE_OPENING_DOUBLE_BACKTICK
+            .replace_all(&tokenized_text, "$1 `` ")
+            .to_string();
+        static RE_OPENING_BACKTICK: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(r"([ (\[{<])`([^`])").unwrap());
+        tokenized_text = RE_OPENING_BACKTICK
+            .replace_all(&tokenized_text, "$1 ` $2")
+            .to_string();
+        static RE_OPENING_SINGLE_QUOTE: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(r"([ (\[{<])'").unwrap());
+        tokenized_text = RE_OPENING_SINGLE_QUOTE
+            .replace_all(&tokenized_text, "$1 ` ")
+            .to_string();
+
+        // Protect ellipses from period splitting
+        tokenized_text = tokenized_text.replace("...", " _ELLIPSIS_ ");
+
+        // Separate out "," except if within numbers (5,300)
+        static RE_COMMA_NON_NUMERIC: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(r"([^\p{N}]),([^\p{N}])").unwrap());
+        tokenized_text = RE_COMMA_NON_NUMERIC
+            .replace_all(&tokenized_text, "$1 , $2")
+            .to_string();
+        // Separate "," before and after a number
+        static RE_COMMA_AFTER_NUMBER: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(r"([\p{N}]),([^\p{N}])").unwrap());
+        tokenized_text = RE_COMMA_AFTER_NUMBER
+            .replace_all(&tokenized_text, "$1 , $2")
+            .to_string();
+        static RE_COMMA_BEFORE_NUMBER: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(r"([^\p{N}]),([\p{N}])").unwrap());
+        tokenized_text = RE_COMMA_BEFORE_NUMBER
+            .replace_all(&tokenized_text, "$1 , $2")
+            .to_string();
+
+        // Separate out symbols and currency signs
+        static RE_SYMBOLS: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(r"([;:@#\$%&\p{Sc}\p{So}])").unwrap());
+        tokenized_text = RE_SYMBOLS.replace_all(&tokenized_text, " $1 ").to_string();
+
+        // Separate out intra-token slashes. PTB tokenization doesn't do this, so the tokens should be merged prior to
+        // parsing with a PTB-trained parser (see syntax-hyphen-splitting.perl in Moses)
+        static RE_SLASH: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(r"([\p{L}\p{N}])/([\p{L}\p{N}])").unwrap());
+        tokenized_text = RE_SLASH
+            .replace_all(&tokenized_text, "$1 @/@ $2")
+            .to_string();
+
+        // Assume sentence tokenization has been done first, so split final periods only
+        static RE_FINAL_PERIOD: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(r#"([^.])([.])([\]\)}>"']*) ?$"#).unwrap());
+        tokenized_text = RE_FINAL_PERIOD
+            .replace_all(&tokenized_text, "$1 $2$3 ")
+            .to_string();
+        // However, split all question marks and exclamation points, since they don't have the abbreviation ambiguity
+        static RE_QUESTION_EXCLAMATION: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(r"([?!])").unwrap());
+        tokenized_text = RE_QUESTION_EXCLAMATION
+            .replace_all(&tokenized_text, " $1 ")
+            .to_string();
+
+        // Parentheses, brackets, etc.
+        static RE_BRACKETS: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(r"([\]\[\(\)\{\}<>])").unwrap());
+        tokenized_text = RE_BRACKETS.replace_all(&tokenized_text, " $1 ").to_string();
+        tokenized_text = tokenized_text
+            .replace("(", "-LRB-")
+            .replace(")", "-RRB-")
+            .replace("[", "-LSB-")
+            .replace("]", "-RSB-")
+            .replace("{", "-LCB-")
+            .replace("}", "-RCB-")
+            .replace("--", " -- ");
+
+        // Add spaces at the beginning and end of the text to reduce the necessary number of regexes
+        tokenized_text = format!(" {} ", tokenized_text);
+
+        // Closing double quotes
+        tokenized_text = tokenized_text.replace("\"", " '' ");
+        // Possessive or closing single quote
+        static RE_CLOSING_SINGLE_QUOTE: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(r"([^'])' ").unwrap());
+        tokenized_text = RE_CLOSING_SINGLE_QUOTE
+            .replace_all(&tokenized_text, "$1 ' ")
+            .to_string();
+        // As in it's, I'm, we'd
+        static RE_CONTRACTION_SMD: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(r"'([sSmMdD]) ").unwrap());
+        tokenized_text = RE_CONTRACTION_SMD
+            .replace_all(&tokenized_text, " '$1 ")
+            .to_string();
+        for (contraction, split_contraction) in [
+            ("'ll ", " 'll "),
+            ("'re ", " 're "),
+            ("'ve ", " 've "),
+            ("n't ", " n't "),
+            ("'LL ", " 'LL "),
+            ("'RE ", " 'RE "),
+            ("'VE ", " 'VE "),
+            ("N'T ", " N'T "),
+        ] {
+            tokenized_text = tokenized_text.replace(contraction, split_contraction);
+        }
+
+        // Split some special cases of words that are contractions in the Penn Treebank
+        static RE_SPECIAL_CONTRACTIONS: LazyLock<[(Regex, &str); 10]> = LazyLock::new(|| {
+            [
+                (Regex::new(r" ([Cc])annot ").unwrap(), " ${1}an not "),
+                (Regex::new(r" ([Dd])'ye ").unwrap(), " ${1}' ye "),
+                (Regex::new(r" ([Gg])imme ").unwrap(), " ${1}im me "),
+                (Regex::new(r" ([Gg])onna ").unwrap(), " ${1}on na "),
+                (Regex::new(r" ([Gg])otta ").unwrap(), " ${1}ot ta "),
+                (Regex::new(r" ([Ll])emme ").unwrap(), " ${1}em me "),
+                (Regex::new(r" ([Mm])ore'n ").unwrap(), " ${1}ore 'n "),
+                (Regex::new(r" '([Tt])is ").unwrap(), " '$1 is "),
+                (Regex::new(r" '([Tt])was ").unwrap(), " '$1 was "),
+                (Regex::new(r" ([Ww])anna ").unwrap(), " ${1}an na "),
+            ]
+        });
+        for (re_contraction, replacement) in RE_SPECIAL_CONTRACTIONS.iter() {
+            tokenized_text = re_contraction
+                .replace_all(&tokenized_text, *replacement)
+                .to_string();
+        }
+
+        // Word tokenization, final periods have already been split
+        tokenized_text = self.split_periods(&tokenized_text, false);
+
+        // Restore ellipses
+        tokenized_text
+            .split_ascii_whitespace()
+            .map(|token| token.replace("_ELLIPSIS_", "..."))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{Language, MosesTokenizer};
+
+    fn penn_tokenize(text: &str) -> String {
+        MosesTokenizer::builder(Language::En)
+            .penn(true)
+            .build()
+            .unwrap()
+            .tokenize_line_string(text)
+    }
+
+    #[test]
+    fn penn_quotes_and_contractions() {
+        assert_eq!(
+            penn_tokenize("\"I can't believe it,\" he said."),
+            "`` I ca n't believe it , '' he said .\n"
+        );
+        assert_eq!(
+            penn_tokenize("'Tis the season, isn't it? They'll say 'yes'."),
+            "` Tis the season , is n't it ? They 'll say ` yes ' .\n"
+        );
+    }
+
+    #[test]
+    fn penn_brackets_and_symbols() {
+        assert_eq!(
+            penn_tokenize("(This) costs $5.00 [or 5,300 yen] & more/less..."),
+            "-LRB- This -RRB- costs $ 5.00 -LSB- or 5,300 yen -RSB- &amp; more @/@ less ...\n"
+        );
+    }
+
+    #[test]
+    fn penn_special_contractions_and_prefixes() {
+        assert_eq!(
+            penn_tokenize("Mr. Smith cannot go, gonna stay -- he said."),
+            "Mr. Smith can not go , gon na stay -- he said .\n"
+        );
+    }
+
+    #[test]
+    fn penn_offsets() {
+        let tokenizer = MosesTokenizer::builder(Language::En)
+            .penn(true)
+            .build()
+            .unwrap();
+        let text = "\"Hi (there)\"";
+        let tokens: Vec<(String, &str)> = tokenizer
+            .tokenize_spans(text)
+            .into_iter()
+            .map(|t| (t.text, &text[t.start..t.end]))
+            .collect();
+        assert_eq!(
+            tokens,
+            vec![
+                ("``".to_string(), "\""),
+                ("Hi".to_string(), "Hi"),
+                ("-LRB-".to_string(), "("),
+                ("there".to_string(), "there"),
+                ("-RRB-".to_string(), ")"),
+                ("''".to_string(), "\""),
+            ]
+        );
+    }
+}
@@ -0,0 +1,24 @@
+// Format of the Moses `nonbreaking_prefix.xx` files. build.rs includes this file to generate the built-in prefixes, so
+// it only depends on the standard library.
+
+/// Whether a nonbreaking prefix keeps its period before any word or only before numbers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrefixType {
+    Always,
+    NumericOnly,
+}
+
+// Prefix of a line of a prefixes file along with its type, with the same rules as the `load_prefixes` subroutine of
+// the original tokenizer. Empty lines and comments have no prefix.
+pub(crate) fn parse_prefix_line(line: &str) -> Option<(&str, PrefixType)> {
+    let line = line.trim_end();
+    if line.is_empty() || line.starts_with('#') {
+        return None;
+    }
+    match line.rfind("#NUMERIC_ONLY#") {
+        Some(index) if line[..index].ends_with(char::is_whitespace) => {
+            Some((line[..index].trim_end(), PrefixType::NumericOnly))
+        }
+        _ => Some((line, PrefixType::Always)),
+    }
+}
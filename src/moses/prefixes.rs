@@ -0,0 +1,167 @@
+use super::Language;
+use super::PrefixType;
+use super::nonbreaking_prefixes::builtin_prefixes;
+use super::prefix_file::parse_prefix_line;
+use crate::error::{Error, Result};
+use crate::stream::read_line;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::ptr;
+
+/// Words that, followed by a period, do not end a sentence, such as "Dr" or "etc". The built-in prefixes of a
+/// language can be extended with (or replaced by) prefixes loaded from files in the Moses `nonbreaking_prefix.xx`
+/// format.
+#[derive(Debug, Clone, Default)]
+pub struct NonbreakingPrefixes {
+    // Built-in prefixes of a language, if they are used
+    builtin: Option<&'static phf::Map<&'static str, PrefixType>>,
+    // Prefixes added at runtime, which take precedence over the built-in ones
+    custom: HashMap<String, PrefixType>,
+}
+
+impl NonbreakingPrefixes {
+    /// Empty set of prefixes
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Built-in prefixes of a language, which are empty for languages without a Moses prefixes file
+    pub fn builtin(language: &Language) -> Self {
+        NonbreakingPrefixes {
+            builtin: builtin_prefixes(language),
+            custom: HashMap::new(),
+        }
+    }
+
+    /// Loads a Moses prefixes file, with one prefix per line, `#` comments and `#NUMERIC_ONLY#` markers for the
+    /// prefixes that only apply before numbers
+    pub fn load(prefixes_file_path: &str) -> Result<Self> {
+        let reader = BufReader::new(
+            File::open(prefixes_file_path).map_err(|e| Error::io(prefixes_file_path, e))?,
+        );
+        Self::read(reader).map_err(|e| e.with_path(prefixes_file_path))
+    }
+
+    pub fn read(mut reader: impl BufRead) -> Result<Self> {
+        let mut prefixes = Self::new();
+        let mut offset = 0;
+        while let Some(line) = read_line(&mut reader, &mut offset, None)? {
+            prefixes.insert_line(&line);
+        }
+        Ok(prefixes)
+    }
+
+    /// Parses the contents of a Moses prefixes file
+    pub fn parse(text: &str) -> Self {
+        let mut prefixes = Self::new();
+        for line in text.lines() {
+            prefixes.insert_line(line);
+        }
+        prefixes
+    }
+
+    pub fn insert(&mut self, prefix: &str, prefix_type: PrefixType) {
+        self.custom.insert(prefix.to_owned(), prefix_type);
+    }
+
+    /// Adds the prefixes of `other`, which replace the existing ones when they are the same
+    pub fn merge(&mut self, other: &NonbreakingPrefixes) {
+        if let Some(other_builtin) = other.builtin {
+            if self.is_empty() {
+                self.builtin = Some(other_builtin);
+            } else {
+                for (prefix, prefix_type) in other_builtin.entries() {
+                    self.insert(prefix, *prefix_type);
+                }
+            }
+        }
+        for (prefix, prefix_type) in &other.custom {
+            self.insert(prefix, *prefix_type);
+        }
+    }
+
+    pub fn get(&self, prefix: &str) -> Option<PrefixType> {
+        // Most tokenizers have no custom prefixes, which saves hashing every word
+        if !self.custom.is_empty()
+            && let Some(prefix_type) = self.custom.get(prefix)
+        {
+            return Some(*prefix_type);
+        }
+        self.builtin?.get(prefix).copied()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.custom.is_empty() && self.builtin.is_none_or(phf::Map::is_empty)
+    }
+
+    /// Every prefix along with its type, custom prefixes hiding the built-in ones they replace
+    pub fn iter(&self) -> impl Iterator<Item = (&str, PrefixType)> {
+        let builtin = self
+            .builtin
+            .into_iter()
+            .flat_map(phf::Map::entries)
+            .filter(|(prefix, _)| !self.custom.contains_key(**prefix))
+            .map(|(prefix, prefix_type)| (*prefix, *prefix_type));
+        self.custom
+            .iter()
+            .map(|(prefix, prefix_type)| (prefix.as_str(), *prefix_type))
+            .chain(builtin)
+    }
+
+    fn insert_line(&mut self, line: &str) {
+        if let Some((prefix, prefix_type)) = parse_prefix_line(line) {
+            self.insert(prefix, prefix_type);
+        }
+    }
+}
+
+// The built-in prefixes of every language are a single static map
+impl PartialEq for NonbreakingPrefixes {
+    fn eq(&self, other: &Self) -> bool {
+        self.builtin.map(ptr::from_ref) == other.builtin.map(ptr::from_ref)
+            && self.custom == other.custom
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_prefixes_file() {
+        let prefixes = NonbreakingPrefixes::parse(
+            "# Domain abbreviations\n\napprox\nFig #NUMERIC_ONLY#\n#NUMERIC_ONLY#\n",
+        );
+        assert_eq!(prefixes.get("approx"), Some(PrefixType::Always));
+        assert_eq!(prefixes.get("Fig"), Some(PrefixType::NumericOnly));
+        assert_eq!(prefixes.get("Dr"), None);
+        assert_eq!(prefixes.get("#NUMERIC_ONLY#"), None);
+        let mut entries: Vec<(&str, PrefixType)> = prefixes.iter().collect();
+        entries.sort_unstable_by_key(|(prefix, _)| *prefix);
+        assert_eq!(
+            entries,
+            [
+                ("Fig", PrefixType::NumericOnly),
+                ("approx", PrefixType::Always)
+            ]
+        );
+    }
+
+    #[test]
+    fn merge_with_builtin_prefixes() {
+        let mut prefixes = NonbreakingPrefixes::builtin(&Language::En);
+        assert_eq!(prefixes.get("Dr"), Some(PrefixType::Always));
+        assert_eq!(prefixes.get("No"), Some(PrefixType::NumericOnly));
+        prefixes.merge(&NonbreakingPrefixes::parse("approx\nNo\n"));
+        assert_eq!(prefixes.get("approx"), Some(PrefixType::Always));
+        assert_eq!(prefixes.get("No"), Some(PrefixType::Always));
+        assert_eq!(prefixes.get("Dr"), Some(PrefixType::Always));
+
+        let mut prefixes = NonbreakingPrefixes::parse("approx\n");
+        prefixes.merge(&NonbreakingPrefixes::builtin(&Language::De));
+        assert_eq!(prefixes.get("approx"), Some(PrefixType::Always));
+        assert_eq!(prefixes.get("Nr"), Some(PrefixType::Always));
+        assert!(NonbreakingPrefixes::builtin(&Language::Generic).is_empty());
+    }
+}
@@ -0,0 +1,135 @@
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// Built-in protected patterns, similar to the `basic-protected-patterns` file shipped with the original Moses
+/// tokenizer. Their matches are kept as single tokens.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    strum_macros::AsRefStr,
+    strum_macros::EnumIter,
+    clap::ValueEnum,
+)]
+#[strum(serialize_all = "kebab-case")]
+pub enum ProtectedPreset {
+    /// http(s)/ftp URLs and URLs starting with "www."
+    Url,
+    /// E-mail addresses
+    Email,
+    /// Opening, closing and self-closing HTML/XML tags, including their attributes
+    HtmlTag,
+    /// Named and numeric HTML entities such as `&amp;` or `&#124;`
+    HtmlEntity,
+    /// Hashtags such as `#tokenization`
+    Hashtag,
+    /// Mentions such as `@tokenaisu`
+    Mention,
+    /// Numbers with inner separators such as `3.14`, `10:30` or `12/05/2024`
+    Number,
+}
+
+impl ProtectedPreset {
+    pub fn pattern(&self) -> &'static str {
+        match self {
+            ProtectedPreset::Url => {
+                r#"(?:(?:https?|ftp)://|www\.)[^\s<>"]*[^\s<>".,;:!?'()\[\]{}]"#
+            }
+            ProtectedPreset::Email => r"[\w\-\.]+@(?:[\w\-]+\.)+[a-zA-Z]{2,}",
+            ProtectedPreset::HtmlTag => r"</?[A-Za-z][\w:\-]*(?:\s+[^<>]*?)?\s*/?>",
+            ProtectedPreset::HtmlEntity => r"&(?:[A-Za-z][A-Za-z0-9]*|#[0-9]+|#[xX][0-9A-Fa-f]+);",
+            ProtectedPreset::Hashtag => r"#[\p{L}\p{N}_]+",
+            ProtectedPreset::Mention => r"@[\p{L}\p{N}_]+",
+            ProtectedPreset::Number => r"\p{N}+(?:[\.,:/]\p{N}+)+",
+        }
+    }
+
+    /// Compiled regex for this preset, shared by every tokenizer that uses it
+    pub fn regex(&self) -> &'static Regex {
+        static RE_URL: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(ProtectedPreset::Url.pattern()).unwrap());
+        static RE_EMAIL: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(ProtectedPreset::Email.pattern()).unwrap());
+        static RE_HTML_TAG: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(ProtectedPreset::HtmlTag.pattern()).unwrap());
+        static RE_HTML_ENTITY: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(ProtectedPreset::HtmlEntity.pattern()).unwrap());
+        static RE_HASHTAG: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(ProtectedPreset::Hashtag.pattern()).unwrap());
+        static RE_MENTION: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(ProtectedPreset::Mention.pattern()).unwrap());
+        static RE_NUMBER: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(ProtectedPreset::Number.pattern()).unwrap());
+        match self {
+            ProtectedPreset::Url => &RE_URL,
+            ProtectedPreset::Email => &RE_EMAIL,
+            ProtectedPreset::HtmlTag => &RE_HTML_TAG,
+            ProtectedPreset::HtmlEntity => &RE_HTML_ENTITY,
+            ProtectedPreset::Hashtag => &RE_HASHTAG,
+            ProtectedPreset::Mention => &RE_MENTION,
+            ProtectedPreset::Number => &RE_NUMBER,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{Language, MosesTokenizer};
+    use super::*;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn presets_compile() {
+        for preset in ProtectedPreset::iter() {
+            assert!(preset.regex().is_match(match preset {
+                ProtectedPreset::Url => "https://example.com/a-b?c=d",
+                ProtectedPreset::Email => "first.last@example.co.uk",
+                ProtectedPreset::HtmlTag => "<a href=\"x\">",
+                ProtectedPreset::HtmlEntity => "&#x27;",
+                ProtectedPreset::Hashtag => "#NLP",
+                ProtectedPreset::Mention => "@tokenaisu",
+                ProtectedPreset::Number => "12/05/2024",
+            }));
+        }
+    }
+
+    #[test]
+    fn presets_combined_with_custom_patterns() {
+        let tokenizer = MosesTokenizer::builder(Language::En)
+            .no_escaping(true)
+            .protected_presets(&[ProtectedPreset::Mention, ProtectedPreset::Email])
+            .protected_preset(ProtectedPreset::Url)
+            .protected_pattern(r"C\+\+")
+            .build()
+            .unwrap();
+        assert_eq!(
+            tokenizer.tokenize_line_string(
+                "Ask @tokenaisu (dev@example.com) about C++ or see https://example.com/a-b?c=d."
+            ),
+            "Ask @tokenaisu ( dev@example.com ) about C++ or see https://example.com/a-b?c=d .\n"
+        );
+    }
+
+    #[test]
+    fn presets_html_and_numbers() {
+        let tokenizer = MosesTokenizer::builder(Language::En)
+            .no_escaping(true)
+            .protected_presets(&[
+                ProtectedPreset::HtmlTag,
+                ProtectedPreset::HtmlEntity,
+                ProtectedPreset::Number,
+                ProtectedPreset::Hashtag,
+            ])
+            .build()
+            .unwrap();
+        assert_eq!(
+            tokenizer.tokenize_line_string("At 10:30 , <br/> Tom &amp; Jerry #cartoons"),
+            "At 10:30 , <br/> Tom &amp; Jerry #cartoons\n"
+        );
+    }
+}
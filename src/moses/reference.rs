@@ -0,0 +1,310 @@
+use super::{Language, MosesTokenizer, is_removed_control_char};
+use regex::Regex;
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+// The cascade of regular expressions the tokenizer was originally ported with, kept as the reference the single-pass
+// engine is tested against
+impl MosesTokenizer {
+    pub(super) fn tokenize_regex_raw(&self, text: &str) -> Vec<String> {
+        let language = &self.language;
+        let mut tokenized_text = text
+            // Remove trailing newline character
+            .trim_end_matches('\n')
+            // Replace all sequences of whitespaces with a single ASCII whitespace while trimming text
+            // This is done for any type of Unicode space (incl. tabs)
+            .split_whitespace()
+            .collect::<Vec<&str>>()
+            .join(" ");
+
+        // Add spaces at the beginning and end of the text
+        tokenized_text.insert(0, ' ');
+        tokenized_text.push(' ');
+
+        // Remove ASCII characters 0-31
+        tokenized_text = tokenized_text
+            .chars()
+            .filter(|&ch| !is_removed_control_char(ch))
+            .collect();
+
+        // Capture protected patterns and replace them with unique substitution strings
+        let mut found_protected_patterns: HashMap<String, String> = HashMap::new();
+        for re_pattern in &self.protected_patterns {
+            tokenized_text = re_pattern
+                .replace_all(&tokenized_text, |caps: &regex::Captures| {
+                    let substitution =
+                        format!("THISISPROTECTED{:03}", found_protected_patterns.len());
+                    found_protected_patterns.insert(substitution.clone(), caps[0].to_owned());
+                    substitution
+                })
+                .to_string();
+        }
+        // After substituting protected patterns, replace all sequences of whitespaces with a single whitespace and trim the text
+        tokenized_text = tokenized_text
+            .split_whitespace()
+            .collect::<Vec<&str>>()
+            .join(" ");
+
+        // Separate out all other special characters depending on the language
+        match language {
+            Language::Fi | Language::Sv => {
+                // In Finnish and Swedish, the colon can be used inside words as an apostrophe-like character:
+                // TODO (applies for all LazyLock regexes) this has some overhead when multithreading because of the read access, cloning the regexes for each thread is technically faster
+                // TODO chain regexes or use alternate intermediate results to pass around Cow<str> between replacing regexes
+                static RE_GENERAL: LazyLock<Regex> =
+                    LazyLock::new(|| Regex::new(r"([^\p{L}\p{N}\s\.\:\'\`\,\-])").unwrap());
+                tokenized_text = RE_GENERAL.replace_all(&tokenized_text, " $1 ").to_string();
+                // If a colon is not immediately followed by lower-case characters, separate it out anyway
+                static RE_COLON: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(:)").unwrap());
+                tokenized_text = replace_all_followed_by(&tokenized_text, &RE_COLON, " $1 ", |c| {
+                    !c.is_some_and(|c| c.is_lowercase())
+                });
+            }
+            Language::Tdt => {
+                // # In Tetun, the apostrophe can be used inside words as an apostrophe-like character:
+                static RE_GENERAL: LazyLock<Regex> =
+                    LazyLock::new(|| Regex::new(r"([^\p{L}\p{N}\s\.\'\`\,\-])").unwrap());
+                tokenized_text = RE_GENERAL.replace_all(&tokenized_text, " $1 ").to_string();
+                // If an apostrophe is not immediately followed by lower-case characters, separate it out anyway
+                static RE_APOSTROPHE: LazyLock<Regex> =
+                    LazyLock::new(|| Regex::new(r"(')").unwrap());
+                tokenized_text =
+                    replace_all_followed_by(&tokenized_text, &RE_APOSTROPHE, " $1 ", |c| {
+                        !c.is_some_and(|c| c.is_lowercase())
+                    });
+            }
+            Language::Ca => {
+                // In Catalan, the middle dot can be used inside words:
+                static RE_GENERAL: LazyLock<Regex> =
+                    LazyLock::new(|| Regex::new(r"([^\p{L}\p{N}\s\.\u{00B7}'\`\,\-])").unwrap());
+                tokenized_text = RE_GENERAL.replace_all(&tokenized_text, " $1 ").to_string();
+                // If a middot is not immediately followed by lower-case characters, separate it out anywa
+                static RE_MIDDOT: LazyLock<Regex> =
+                    LazyLock::new(|| Regex::new(r"(\u{00B7})").unwrap());
+                tokenized_text =
+                    replace_all_followed_by(&tokenized_text, &RE_MIDDOT, " $1 ", |c| {
+                        !c.is_some_and(|c| c.is_lowercase())
+                    });
+            }
+            _ => {
+                static RE_GENERAL: LazyLock<Regex> =
+                    LazyLock::new(|| Regex::new(r"([^\p{L}\p{N}\s\.\'\`\,\-])").unwrap());
+                tokenized_text = RE_GENERAL.replace_all(&tokenized_text, " $1 ").to_string();
+            }
+        }
+
+        // Optional aggressive hyphen splitting
+        if self.aggresive_hyphen_splitting {
+            static RE_AGGRESSIVE_HYPHEN_SPLITTING: LazyLock<Regex> =
+                LazyLock::new(|| Regex::new(r"([\p{L}\p{N}])-").unwrap());
+            tokenized_text = replace_all_followed_by(
+                &tokenized_text,
+                &RE_AGGRESSIVE_HYPHEN_SPLITTING,
+                "$1 @-@ ",
+                |c| c.is_some_and(|c| c.is_alphanumeric()),
+            );
+        }
+
+        // Multi-dot tagging
+        static RE_NEW_MULTI_DOT: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(r"\.([\.]+)").unwrap());
+        tokenized_text = RE_NEW_MULTI_DOT
+            .replace_all(&tokenized_text, " DOTMULTI$1")
+            .to_string();
+        static RE_DOTMULTI_LEFT: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(r"DOTMULTI\.").unwrap());
+        static RE_DOTMULTI_PLUS_NONDOT: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(r"DOTMULTI\.([^\.])").unwrap());
+        static RE_DOTMULTI_EXPAND: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(r"DOTMULTI\.").unwrap());
+        while RE_DOTMULTI_LEFT.is_match(&tokenized_text) {
+            // Replace DOTMULTI. followed by non-dot with DOTDOTMULTI plus that character
+            tokenized_text = RE_DOTMULTI_PLUS_NONDOT
+                .replace_all(&tokenized_text, "DOTDOTMULTI $1")
+                .to_string();
+            // Replace any remaining DOTMULTI. with DOTDOTMULTI
+            tokenized_text = RE_DOTMULTI_EXPAND
+                .replace_all(&tokenized_text, "DOTDOTMULTI")
+                .to_string();
+        }
+
+        // Separate out "," except if within numbers (5,300)
+        static RE_COMMA_AFTER_NON_NUMERIC: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(r"([^\p{N}]),").unwrap());
+        tokenized_text = RE_COMMA_AFTER_NON_NUMERIC
+            .replace_all(&tokenized_text, "$1 , ")
+            .to_string();
+        static RE_COMMA_BEFORE_NON_NUMERIC: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(r",([^\p{N}])").unwrap());
+        tokenized_text = RE_COMMA_BEFORE_NON_NUMERIC
+            .replace_all(&tokenized_text, " , $1")
+            .to_string();
+
+        // Separate "," after a number if it's the end of a sentence
+        static RE_COMMA_AFTER_NUMBER_END_OF_SENTENCE: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(r"([\p{N}]),$").unwrap());
+        tokenized_text = RE_COMMA_AFTER_NUMBER_END_OF_SENTENCE
+            .replace_all(&tokenized_text, "$1 ,")
+            .to_string();
+
+        // Split contractions
+        match language {
+            Language::En => {
+                // Split contractions right
+                // Non-alpha + apostrophe + non-alpha -> add spaces around apostrophe
+                static RE_SPACE_AROUND_APHOSTROPHE: LazyLock<Regex> =
+                    LazyLock::new(|| Regex::new(r"([^\p{L}])[']([^\p{L}])").unwrap());
+                tokenized_text = RE_SPACE_AROUND_APHOSTROPHE
+                    .replace_all(&tokenized_text, "$1 ' $2")
+                    .to_string();
+                // Non-alpha/non-numeric + apostrophe + alpha -> space before apostrophe
+                static RE_SPACE_BEFORE_APHOSTROPHE: LazyLock<Regex> =
+                    LazyLock::new(|| Regex::new(r"([^\p{L}\p{N}])[']([\p{L}])").unwrap());
+                tokenized_text = RE_SPACE_BEFORE_APHOSTROPHE
+                    .replace_all(&tokenized_text, "$1 ' $2")
+                    .to_string();
+                // Alpha + apostrophe + non-alpha -> space after apostrophe
+                static RE_SPACE_AFTER_APHOSTROPHE: LazyLock<Regex> =
+                    LazyLock::new(|| Regex::new(r"([\p{L}])[']([^\p{L}])").unwrap());
+                tokenized_text = RE_SPACE_AFTER_APHOSTROPHE
+                    .replace_all(&tokenized_text, "$1 ' $2")
+                    .to_string();
+                // Alpha + apostrophe + alpha -> space before apostrophe (e.g., "don't" -> "don ' t")
+                static RE_SPACE_BEFORE_APHOSTROPHE_ALPHA: LazyLock<Regex> =
+                    LazyLock::new(|| Regex::new(r"([\p{L}])[']([\p{L}])").unwrap());
+                tokenized_text = RE_SPACE_BEFORE_APHOSTROPHE_ALPHA
+                    .replace_all(&tokenized_text, "$1 '$2")
+                    .to_string();
+
+                // Special case for "1990's" - numeric + apostrophe + 's'
+                static RE_NUMERIC_APOSTROPHE: LazyLock<Regex> =
+                    LazyLock::new(|| Regex::new(r"([\p{N}])[']([s])").unwrap());
+                tokenized_text = RE_NUMERIC_APOSTROPHE
+                    .replace_all(&tokenized_text, "$1 '$2")
+                    .to_string();
+            }
+            Language::Fr | Language::It | Language::Ga | Language::Ca => {
+                // Split contractions left
+                // Non-alpha + apostrophe + non-alpha -> add spaces around apostrophe
+                static RE_SPACE_AROUND_APOSTROPHE: LazyLock<Regex> =
+                    LazyLock::new(|| Regex::new(r"([^\p{L}])[']([^\p{L}])").unwrap());
+                tokenized_text = RE_SPACE_AROUND_APOSTROPHE
+                    .replace_all(&tokenized_text, "$1 ' $2")
+                    .to_string();
+                // Non-alpha + apostrophe + alpha -> space before apostrophe
+                static RE_SPACE_BEFORE_APOSTROPHE: LazyLock<Regex> =
+                    LazyLock::new(|| Regex::new(r"([^\p{L}])[']([\p{L}])").unwrap());
+                tokenized_text = RE_SPACE_BEFORE_APOSTROPHE
+                    .replace_all(&tokenized_text, "$1 ' $2")
+                    .to_string();
+                // Alpha + apostrophe + non-alpha -> space after apostrophe
+                static RE_SPACE_AFTER_APOSTROPHE: LazyLock<Regex> =
+                    LazyLock::new(|| Regex::new(r"([\p{L}])[']([^\p{L}])").unwrap());
+                tokenized_text = RE_SPACE_AFTER_APOSTROPHE
+                    .replace_all(&tokenized_text, "$1 ' $2")
+                    .to_string();
+                // Alpha + apostrophe + alpha -> space after apostrophe (e.g., "l'eau" -> "l' eau")
+                static RE_SPACE_BEFORE_APHOSTROPHE_ALPHA: LazyLock<Regex> =
+                    LazyLock::new(|| Regex::new(r"([\p{L}])[']([\p{L}])").unwrap());
+                tokenized_text = RE_SPACE_BEFORE_APHOSTROPHE_ALPHA
+                    .replace_all(&tokenized_text, "$1' $2")
+                    .to_string();
+            }
+            Language::So | Language::Tdt => {
+                // Don't split glottals (no alpha + apostrophe + alpha rule)
+                // Non-alpha + apostrophe + non-alpha -> add spaces around apostrophe
+                static RE_SPACE_AROUND_APOSTROPHE: LazyLock<Regex> =
+                    LazyLock::new(|| Regex::new(r"([^\p{L}])[']([^\p{L}])").unwrap());
+                tokenized_text = RE_SPACE_AROUND_APOSTROPHE
+                    .replace_all(&tokenized_text, "$1 ' $2")
+                    .to_string();
+                // Non-alpha + apostrophe + alpha -> space before apostrophe
+                static RE_SPACE_BEFORE_APOSTROPHE: LazyLock<Regex> =
+                    LazyLock::new(|| Regex::new(r"([^\p{L}])[']([\p{L}])").unwrap());
+                tokenized_text = RE_SPACE_BEFORE_APOSTROPHE
+                    .replace_all(&tokenized_text, "$1 ' $2")
+                    .to_string();
+                // Alpha + apostrophe + non-alpha -> space after apostrophe
+                static RE_SPACE_AFER_APOSTROPHE: LazyLock<Regex> =
+                    LazyLock::new(|| Regex::new(r"([\p{L}])[']([^\p{L}])").unwrap());
+                tokenized_text = RE_SPACE_AFER_APOSTROPHE
+                    .replace_all(&tokenized_text, "$1 ' $2")
+                    .to_string();
+            }
+            _ => {
+                // Default: add spaces around all apostrophes
+                static RE_APOSTROPHE_SPACE: LazyLock<Regex> =
+                    LazyLock::new(|| Regex::new(r"'").unwrap());
+                tokenized_text = RE_APOSTROPHE_SPACE
+                    .replace_all(&tokenized_text, " ' ")
+                    .to_string();
+            }
+        }
+
+        // Word tokenization
+        tokenized_text = self.split_periods(&tokenized_text, true);
+
+        // Clean up extraneous spaces
+        tokenized_text = tokenized_text
+            .split_ascii_whitespace()
+            .collect::<Vec<&str>>()
+            .join(" ");
+
+        // .' at end of sentence is missed
+        static RE_PERIOD: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\.\' ?$").unwrap());
+        tokenized_text = RE_PERIOD.replace(&tokenized_text, ". ' ").to_string();
+
+        // Restore protected patterns and multi-dots token by token
+        tokenized_text
+            .split_ascii_whitespace()
+            .map(|token| {
+                let mut token = token.to_string();
+                for (substitution, pattern) in &found_protected_patterns {
+                    if token.contains(substitution.as_str()) {
+                        token = token.replace(substitution, pattern);
+                    }
+                }
+                while token.contains("DOTDOTMULTI") {
+                    token = token.replace("DOTDOTMULTI", "DOTMULTI.");
+                }
+                token.replace("DOTMULTI", ".")
+            })
+            .collect()
+    }
+}
+
+// Raw tokens of the single-pass engine and of the regular expression cascade, which the benchmarks compare
+#[cfg(feature = "bench-reference")]
+impl MosesTokenizer {
+    #[doc(hidden)]
+    pub fn bench_tokenize_engine(&self, text: &str) -> Vec<String> {
+        self.tokenize_moses_raw(text)
+    }
+
+    #[doc(hidden)]
+    pub fn bench_tokenize_regex_cascade(&self, text: &str) -> Vec<String> {
+        self.tokenize_regex_raw(text)
+    }
+}
+
+// The regex crate does not support look-around, so patterns such as `(:)(?=$|[^\p{Ll}])` from the original
+// Moses tokenizer are emulated by checking the character that follows each match before replacing it
+fn replace_all_followed_by(
+    text: &str,
+    re: &Regex,
+    replacement: &str,
+    lookahead: impl Fn(Option<char>) -> bool,
+) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut last_match_end = 0;
+    for caps in re.captures_iter(text) {
+        let m = caps.get(0).unwrap();
+        if lookahead(text[m.end()..].chars().next()) {
+            result.push_str(&text[last_match_end..m.start()]);
+            caps.expand(replacement, &mut result);
+            last_match_end = m.end();
+        }
+    }
+    result.push_str(&text[last_match_end..]);
+    result
+}
@@ -0,0 +1,461 @@
+use super::Language;
+use super::{NonbreakingPrefixes, PrefixType};
+use crate::error::{Error, Result};
+use crate::stream::{STREAMING_CHUNK_LINES, io_error, read_line};
+use rayon::prelude::*;
+use regex::Regex;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::sync::{LazyLock, OnceLock};
+
+// Port of https://github.com/moses-smt/mosesdecoder/blob/master/scripts/ems/support/split-sentences.perl
+
+// Characters that can start a sentence on top of upper case letters and digits, which are the Unicode blocks of
+// the Indic scripts that have no case
+const SENTENCE_STARTS: [&str; 10] = [
+    "",
+    r"\x{0900}-\x{097F}\x{A8E0}-\x{A8FF}", // Devanagari
+    r"\x{0A80}-\x{0AFF}",                  // Gujarati
+    r"\x{0980}-\x{09FF}",                  // Bengali
+    r"\x{0C80}-\x{0CFF}",                  // Kannada
+    r"\x{0D00}-\x{0D7F}",                  // Malayalam
+    r"\x{0B00}-\x{0B7F}",                  // Oriya
+    r"\x{0A00}-\x{0A7F}",                  // Gurmukhi
+    r"\x{0B80}-\x{0BFF}",                  // Tamil
+    r"\x{0C00}-\x{0C7F}",                  // Telugu
+];
+
+fn sentence_start_index(language: &Language) -> usize {
+    match language {
+        Language::Hi | Language::Mr => 1,
+        Language::Gu => 2,
+        Language::As | Language::Bn | Language::Mni => 3,
+        Language::Kn => 4,
+        Language::Ml => 5,
+        Language::Or => 6,
+        Language::Pa => 7,
+        Language::Ta => 8,
+        Language::Te => 9,
+        _ => 0,
+    }
+}
+
+// Rules that depend on the characters that can start a sentence
+struct SplitRules {
+    punctuation: Regex,
+    multi_dots: Regex,
+    punctuation_inside_quote: Regex,
+    punctuation_before_quote: Regex,
+    next_word: Regex,
+}
+
+impl SplitRules {
+    fn new(sentence_start: &str) -> Self {
+        let start = format!(r"[\p{{Uppercase}}0-9{sentence_start}]");
+        let regex = |pattern: String| Regex::new(&pattern).unwrap();
+        SplitRules {
+            // Non-period end of sentence markers (?!) followed by sentence starters
+            punctuation: regex(format!(r#"([?!\x{{0964}}]) +(['"\(\[¿¡\p{{Pi}}]*{start})"#)),
+            // Multi-dots followed by sentence starters
+            multi_dots: regex(format!(r#"(\.[\.]+) +(['"\(\[¿¡\p{{Pi}}]*{start})"#)),
+            // Sentences that end with some sort of punctuation inside a quote or parenthetical and are followed by
+            // a possible sentence starter punctuation and upper case
+            punctuation_inside_quote: regex(format!(
+                r#"([?!\.\x{{0964}}] *[\x{{300d}}\x{{300f}}'"\)\]\p{{Pf}}]+) +(['"\(\[¿¡\p{{Pi}}]* *{start})"#
+            )),
+            // Sentences that end with some sort of punctuation and are followed by a sentence starter punctuation
+            // and upper case
+            punctuation_before_quote: regex(format!(
+                r#"([?!\.\x{{0964}}]) +([\x{{300d}}\x{{300f}}'"\(\[¿¡\p{{Pi}}]+ *{start})"#
+            )),
+            // A word with a bunch of initial quotes, maybe a space, then either upper case or a number
+            next_word: regex(format!(
+                r#"^( *['"\(\[¿¡\p{{Pi}}]* *[0-9{sentence_start}\p{{Uppercase}}])"#
+            )),
+        }
+    }
+
+    fn get(language: &Language) -> &'static SplitRules {
+        static RULES: [OnceLock<SplitRules>; SENTENCE_STARTS.len()] =
+            [const { OnceLock::new() }; SENTENCE_STARTS.len()];
+        let index = sentence_start_index(language);
+        RULES[index].get_or_init(|| SplitRules::new(SENTENCE_STARTS[index]))
+    }
+}
+
+/// Moses-like sentence splitter, which can be configured once with its nonbreaking prefixes and then reused (and
+/// shared across threads)
+#[derive(Debug, Clone)]
+pub struct SentenceSplitter {
+    language: Language,
+    nonbreaking_prefixes: NonbreakingPrefixes,
+}
+
+impl SentenceSplitter {
+    /// Splitter with the built-in nonbreaking prefixes of the language. Like the original script, the English
+    /// prefixes are used for languages without their own, except for the generic language, which is meant to make
+    /// no language-specific assumptions.
+    pub fn new(language: Language) -> Self {
+        let mut nonbreaking_prefixes = NonbreakingPrefixes::builtin(&language);
+        if nonbreaking_prefixes.is_empty() && language != Language::Generic {
+            nonbreaking_prefixes = NonbreakingPrefixes::builtin(&Language::En);
+        }
+        SentenceSplitter {
+            language,
+            nonbreaking_prefixes,
+        }
+    }
+
+    pub fn language(&self) -> &Language {
+        &self.language
+    }
+
+    /// Replaces the built-in nonbreaking prefixes of the language
+    pub fn nonbreaking_prefixes(mut self, nonbreaking_prefixes: NonbreakingPrefixes) -> Self {
+        self.nonbreaking_prefixes = nonbreaking_prefixes;
+        self
+    }
+
+    /// Adds nonbreaking prefixes on top of the current ones
+    pub fn extra_nonbreaking_prefixes(
+        mut self,
+        nonbreaking_prefixes: &NonbreakingPrefixes,
+    ) -> Self {
+        self.nonbreaking_prefixes.merge(nonbreaking_prefixes);
+        self
+    }
+
+    pub fn split_file(
+        &self,
+        input_file_path: &str,
+        output_file_path: &str,
+        paragraph_markers: bool,
+    ) -> Result<()> {
+        let reader =
+            BufReader::new(File::open(input_file_path).map_err(|e| Error::io(input_file_path, e))?);
+        let writer = BufWriter::new(
+            File::create(output_file_path).map_err(|e| Error::io(output_file_path, e))?,
+        );
+        self.split_paragraphs(
+            reader,
+            writer,
+            Some(input_file_path),
+            Some(output_file_path),
+            paragraph_markers,
+        )
+    }
+
+    /// Splits the paragraphs read from `reader` into one sentence per line. Paragraphs are separated by blank
+    /// lines, which are written as `<P>` lines when `paragraph_markers` is set, or by lines consisting of an
+    /// XML/SGML tag, which are passed through unchanged.
+    pub fn split_reader(
+        &self,
+        reader: impl BufRead,
+        writer: impl Write,
+        paragraph_markers: bool,
+    ) -> Result<()> {
+        self.split_paragraphs(reader, writer, None, None, paragraph_markers)
+    }
+
+    /// Splits a paragraph into sentences. The lines of the paragraph, if there are several, are joined with spaces.
+    pub fn split(&self, paragraph: &str) -> Vec<String> {
+        let paragraph = paragraph.lines().collect::<Vec<_>>().join(" ");
+        if paragraph.trim().is_empty() {
+            return Vec::new();
+        }
+        self.split_paragraph(&paragraph)
+            .lines()
+            .map(str::to_owned)
+            .collect()
+    }
+
+    // Reads paragraphs in chunks and splits them in parallel, like `map_lines_parallel` does with lines
+    fn split_paragraphs(
+        &self,
+        mut reader: impl BufRead,
+        mut writer: impl Write,
+        input_path: Option<&str>,
+        output_path: Option<&str>,
+        paragraph_markers: bool,
+    ) -> Result<()> {
+        static RE_XML_LINE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^<.+>$").unwrap());
+        let mut offset = 0;
+        let mut finished = false;
+        // Lines of the current paragraph, which may span several chunks
+        let mut paragraph = String::new();
+        while !finished {
+            // Paragraphs along with the line that closes them (a tag or a paragraph marker)
+            let mut chunk: Vec<(String, String)> = Vec::new();
+            let mut chunk_lines = 0;
+            while chunk_lines < STREAMING_CHUNK_LINES {
+                let Some(line) = read_line(&mut reader, &mut offset, input_path)? else {
+                    finished = true;
+                    break;
+                };
+                chunk_lines += 1;
+                if RE_XML_LINE.is_match(&line) {
+                    chunk.push((std::mem::take(&mut paragraph), format!("{line}\n")));
+                } else if line.trim().is_empty() {
+                    let marker = if paragraph_markers && !paragraph.is_empty() {
+                        "<P>\n"
+                    } else {
+                        ""
+                    };
+                    chunk.push((std::mem::take(&mut paragraph), marker.to_owned()));
+                } else {
+                    paragraph.push_str(&line);
+                    paragraph.push(' ');
+                }
+            }
+            // The leftover text is split at the end of the input
+            if finished && !paragraph.is_empty() {
+                chunk.push((std::mem::take(&mut paragraph), String::new()));
+            }
+            let split_chunk: Vec<String> = chunk
+                .par_iter()
+                .map(|(paragraph, closing_line)| {
+                    let mut output = if paragraph.is_empty() {
+                        String::new()
+                    } else {
+                        self.split_paragraph(paragraph)
+                    };
+                    output.push_str(closing_line);
+                    output
+                })
+                .collect();
+            for output in split_chunk {
+                writer
+                    .write_all(output.as_bytes())
+                    .map_err(io_error(output_path))?;
+            }
+        }
+        writer.flush().map_err(io_error(output_path))
+    }
+
+    // Returns the sentences of the paragraph separated by (and ending with) newlines
+    fn split_paragraph(&self, text: &str) -> String {
+        let rules = SplitRules::get(&self.language);
+        let mut text = clean_spaces(text);
+
+        text = rules.punctuation.replace_all(&text, "$1\n$2").into_owned();
+        text = rules.multi_dots.replace_all(&text, "$1\n$2").into_owned();
+        text = rules
+            .punctuation_inside_quote
+            .replace_all(&text, "$1\n$2")
+            .into_owned();
+        text = rules
+            .punctuation_before_quote
+            .replace_all(&text, "$1\n$2")
+            .into_owned();
+
+        if matches!(self.language, Language::Yue | Language::Zh) {
+            // Chinese full stops are not followed by whitespace, nor is there any idea of capitalization
+            static RE_CJK_FULL_STOP: LazyLock<Regex> = LazyLock::new(|| {
+                Regex::new(r#"([\x{3002}\x{ff0e}\x{ff1f}\x{ff01}]+\s*["\x{201d}\x{201e}\x{300d}\x{300f}]?\s*)"#)
+                    .unwrap()
+            });
+            // A Western sentence ender followed by an ideograph always ends a sentence
+            static RE_WESTERN_FULL_STOP: LazyLock<Regex> =
+                LazyLock::new(|| Regex::new(r"([\.?!]) *(\p{Han})").unwrap());
+            // Split close-paren-then-comma into two
+            static RE_PUNCTUATION_PAIR: LazyLock<Regex> =
+                LazyLock::new(|| Regex::new(r"(\p{P}) *(\p{P})").unwrap());
+            text = RE_CJK_FULL_STOP.replace_all(&text, "$1\n").into_owned();
+            text = RE_WESTERN_FULL_STOP
+                .replace_all(&text, "$1\n$2")
+                .into_owned();
+            text = RE_PUNCTUATION_PAIR
+                .replace_all(&text, " $1 $2 ")
+                .into_owned();
+        }
+
+        // Special punctuation cases are covered, check all remaining periods
+        static RE_PERIOD: LazyLock<Regex> = LazyLock::new(|| {
+            Regex::new(r#"([\p{Alphabetic}\p{Nd}\.\-]*)(['"\)\]%\p{Pf}]*)(\.+)$"#).unwrap()
+        });
+        // Upper case acronyms such as U.S.A.
+        static RE_ACRONYM: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(r"(\.)[\p{Uppercase}\-]+(\.+)$").unwrap());
+        static RE_NUMBER_START: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[0-9]+").unwrap());
+        let words: Vec<&str> = text.split(' ').collect();
+        let mut split_text = String::with_capacity(text.len() + words.len());
+        for (i, word) in words.iter().enumerate() {
+            split_text.push_str(word);
+            if i == words.len() - 1 {
+                break;
+            }
+            if let Some(caps) = RE_PERIOD.captures(word) {
+                let prefix = &caps[1];
+                let starting_punct = &caps[2];
+                let prefix_type = self
+                    .nonbreaking_prefixes
+                    .get(prefix)
+                    .filter(|_| !prefix.is_empty() && starting_punct.is_empty());
+                if prefix_type == Some(PrefixType::Always) || RE_ACRONYM.is_match(word) {
+                    // Not breaking
+                } else if rules.next_word.is_match(words[i + 1])
+                    && !(prefix_type == Some(PrefixType::NumericOnly)
+                        && RE_NUMBER_START.is_match(words[i + 1]))
+                {
+                    // Always break unless a numeric-only prefix is followed by a number
+                    split_text.push('\n');
+                }
+            }
+            split_text.push(' ');
+        }
+
+        let mut split_text = clean_spaces(&split_text);
+        if !split_text.ends_with('\n') {
+            split_text.push('\n');
+        }
+        split_text
+    }
+}
+
+pub fn split_sentences_file(
+    input_file_path: &str,
+    output_file_path: &str,
+    language: Language,
+    paragraph_markers: bool,
+) -> Result<()> {
+    SentenceSplitter::new(language).split_file(input_file_path, output_file_path, paragraph_markers)
+}
+
+pub fn split_sentences_reader(
+    reader: impl BufRead,
+    writer: impl Write,
+    language: Language,
+    paragraph_markers: bool,
+) -> Result<()> {
+    SentenceSplitter::new(language).split_reader(reader, writer, paragraph_markers)
+}
+
+pub fn split_sentences(paragraph: &str, language: Language) -> Vec<String> {
+    SentenceSplitter::new(language).split(paragraph)
+}
+
+// Cleans up spaces at the head and tail of each line, as well as any double-spacing
+fn clean_spaces(text: &str) -> String {
+    static RE_SPACES: LazyLock<Regex> = LazyLock::new(|| Regex::new(r" +").unwrap());
+    let text = RE_SPACES.replace_all(text, " ");
+    let text = text.replace("\n ", "\n").replace(" \n", "\n");
+    let text = text.strip_prefix(' ').unwrap_or(&text);
+    text.strip_suffix(' ').unwrap_or(text).to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_on_punctuation() {
+        assert_eq!(
+            split_sentences(
+                "Hello world. How are you? I'm fine! \"Great.\" (Really.) Bye... See you.",
+                Language::En
+            ),
+            vec![
+                "Hello world.",
+                "How are you?",
+                "I'm fine!",
+                "\"Great.\"",
+                "(Really.)",
+                "Bye...",
+                "See you."
+            ]
+        );
+    }
+
+    #[test]
+    fn split_nonbreaking_prefixes() {
+        assert_eq!(
+            split_sentences(
+                "Mr. Smith met Dr. Jones in the U.S.A. Today. It was No. 5 on the list. See pp. 12 and No. Else.",
+                Language::En
+            ),
+            vec![
+                "Mr. Smith met Dr. Jones in the U.S.A. Today.",
+                "It was No. 5 on the list.",
+                "See pp. 12 and No.",
+                "Else."
+            ]
+        );
+        // Lower case words never start a sentence
+        assert_eq!(
+            split_sentences("This is e.g. fine. and more", Language::En),
+            vec!["This is e.g. fine. and more"]
+        );
+    }
+
+    #[test]
+    fn split_fallback_prefixes_and_indic_starts() {
+        // Somali has no prefixes file, so English prefixes are used
+        assert_eq!(
+            split_sentences("Waa Mr. Cali. Waa nin.", Language::So),
+            vec!["Waa Mr. Cali.", "Waa nin."]
+        );
+        assert_eq!(
+            split_sentences("यह एक वाक्य है। यह दूसरा है।", Language::Hi),
+            vec!["यह एक वाक्य है।", "यह दूसरा है।"]
+        );
+    }
+
+    #[test]
+    fn split_custom_prefixes() {
+        let text = "See Fig. 3 for details. It is approx. Ten metres long.";
+        assert_eq!(
+            split_sentences(text, Language::En),
+            vec![
+                "See Fig.",
+                "3 for details.",
+                "It is approx.",
+                "Ten metres long."
+            ]
+        );
+        let prefixes = NonbreakingPrefixes::parse("approx\nFig #NUMERIC_ONLY#\n");
+        let splitter = SentenceSplitter::new(Language::En).extra_nonbreaking_prefixes(&prefixes);
+        assert_eq!(
+            splitter.split(text),
+            vec!["See Fig. 3 for details.", "It is approx. Ten metres long."]
+        );
+        let splitter = SentenceSplitter::new(Language::En).nonbreaking_prefixes(prefixes);
+        assert_eq!(
+            splitter.split("Dr. Smith is approx. Ten."),
+            vec!["Dr.", "Smith is approx. Ten."]
+        );
+    }
+
+    #[test]
+    fn split_generic_language() {
+        assert_eq!(
+            split_sentences("Ask Dr. Smith. He knows.", Language::Generic),
+            vec!["Ask Dr.", "Smith.", "He knows."]
+        );
+    }
+
+    #[test]
+    fn split_cjk_full_stops() {
+        assert_eq!(
+            split_sentences("我喜欢读书。你呢？我也是！Ok.好的", Language::Zh),
+            vec!["我喜欢读书。", "你呢？", "我也是！", "Ok.", "好的"]
+        );
+    }
+
+    #[test]
+    fn split_paragraphs_with_markers() {
+        let input = "First sentence. Second\nsentence.\n\n<doc id=\"1\">\nThird one. Fourth one.\n";
+        let mut output = Vec::new();
+        split_sentences_reader(input.as_bytes(), &mut output, Language::En, true).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "First sentence.\nSecond sentence.\n<P>\n<doc id=\"1\">\nThird one.\nFourth one.\n"
+        );
+
+        let mut output = Vec::new();
+        split_sentences_reader(input.as_bytes(), &mut output, Language::En, false).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "First sentence.\nSecond sentence.\n<doc id=\"1\">\nThird one.\nFourth one.\n"
+        );
+    }
+}
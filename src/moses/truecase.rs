@@ -0,0 +1,484 @@
+use crate::error::{Error, Result};
+use crate::stream::{fold_lines_parallel, map_lines_parallel, read_line};
+use rayon::prelude::*;
+use regex::Regex;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::sync::LazyLock;
+
+// Ports of https://github.com/moses-smt/mosesdecoder/blob/master/scripts/recaser/train-truecaser.perl,
+// https://github.com/moses-smt/mosesdecoder/blob/master/scripts/recaser/truecase.perl and
+// https://github.com/moses-smt/mosesdecoder/blob/master/scripts/recaser/detruecase.perl
+
+// Words after which a new sentence starts
+const SENTENCE_END: [&str; 4] = [".", ":", "?", "!"];
+
+// Words that can precede the first word of a sentence without being its first word themselves
+const DELAYED_SENTENCE_START: [&str; 8] =
+    ["(", "[", "\"", "'", "&apos;", "&quot;", "&#91;", "&#93;"];
+
+/// Truecasing model, which stores how often every casing of a word has been seen in the middle of a sentence
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TruecaserModel {
+    // Casings of every lowercased word along with their frequencies, the most frequent one first
+    casings: HashMap<String, Vec<(String, f64)>>,
+}
+
+impl TruecaserModel {
+    /// Trains a model from tokenized text. With `possibly_use_first_token`, the first word of a sentence is also
+    /// counted when it is not capitalized, or (with a small weight) when it is the only word of its line.
+    pub fn train(text: &str, possibly_use_first_token: bool) -> Self {
+        let counts = text
+            .par_lines()
+            .fold(HashMap::new, |mut counts, line| {
+                count_casings(line, possibly_use_first_token, &mut counts);
+                counts
+            })
+            .reduce(HashMap::new, merge_counts);
+        Self::from_counts(counts)
+    }
+
+    pub fn train_reader(reader: impl BufRead, possibly_use_first_token: bool) -> Result<Self> {
+        let counts = fold_lines_parallel(
+            reader,
+            |counts, line| count_casings(line, possibly_use_first_token, counts),
+            merge_counts,
+        )?;
+        Ok(Self::from_counts(counts))
+    }
+
+    pub fn train_file(corpus_file_path: &str, possibly_use_first_token: bool) -> Result<Self> {
+        let reader = BufReader::new(
+            File::open(corpus_file_path).map_err(|e| Error::io(corpus_file_path, e))?,
+        );
+        Self::train_reader(reader, possibly_use_first_token)
+            .map_err(|e| e.with_path(corpus_file_path))
+    }
+
+    /// Loads a model in the text format written by `train-truecaser.perl`, e.g. `Paris (12/13) paris (1)`
+    pub fn load(model_file_path: &str) -> Result<Self> {
+        let reader =
+            BufReader::new(File::open(model_file_path).map_err(|e| Error::io(model_file_path, e))?);
+        Self::read(reader).map_err(|e| e.with_path(model_file_path))
+    }
+
+    pub fn read(mut reader: impl BufRead) -> Result<Self> {
+        let mut casings = HashMap::new();
+        let mut offset = 0;
+        let mut line_number = 0;
+        while let Some(line) = read_line(&mut reader, &mut offset, None)? {
+            line_number += 1;
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.is_empty() {
+                continue;
+            }
+            let invalid_line = || Error::InvalidModel {
+                path: None,
+                message: format!("line {line_number}: `{line}`"),
+            };
+            // The best casing comes first along with its count and the total count of the word
+            let best_count = fields
+                .get(1)
+                .and_then(|field| field.strip_prefix('('))
+                .and_then(|field| field.split_once('/'))
+                .and_then(|(count, _)| count.parse::<f64>().ok())
+                .ok_or_else(invalid_line)?;
+            let mut word_casings = vec![(fields[0].to_owned(), best_count)];
+            for pair in fields[2..].chunks(2) {
+                let count = pair
+                    .get(1)
+                    .and_then(|field| field.strip_prefix('('))
+                    .and_then(|field| field.strip_suffix(')'))
+                    .and_then(|count| count.parse::<f64>().ok())
+                    .ok_or_else(invalid_line)?;
+                word_casings.push((pair[0].to_owned(), count));
+            }
+            casings.insert(fields[0].to_lowercase(), word_casings);
+        }
+        Ok(TruecaserModel { casings })
+    }
+
+    pub fn save(&self, model_file_path: &str) -> Result<()> {
+        let writer = BufWriter::new(
+            File::create(model_file_path).map_err(|e| Error::io(model_file_path, e))?,
+        );
+        self.write(writer).map_err(|e| e.with_path(model_file_path))
+    }
+
+    /// Writes the model in the text format of `train-truecaser.perl`, sorted by word so that it is reproducible
+    pub fn write(&self, mut writer: impl Write) -> Result<()> {
+        let mut words: Vec<&String> = self.casings.keys().collect();
+        words.sort();
+        for word in words {
+            let word_casings = &self.casings[word];
+            let total: f64 = word_casings.iter().map(|(_, count)| count).sum();
+            let (best, best_count) = &word_casings[0];
+            write!(writer, "{best} ({best_count}/{total})")?;
+            for (casing, count) in &word_casings[1..] {
+                write!(writer, " {casing} ({count})")?;
+            }
+            writeln!(writer)?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+
+    // Most frequent casing of a word
+    fn best(&self, word: &str) -> Option<&str> {
+        self.casings
+            .get(&word.to_lowercase())
+            .map(|word_casings| word_casings[0].0.as_str())
+    }
+
+    // Whether this exact casing of the word has been seen
+    fn is_known(&self, word: &str) -> bool {
+        self.casings
+            .get(&word.to_lowercase())
+            .is_some_and(|word_casings| word_casings.iter().any(|(casing, _)| casing == word))
+    }
+
+    fn from_counts(counts: HashMap<String, HashMap<String, f64>>) -> Self {
+        let casings = counts
+            .into_iter()
+            .map(|(word, word_counts)| {
+                let mut word_casings: Vec<(String, f64)> = word_counts.into_iter().collect();
+                // Ties are broken alphabetically so that the model does not depend on the hashing order
+                word_casings.sort_by(|(a, a_count), (b, b_count)| {
+                    b_count.total_cmp(a_count).then_with(|| a.cmp(b))
+                });
+                (word, word_casings)
+            })
+            .collect();
+        TruecaserModel { casings }
+    }
+}
+
+// Counts the casings of the words of a line that are not at the start of a sentence
+fn count_casings(
+    line: &str,
+    possibly_use_first_token: bool,
+    counts: &mut HashMap<String, HashMap<String, f64>>,
+) {
+    let words: Vec<&str> = split_xml(line)
+        .into_iter()
+        .filter_map(|piece| match piece {
+            XmlPiece::Word(word) => Some(word),
+            XmlPiece::Markup(_) => None,
+        })
+        .collect();
+    let start = words
+        .iter()
+        .position(|word| !DELAYED_SENTENCE_START.contains(word))
+        .unwrap_or(words.len());
+    let mut first_word_of_sentence = true;
+    for i in start..words.len() {
+        let word = words[i];
+        if !first_word_of_sentence && SENTENCE_END.contains(&words[i - 1]) {
+            first_word_of_sentence = true;
+        }
+        if DELAYED_SENTENCE_START.contains(&word) {
+            continue;
+        }
+        let weight = if !first_word_of_sentence {
+            1.0
+        } else if possibly_use_first_token {
+            // Sentence-initial words that are not capitalized are in their natural case anyway, and so are
+            // capitalized words alone in their line, which are presumably not sentences
+            if !word.chars().next().is_some_and(char::is_uppercase) {
+                1.0
+            } else if words.len() == 1 {
+                0.1
+            } else {
+                0.0
+            }
+        } else {
+            0.0
+        };
+        if weight > 0.0 {
+            *counts
+                .entry(word.to_lowercase())
+                .or_default()
+                .entry(word.to_owned())
+                .or_default() += weight;
+        }
+        first_word_of_sentence = false;
+    }
+}
+
+fn merge_counts(
+    mut counts: HashMap<String, HashMap<String, f64>>,
+    other_counts: HashMap<String, HashMap<String, f64>>,
+) -> HashMap<String, HashMap<String, f64>> {
+    for (word, word_counts) in other_counts {
+        let entry = counts.entry(word).or_default();
+        for (casing, count) in word_counts {
+            *entry.entry(casing).or_default() += count;
+        }
+    }
+    counts
+}
+
+// Piece of a line, which is either a word or an XML tag
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum XmlPiece<'a> {
+    Word(&'a str),
+    Markup(&'a str),
+}
+
+// Splits a line into words and XML tags like `split_xml` of the truecasing scripts, so that a tag is a single piece
+// even when its attributes contain whitespace, e.g. `<seg id="1">`
+fn split_xml(line: &str) -> Vec<XmlPiece<'_>> {
+    static RE_XML_TAG: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^<\S[^>]*>").unwrap());
+    static RE_WORD: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[^\s<>]+").unwrap());
+    static RE_NON_WHITESPACE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\S+").unwrap());
+    let mut pieces = Vec::new();
+    let mut end = 0;
+    loop {
+        let start = line.len() - line[end..].trim_start().len();
+        if start == line.len() {
+            break;
+        }
+        let rest = &line[start..];
+        if let Some(tag) = RE_XML_TAG.find(rest) {
+            let tag_end = start + tag.end();
+            match pieces.last_mut() {
+                // A tag right after a word ending with a factor separator is a factor of that word, e.g. `word|<b>`
+                Some(XmlPiece::Word(word)) if start == end && word.ends_with('|') => {
+                    let word_start = start - word.len();
+                    end = line.len() - line[tag_end..].trim_start_matches('|').len();
+                    *word = &line[word_start..end];
+                }
+                _ => {
+                    end = tag_end;
+                    pieces.push(XmlPiece::Markup(&line[start..end]));
+                }
+            }
+        } else {
+            // Words stop at the start of a tag, unless `<` or `>` starts them without starting a tag
+            let word = RE_WORD
+                .find(rest)
+                .or_else(|| RE_NON_WHITESPACE.find(rest))
+                .unwrap();
+            end = start + word.end();
+            pieces.push(XmlPiece::Word(&line[start..end]));
+        }
+    }
+    pieces
+}
+
+pub fn truecase_file(
+    input_file_path: &str,
+    output_file_path: &str,
+    model: &TruecaserModel,
+) -> Result<()> {
+    let reader =
+        BufReader::new(File::open(input_file_path).map_err(|e| Error::io(input_file_path, e))?);
+    let writer =
+        BufWriter::new(File::create(output_file_path).map_err(|e| Error::io(output_file_path, e))?);
+    map_lines_parallel(
+        reader,
+        writer,
+        Some(input_file_path),
+        Some(output_file_path),
+        |line| truecase_line(line, model),
+    )
+}
+
+pub fn truecase_reader(
+    reader: impl BufRead,
+    writer: impl Write,
+    model: &TruecaserModel,
+) -> Result<()> {
+    map_lines_parallel(reader, writer, None, None, |line| {
+        truecase_line(line, model)
+    })
+}
+
+/// Restores the most frequent casing of the words at the start of a sentence and of unknown casings of known
+/// words, leaving the rest untouched. Factors (`word|factor`) are kept as they are.
+pub fn truecase_line(text: &str, model: &TruecaserModel) -> String {
+    let mut truecased_words = Vec::new();
+    let mut sentence_start = true;
+    for piece in split_xml(text) {
+        let token = match piece {
+            XmlPiece::Word(token) => token,
+            XmlPiece::Markup(tag) => {
+                truecased_words.push(tag.to_owned());
+                continue;
+            }
+        };
+        let (word, other_factors) = match token.find('|') {
+            Some(index) if index > 0 => token.split_at(index),
+            _ => (token, ""),
+        };
+        let truecased_word = match model.best(word) {
+            Some(best) if sentence_start || !model.is_known(word) => best,
+            _ => word,
+        };
+        truecased_words.push(format!("{truecased_word}{other_factors}"));
+
+        if SENTENCE_END.contains(&word) {
+            sentence_start = true;
+        } else if !DELAYED_SENTENCE_START.contains(&word) {
+            sentence_start = false;
+        }
+    }
+    let mut truecased_text = truecased_words.join(" ");
+    truecased_text.push('\n');
+    truecased_text
+}
+
+pub fn detruecase_file(input_file_path: &str, output_file_path: &str) -> Result<()> {
+    let reader =
+        BufReader::new(File::open(input_file_path).map_err(|e| Error::io(input_file_path, e))?);
+    let writer =
+        BufWriter::new(File::create(output_file_path).map_err(|e| Error::io(output_file_path, e))?);
+    map_lines_parallel(
+        reader,
+        writer,
+        Some(input_file_path),
+        Some(output_file_path),
+        detruecase_line,
+    )
+}
+
+pub fn detruecase_reader(reader: impl BufRead, writer: impl Write) -> Result<()> {
+    map_lines_parallel(reader, writer, None, None, detruecase_line)
+}
+
+/// Capitalizes the first word of every sentence
+pub fn detruecase_line(text: &str) -> String {
+    let text = text.trim_end_matches('\n');
+    // Lines consisting only of an XML/SGML tag are passed through unchanged
+    static RE_XML_LINE: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"^\s*</?[^>]+>\s*$").unwrap());
+    if RE_XML_LINE.is_match(text) {
+        return format!("{}\n", text);
+    }
+    let mut detruecased_words = Vec::new();
+    let mut sentence_start = true;
+    for piece in split_xml(text) {
+        let word = match piece {
+            XmlPiece::Word(word) => word,
+            XmlPiece::Markup(tag) => {
+                detruecased_words.push(tag.to_owned());
+                continue;
+            }
+        };
+        if sentence_start {
+            let mut chars = word.chars();
+            let first_char = chars.next().unwrap();
+            detruecased_words.push(first_char.to_uppercase().chain(chars).collect());
+        } else {
+            detruecased_words.push(word.to_owned());
+        }
+
+        if SENTENCE_END.contains(&word) {
+            sentence_start = true;
+        } else if !DELAYED_SENTENCE_START.contains(&word) {
+            sentence_start = false;
+        }
+    }
+    let mut detruecased_text = detruecased_words.join(" ");
+    detruecased_text.push('\n');
+    detruecased_text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CORPUS: &str = "The president of the United States visited Paris .\n\
+                          In Paris , the president met the mayor .\n\
+                          &quot; The mayor of Paris is here . &quot; She said .\n\
+                          He read the News about the United Nations .\n\
+                          The news was good : the news was new .\n";
+
+    #[test]
+    fn train_truecaser() {
+        let model = TruecaserModel::train(CORPUS, false);
+        assert_eq!(model.best("the"), Some("the"));
+        assert_eq!(model.best("paris"), Some("Paris"));
+        assert_eq!(model.best("news"), Some("news"));
+        assert!(model.is_known("News"));
+        // Sentence-initial words are not counted, neither after a delayed sentence start nor after ":"
+        assert_eq!(model.best("in"), None);
+        assert_eq!(model.best("she"), None);
+        assert_eq!(
+            model.casings["news"],
+            vec![("news".to_owned(), 2.0), ("News".to_owned(), 1.0)]
+        );
+    }
+
+    #[test]
+    fn train_truecaser_first_token() {
+        let model = TruecaserModel::train("in the morning .\nHello\nThe end .\n", true);
+        assert_eq!(model.best("in"), Some("in"));
+        assert_eq!(model.casings["hello"], vec![("Hello".to_owned(), 0.1)]);
+        assert_eq!(model.best("the"), Some("the"));
+    }
+
+    #[test]
+    fn save_and_load_model() {
+        let model = TruecaserModel::train(CORPUS, false);
+        let mut saved = Vec::new();
+        model.write(&mut saved).unwrap();
+        let saved = String::from_utf8(saved).unwrap();
+        assert!(saved.contains("news (2/3) News (1)\n"));
+        assert!(saved.contains("Paris (3/3)\n"));
+        assert_eq!(TruecaserModel::read(saved.as_bytes()).unwrap(), model);
+
+        assert!(matches!(
+            TruecaserModel::read("Paris (3/3)\nnews 2\n".as_bytes()),
+            Err(Error::InvalidModel { path: None, .. })
+        ));
+    }
+
+    #[test]
+    fn truecase_and_detruecase() {
+        let model = TruecaserModel::train(CORPUS, false);
+        assert_eq!(
+            truecase_line("THE MAYOR OF PARIS SAID : The News was good .", &model),
+            "the mayor of Paris said : the News was good .\n"
+        );
+        assert_eq!(
+            truecase_line("&quot; The president|NN of paris", &model),
+            "&quot; the president|NN of Paris\n"
+        );
+        assert_eq!(
+            detruecase_line("the mayor said : &quot; the news was good . &quot; ok"),
+            "The mayor said : &quot; The news was good . &quot; Ok\n"
+        );
+        assert_eq!(detruecase_line("<seg id=\"1\">"), "<seg id=\"1\">\n");
+    }
+
+    #[test]
+    fn xml_tags_with_attributes() {
+        let model = TruecaserModel::train(
+            "<seg id=\"1\"> The house of the mayor . </seg>\n<p class=\"a b\"> In the house of The Who .\n",
+            false,
+        );
+        assert_eq!(model.best("the"), Some("the"));
+        assert!(model.is_known("The"));
+        assert!(!model.is_known("id=\"1\">"));
+        assert_eq!(
+            truecase_line("<seg id=\"1\"> The house . </seg>", &model),
+            "<seg id=\"1\"> the house . </seg>\n"
+        );
+        assert_eq!(
+            detruecase_line("<seg id=\"1\"> the house . </seg>"),
+            "<seg id=\"1\"> The house . </seg>\n"
+        );
+        assert_eq!(
+            split_xml("a|<b c=\"d\">|| e<f>g <h"),
+            [
+                XmlPiece::Word("a|<b c=\"d\">||"),
+                XmlPiece::Word("e"),
+                XmlPiece::Markup("<f>"),
+                XmlPiece::Word("g"),
+                XmlPiece::Word("<h"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,52 @@
+use crate::error::{Error, Result};
+use rayon::prelude::*;
+use rayon::{ThreadPool, ThreadPoolBuilder};
+use std::sync::Arc;
+
+// Where the lines of a text are processed: on Rayon's global pool, on a dedicated pool, or sequentially on the
+// calling thread
+#[derive(Debug, Clone, Default)]
+pub(crate) enum Executor {
+    #[default]
+    Global,
+    Pool(Arc<ThreadPool>),
+    Sequential,
+}
+
+impl Executor {
+    // Executor with the given number of threads, where 0 uses Rayon's global pool and 1 the calling thread
+    pub(crate) fn with_threads(threads: usize) -> Result<Self> {
+        Ok(match threads {
+            0 => Executor::Global,
+            1 => Executor::Sequential,
+            threads => Executor::Pool(Arc::new(
+                ThreadPoolBuilder::new()
+                    .num_threads(threads)
+                    .build()
+                    .map_err(Error::ThreadPool)?,
+            )),
+        })
+    }
+
+    // Maps every item in batches of `batch_len` items, one batch per task, and returns the results in the original
+    // order
+    pub(crate) fn map_batches<T: Sync, U: Send>(
+        &self,
+        items: &[T],
+        batch_len: usize,
+        map_item: impl Fn(&T) -> U + Sync,
+    ) -> Vec<U> {
+        let batch_len = batch_len.max(1);
+        let map_parallel = || {
+            items
+                .par_chunks(batch_len)
+                .flat_map_iter(|batch| batch.iter().map(&map_item))
+                .collect()
+        };
+        match self {
+            Executor::Global => map_parallel(),
+            Executor::Pool(pool) => pool.install(map_parallel),
+            Executor::Sequential => items.iter().map(&map_item).collect(),
+        }
+    }
+}
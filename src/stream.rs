@@ -0,0 +1,150 @@
+use crate::error::{Error, Result};
+use crate::parallel::Executor;
+use rayon::prelude::*;
+use std::collections::HashMap;
+use std::io::{BufRead, Write};
+
+// Number of lines read and processed in parallel at a time when streaming, which bounds memory usage
+pub(crate) const STREAMING_CHUNK_LINES: usize = 10_000;
+
+// Reads `reader` in chunks of lines, maps every line in parallel and writes the results to `writer` in the
+// original order. File paths, when known, are only used to give context to errors.
+pub(crate) fn map_lines_parallel(
+    reader: impl BufRead,
+    writer: impl Write,
+    input_path: Option<&str>,
+    output_path: Option<&str>,
+    map_line: impl Fn(&str) -> String + Sync,
+) -> Result<()> {
+    map_lines_batched(
+        reader,
+        writer,
+        input_path,
+        output_path,
+        &Executor::Global,
+        1,
+        map_line,
+    )
+}
+
+// Like `map_lines_parallel`, but mapping the lines of every chunk with `executor` in batches of `batch_lines` lines
+pub(crate) fn map_lines_batched(
+    mut reader: impl BufRead,
+    mut writer: impl Write,
+    input_path: Option<&str>,
+    output_path: Option<&str>,
+    executor: &Executor,
+    batch_lines: usize,
+    map_line: impl Fn(&str) -> String + Sync,
+) -> Result<()> {
+    // Chunks hold at least one batch, so that large batches are not cut short
+    let chunk_lines = STREAMING_CHUNK_LINES.max(batch_lines);
+    let mut offset = 0;
+    loop {
+        let mut chunk = Vec::with_capacity(chunk_lines);
+        while chunk.len() < chunk_lines {
+            match read_line(&mut reader, &mut offset, input_path)? {
+                Some(line) => chunk.push(line),
+                None => break,
+            }
+        }
+        if chunk.is_empty() {
+            break;
+        }
+        for mapped_line in executor.map_batches(&chunk, batch_lines, |line| map_line(line)) {
+            writer
+                .write_all(mapped_line.as_bytes())
+                .map_err(io_error(output_path))?;
+        }
+    }
+    writer.flush().map_err(io_error(output_path))
+}
+
+// Reads `reader` in chunks of lines and folds every line into an accumulator in parallel, such as the counts of a
+// training corpus. The accumulators of the lines of a chunk and of the chunks are combined with `merge`.
+pub(crate) fn fold_lines_parallel<T: Default + Send>(
+    mut reader: impl BufRead,
+    fold_line: impl Fn(&mut T, &str) + Sync,
+    merge: impl Fn(T, T) -> T + Sync,
+) -> Result<T> {
+    let mut accumulator = T::default();
+    let mut offset = 0;
+    loop {
+        let mut chunk = Vec::with_capacity(STREAMING_CHUNK_LINES);
+        while chunk.len() < STREAMING_CHUNK_LINES {
+            match read_line(&mut reader, &mut offset, None)? {
+                Some(line) => chunk.push(line),
+                None => break,
+            }
+        }
+        if chunk.is_empty() {
+            break;
+        }
+        let chunk_accumulator = chunk
+            .par_iter()
+            .fold(T::default, |mut accumulator, line| {
+                fold_line(&mut accumulator, line);
+                accumulator
+            })
+            .reduce(T::default, &merge);
+        accumulator = merge(accumulator, chunk_accumulator);
+    }
+    Ok(accumulator)
+}
+
+// Adds the counts of words of `other_counts` to `counts`, as merged by `fold_lines_parallel`
+pub(crate) fn merge_counts(
+    mut counts: HashMap<String, u64>,
+    other_counts: HashMap<String, u64>,
+) -> HashMap<String, u64> {
+    for (word, count) in other_counts {
+        *counts.entry(word).or_default() += count;
+    }
+    counts
+}
+
+// Reads the next line without its terminator ("\n" or "\r\n"), or `None` at the end of the input. `offset` is the
+// number of bytes read so far and is used to locate invalid UTF-8.
+pub(crate) fn read_line(
+    reader: &mut impl BufRead,
+    offset: &mut usize,
+    input_path: Option<&str>,
+) -> Result<Option<String>> {
+    let Some(mut line) = read_line_with_terminator(reader, offset, input_path)? else {
+        return Ok(None);
+    };
+    if line.ends_with('\n') {
+        line.pop();
+        if line.ends_with('\r') {
+            line.pop();
+        }
+    }
+    Ok(Some(line))
+}
+
+// Like `read_line`, but keeping the terminator of the line if it has one
+pub(crate) fn read_line_with_terminator(
+    reader: &mut impl BufRead,
+    offset: &mut usize,
+    input_path: Option<&str>,
+) -> Result<Option<String>> {
+    let mut buffer = Vec::new();
+    let read = reader
+        .read_until(b'\n', &mut buffer)
+        .map_err(io_error(input_path))?;
+    if read == 0 {
+        return Ok(None);
+    }
+    let line = String::from_utf8(buffer).map_err(|e| Error::InvalidUtf8 {
+        offset: *offset + e.utf8_error().valid_up_to(),
+    })?;
+    *offset += read;
+    Ok(Some(line))
+}
+
+pub(crate) fn io_error(path: Option<&str>) -> impl FnOnce(std::io::Error) -> Error + '_ {
+    move |source| Error::Io {
+        path: path.map(str::to_owned),
+        source,
+    }
+}
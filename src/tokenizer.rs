@@ -0,0 +1,378 @@
+use crate::bpe::{BpeCodes, BpeTokenizer};
+use crate::byte_bpe::{ByteBpeModel, ByteBpeTokenizer, ByteBpeTokenizerBuilder};
+use crate::error::{Error, Result};
+use crate::huggingface::HuggingFaceTokenizer;
+use crate::moses::{DEFAULT_BATCH_LINES, Language, MosesTokenizer, MosesTokenizerBuilder};
+use crate::parallel::Executor;
+use crate::stream::{map_lines_batched, map_lines_parallel};
+use crate::unigram::{UnigramModel, UnigramTokenizer};
+use crate::whitespace::WhitespaceTokenizer;
+use crate::wordpiece::{WordPieceTokenizer, WordPieceTokenizerBuilder, WordPieceVocabulary};
+use rayon::prelude::*;
+use std::io::{BufRead, Write};
+
+/// Token along with the byte (`start`, `end`) and char (`char_start`, `char_end`) offsets of the text it was
+/// extracted from in the original line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    pub text: String,
+    pub start: usize,
+    pub end: usize,
+    pub char_start: usize,
+    pub char_end: usize,
+}
+
+/// Tokens of a line along with their ids in the vocabulary of the model that produced them
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Encoding {
+    pub ids: Vec<u32>,
+    pub tokens: Vec<Token>,
+}
+
+/// Tokenizer of the bundle, which splits lines of text into tokens
+pub trait Tokenizer: Send + Sync {
+    /// Name of the tokenizer, as selected with `--tokenizer` in the command line
+    fn name(&self) -> &str;
+
+    /// Tokenizes a single line
+    fn tokenize_line(&self, line: &str) -> Vec<String>;
+
+    /// Tokenizes a single line and returns its tokens along with their byte and char offsets into `line`
+    fn tokenize_spans(&self, line: &str) -> Vec<Token>;
+
+    /// Tokenizes every line in parallel, keeping their order
+    fn tokenize_batch(&self, lines: &[&str]) -> Vec<Vec<String>> {
+        lines
+            .par_iter()
+            .map(|line| self.tokenize_line(line))
+            .collect()
+    }
+
+    /// Tokenizes a single line into the text written by [`Self::tokenize_reader`], its tokens separated by spaces and
+    /// followed by a newline
+    fn tokenize_line_string(&self, line: &str) -> String {
+        let mut tokenized_line = self.tokenize_line(line).join(" ");
+        tokenized_line.push('\n');
+        tokenized_line
+    }
+
+    /// Tokenizes every line of `reader` and writes its tokens separated by spaces to `writer`, one line per line
+    fn tokenize_reader(&self, reader: &mut dyn BufRead, writer: &mut dyn Write) -> Result<()> {
+        map_lines_parallel(reader, writer, None, None, |line| {
+            self.tokenize_line_string(line)
+        })
+    }
+}
+
+/// Inverse operation of a [`Tokenizer`], which joins tokens back into text
+pub trait Detokenizer: Send + Sync {
+    /// Detokenizes the tokens of a single line
+    fn detokenize_line(&self, tokens: &[String]) -> String;
+}
+
+/// Options given to the factories of a [`TokenizerRegistry`], each of which uses the ones that apply to it
+#[derive(Debug, Clone)]
+pub struct TokenizerOptions {
+    /// Language of the text, which only the tokenizers that depend on it require
+    pub language: Option<Language>,
+    /// Configuration of the Moses tokenizer, whose language is replaced by `language`
+    pub moses: MosesTokenizerBuilder,
+    /// Configuration of the WordPiece tokenizer, whose vocabulary is given as model
+    pub wordpiece: WordPieceTokenizerBuilder,
+    /// Configuration of the byte-level BPE tokenizer, whose rank file or `vocab.json` is given as model
+    pub byte_bpe: ByteBpeTokenizerBuilder,
+    /// Replaces the pre-tokenizer of Hugging Face pipelines with the Moses tokenizer configured by `moses`
+    pub moses_pre_tokenizer: bool,
+    /// Model file of the tokenizers that need one, such as the codes of `bpe`
+    pub model: Option<String>,
+    /// Number of threads that every tokenizer of the registry tokenizes batches and readers with, where 0 uses one per
+    /// core and 1 tokenizes sequentially
+    pub threads: usize,
+    /// Number of lines tokenized by each parallel task. Defaults to [`DEFAULT_BATCH_LINES`]
+    pub batch_lines: usize,
+}
+
+impl TokenizerOptions {
+    /// Path of the model file required by a tokenizer
+    pub fn model(&self, tokenizer: &str) -> Result<&str> {
+        self.model
+            .as_deref()
+            .ok_or_else(|| Error::MissingModel(tokenizer.to_owned()))
+    }
+
+    /// Language of the text required by a tokenizer
+    pub fn language(&self, tokenizer: &str) -> Result<Language> {
+        self.language
+            .clone()
+            .ok_or_else(|| Error::MissingLanguage(tokenizer.to_owned()))
+    }
+
+    pub fn new(language: Language) -> Self {
+        TokenizerOptions {
+            moses: MosesTokenizer::builder(language.clone()),
+            language: Some(language),
+            wordpiece: WordPieceTokenizer::builder(),
+            byte_bpe: ByteBpeTokenizer::builder(),
+            moses_pre_tokenizer: false,
+            model: None,
+            threads: 0,
+            batch_lines: DEFAULT_BATCH_LINES,
+        }
+    }
+}
+
+pub type TokenizerFactory = fn(&TokenizerOptions) -> Result<Box<dyn Tokenizer>>;
+
+/// Tokenizer that can be built by name from a [`TokenizerRegistry`]
+#[derive(Debug, Clone, Copy)]
+pub struct TokenizerEntry {
+    pub name: &'static str,
+    /// One-line description shown in the help of the command line
+    pub about: &'static str,
+    pub factory: TokenizerFactory,
+}
+
+/// Tokenizers available by name, such as the ones selected with `--tokenizer` in the command line
+#[derive(Debug, Clone, Default)]
+pub struct TokenizerRegistry {
+    entries: Vec<TokenizerEntry>,
+}
+
+impl TokenizerRegistry {
+    /// Empty registry, see [`Self::builtin`] for the tokenizers of the crate
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registry with every tokenizer of the crate, the first of which is the default one
+    pub fn builtin() -> Self {
+        let mut registry = Self::new();
+        registry.register(TokenizerEntry {
+            name: "moses",
+            about: "Moses-like tokenizer, like tokenizer.perl",
+            factory: |options| {
+                let language = options.language("moses")?;
+                Ok(Box::new(options.moses.clone().language(language).build()?))
+            },
+        });
+        registry.register(TokenizerEntry {
+            name: "whitespace",
+            about: "Splits lines on whitespace only",
+            factory: |_| Ok(Box::new(WhitespaceTokenizer)),
+        });
+        registry.register(TokenizerEntry {
+            name: "bpe",
+            about: "Splits words into subwords with the BPE codes given as model, like subword-nmt apply-bpe",
+            factory: |options| {
+                let codes = BpeCodes::load(options.model("bpe")?)?;
+                Ok(Box::new(BpeTokenizer::new(&codes)))
+            },
+        });
+        registry.register(TokenizerEntry {
+            name: "wordpiece",
+            about: "Splits text into the subwords of the BERT vocab.txt given as model",
+            factory: |options| {
+                let vocabulary = WordPieceVocabulary::load(options.model("wordpiece")?)?;
+                Ok(Box::new(options.wordpiece.clone().build(vocabulary)?))
+            },
+        });
+        registry.register(TokenizerEntry {
+            name: "unigram",
+            about: "Splits text into the pieces of the SentencePiece Unigram .model given as model",
+            factory: |options| {
+                let model = UnigramModel::load(options.model("unigram")?)?;
+                Ok(Box::new(UnigramTokenizer::new(&model)))
+            },
+        });
+        registry.register(TokenizerEntry {
+            name: "byte_bpe",
+            about: "Splits text into the byte-level BPE tokens of the GPT-2 vocab.json or tiktoken file given as model",
+            factory: |options| {
+                let model = ByteBpeModel::load(options.model("byte_bpe")?)?;
+                Ok(Box::new(options.byte_bpe.clone().build(model)?))
+            },
+        });
+        registry.register(TokenizerEntry {
+            name: "huggingface",
+            about: "Runs the pipeline of the Hugging Face tokenizer.json given as model",
+            factory: |options| {
+                let mut tokenizer = HuggingFaceTokenizer::load(options.model("huggingface")?)?;
+                if options.moses_pre_tokenizer {
+                    let language = options.language("huggingface")?;
+                    let moses = options.moses.clone().language(language).build()?;
+                    tokenizer = tokenizer.moses_pre_tokenizer(moses);
+                }
+                Ok(Box::new(tokenizer))
+            },
+        });
+        registry
+    }
+
+    /// Adds a tokenizer, replacing any other one with the same name
+    pub fn register(&mut self, entry: TokenizerEntry) {
+        match self.entries.iter_mut().find(|e| e.name == entry.name) {
+            Some(existing_entry) => *existing_entry = entry,
+            None => self.entries.push(entry),
+        }
+    }
+
+    pub fn entries(&self) -> &[TokenizerEntry] {
+        &self.entries
+    }
+
+    pub fn build(&self, name: &str, options: &TokenizerOptions) -> Result<Box<dyn Tokenizer>> {
+        let entry = self
+            .entries
+            .iter()
+            .find(|entry| entry.name == name)
+            .ok_or_else(|| Error::UnsupportedTokenizer(name.to_owned()))?;
+        Ok(Box::new(ParallelTokenizer {
+            tokenizer: (entry.factory)(options)?,
+            executor: Executor::with_threads(options.threads)?,
+            batch_lines: options.batch_lines.max(1),
+        }))
+    }
+}
+
+// Tokenizer built by a registry, whose batches and readers are tokenized with the threads and batch lines of the
+// options instead of Rayon's global pool
+struct ParallelTokenizer {
+    tokenizer: Box<dyn Tokenizer>,
+    executor: Executor,
+    batch_lines: usize,
+}
+
+impl Tokenizer for ParallelTokenizer {
+    fn name(&self) -> &str {
+        self.tokenizer.name()
+    }
+
+    fn tokenize_line(&self, line: &str) -> Vec<String> {
+        self.tokenizer.tokenize_line(line)
+    }
+
+    fn tokenize_spans(&self, line: &str) -> Vec<Token> {
+        self.tokenizer.tokenize_spans(line)
+    }
+
+    fn tokenize_line_string(&self, line: &str) -> String {
+        self.tokenizer.tokenize_line_string(line)
+    }
+
+    fn tokenize_batch(&self, lines: &[&str]) -> Vec<Vec<String>> {
+        self.executor.map_batches(lines, self.batch_lines, |line| {
+            self.tokenizer.tokenize_line(line)
+        })
+    }
+
+    fn tokenize_reader(&self, reader: &mut dyn BufRead, writer: &mut dyn Write) -> Result<()> {
+        map_lines_batched(
+            reader,
+            writer,
+            None,
+            None,
+            &self.executor,
+            self.batch_lines,
+            |line| self.tokenizer.tokenize_line_string(line),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_tokenizers() {
+        let registry = TokenizerRegistry::builtin();
+        let options = TokenizerOptions::new(Language::En);
+        for entry in registry.entries() {
+            if ["bpe", "wordpiece", "unigram", "byte_bpe", "huggingface"].contains(&entry.name) {
+                continue;
+            }
+            let tokenizer = registry.build(entry.name, &options).unwrap();
+            assert_eq!(tokenizer.name(), entry.name);
+            assert_eq!(
+                tokenizer.tokenize_batch(&["Hello world", "", "Bye"]),
+                vec![vec!["Hello", "world"], vec![], vec!["Bye"]]
+            );
+        }
+        assert!(matches!(
+            registry.build("unknown", &options),
+            Err(Error::UnsupportedTokenizer(name)) if name == "unknown"
+        ));
+        assert!(matches!(
+            registry.build("bpe", &options),
+            Err(Error::MissingModel(name)) if name == "bpe"
+        ));
+        assert!(matches!(
+            registry.build("wordpiece", &options),
+            Err(Error::MissingModel(name)) if name == "wordpiece"
+        ));
+        assert!(matches!(
+            registry.build("unigram", &options),
+            Err(Error::MissingModel(name)) if name == "unigram"
+        ));
+        assert!(matches!(
+            registry.build("byte_bpe", &options),
+            Err(Error::MissingModel(name)) if name == "byte_bpe"
+        ));
+        assert!(matches!(
+            registry.build("huggingface", &options),
+            Err(Error::MissingModel(name)) if name == "huggingface"
+        ));
+    }
+
+    #[test]
+    fn missing_language() {
+        let registry = TokenizerRegistry::builtin();
+        let mut options = TokenizerOptions::new(Language::Fr);
+        options.language = None;
+        assert!(matches!(
+            registry.build("moses", &options),
+            Err(Error::MissingLanguage(name)) if name == "moses"
+        ));
+        let tokenizer = registry.build("whitespace", &options).unwrap();
+        assert_eq!(tokenizer.tokenize_line("a b"), ["a", "b"]);
+    }
+
+    #[test]
+    fn registry_threads() {
+        let registry = TokenizerRegistry::builtin();
+        let lines: Vec<String> = (0..500).map(|i| format!("line {i}")).collect();
+        let lines: Vec<&str> = lines.iter().map(String::as_str).collect();
+        for threads in [1, 3] {
+            let mut options = TokenizerOptions::new(Language::En);
+            options.threads = threads;
+            options.batch_lines = 7;
+            let tokenizer = registry.build("whitespace", &options).unwrap();
+            let batch = tokenizer.tokenize_batch(&lines);
+            assert_eq!(batch.len(), lines.len());
+            assert_eq!(batch[499], ["line", "499"]);
+            let mut output = Vec::new();
+            tokenizer
+                .tokenize_reader(&mut "a  b\nc\n".as_bytes(), &mut output)
+                .unwrap();
+            assert_eq!(output, b"a b\nc\n");
+        }
+    }
+
+    #[test]
+    fn registered_tokenizer_replaces_builtin() {
+        let mut registry = TokenizerRegistry::builtin();
+        let entries = registry.entries().len();
+        registry.register(TokenizerEntry {
+            name: "moses",
+            about: "",
+            factory: |_| Ok(Box::new(WhitespaceTokenizer)),
+        });
+        assert_eq!(registry.entries().len(), entries);
+        let tokenizer = registry
+            .build("moses", &TokenizerOptions::new(Language::En))
+            .unwrap();
+        assert_eq!(
+            tokenizer.tokenize_line("Hello, world."),
+            ["Hello,", "world."]
+        );
+    }
+}
@@ -0,0 +1,823 @@
+use crate::error::{Error, Result};
+use crate::tokenizer::{Detokenizer, Encoding, Token, Tokenizer};
+use lattice::{Lattice, Node, SplitMix64};
+use normalizer::Normalizer;
+use proto::{Reader, Writer};
+use rayon::prelude::*;
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::ops::Range;
+mod lattice;
+mod normalizer;
+mod proto;
+mod train;
+pub(crate) use normalizer::PrecompiledCharsmap;
+pub use train::UnigramTrainer;
+
+// Ports of https://github.com/google/sentencepiece/blob/master/src/unigram_model.cc and of the encoding and decoding
+// of https://github.com/google/sentencepiece/blob/master/src/sentencepiece_processor.cc
+
+// Marker of the spaces of normalized text
+const SPACE_SYMBOL: &str = "\u{2581}";
+
+// Penalty of the score of unknown pieces with respect to the lowest score of the model
+const UNKNOWN_PENALTY: f32 = 10.0;
+
+// Text that unknown pieces are decoded into by default
+const DEFAULT_UNKNOWN_SURFACE: &str = " \u{2047} ";
+
+/// Type of a piece of a SentencePiece model
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PieceType {
+    /// Piece that text is split into according to its score
+    Normal,
+    /// Piece produced for the characters that no other piece matches
+    Unknown,
+    /// Piece that is never produced from text, such as `<s>` and `</s>`
+    Control,
+    /// Piece that is always produced when found in the text, which is not normalized
+    UserDefined,
+    /// Piece left out of segmentations
+    Unused,
+    /// Piece of a single byte such as `<0x41>`, which unknown characters are split into with byte fallback
+    Byte,
+}
+
+impl PieceType {
+    fn from_proto(value: u64) -> Option<Self> {
+        match value {
+            1 => Some(PieceType::Normal),
+            2 => Some(PieceType::Unknown),
+            3 => Some(PieceType::Control),
+            4 => Some(PieceType::UserDefined),
+            5 => Some(PieceType::Unused),
+            6 => Some(PieceType::Byte),
+            _ => None,
+        }
+    }
+
+    fn to_proto(self) -> u64 {
+        match self {
+            PieceType::Normal => 1,
+            PieceType::Unknown => 2,
+            PieceType::Control => 3,
+            PieceType::UserDefined => 4,
+            PieceType::Unused => 5,
+            PieceType::Byte => 6,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Piece {
+    pub piece: String,
+    /// Log probability of the piece, for normal pieces
+    pub score: f32,
+    pub kind: PieceType,
+}
+
+impl Piece {
+    pub fn new(piece: &str, score: f32, kind: PieceType) -> Self {
+        Piece {
+            piece: piece.to_owned(),
+            score,
+            kind,
+        }
+    }
+}
+
+/// Unigram language model of SentencePiece, with the pieces that text is split into (whose ids are their positions)
+/// and the way text is normalized, as found in `.model` files
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnigramModel {
+    pieces: Vec<Piece>,
+    // Options of the `NormalizerSpec` of the model
+    normalizer_name: String,
+    charsmap: Option<PrecompiledCharsmap>,
+    add_dummy_prefix: bool,
+    remove_extra_whitespaces: bool,
+    escape_whitespaces: bool,
+    // Options of the `TrainerSpec` of the model
+    treat_whitespace_as_suffix: bool,
+    byte_fallback: bool,
+    unknown_surface: String,
+}
+
+impl UnigramModel {
+    /// Model with the given pieces, which must contain a single unknown piece, and without normalization rules other
+    /// than the handling of whitespace. Byte fallback is enabled when there are byte pieces.
+    pub fn new(pieces: Vec<Piece>) -> Result<Self> {
+        let model = UnigramModel {
+            byte_fallback: pieces.iter().any(|piece| piece.kind == PieceType::Byte),
+            pieces,
+            ..Self::default()
+        };
+        model.validate().map_err(|message| Error::InvalidModel {
+            path: None,
+            message,
+        })?;
+        Ok(model)
+    }
+
+    pub fn pieces(&self) -> &[Piece] {
+        &self.pieces
+    }
+
+    /// Name of the normalization rules of the model, such as `nmt_nfkc` or `identity`
+    pub fn normalizer_name(&self) -> &str {
+        &self.normalizer_name
+    }
+
+    pub fn byte_fallback(&self) -> bool {
+        self.byte_fallback
+    }
+
+    /// Loads a `.model` file written by SentencePiece, which must be a Unigram model
+    pub fn load(model_file_path: &str) -> Result<Self> {
+        let reader =
+            BufReader::new(File::open(model_file_path).map_err(|e| Error::io(model_file_path, e))?);
+        Self::read(reader).map_err(|e| e.with_path(model_file_path))
+    }
+
+    pub fn read(mut reader: impl Read) -> Result<Self> {
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data)?;
+        Self::parse(&data).map_err(|message| Error::InvalidModel {
+            path: None,
+            message,
+        })
+    }
+
+    pub fn save(&self, model_file_path: &str) -> Result<()> {
+        let writer = BufWriter::new(
+            File::create(model_file_path).map_err(|e| Error::io(model_file_path, e))?,
+        );
+        self.write(writer).map_err(|e| e.with_path(model_file_path))
+    }
+
+    /// Writes the model in the format of SentencePiece, with the fields of the specs it knows about
+    pub fn write(&self, mut writer: impl Write) -> Result<()> {
+        let mut model = Writer::default();
+        for piece in &self.pieces {
+            let mut message = Writer::default();
+            message.bytes(1, piece.piece.as_bytes());
+            message.f32(2, piece.score);
+            message.varint(3, piece.kind.to_proto());
+            model.message(1, &message);
+        }
+        let mut trainer_spec = Writer::default();
+        // Unigram model type
+        trainer_spec.varint(3, 1);
+        trainer_spec.int32(4, self.pieces.len() as i32);
+        if self.treat_whitespace_as_suffix {
+            trainer_spec.bool(24, true);
+        }
+        trainer_spec.bool(35, self.byte_fallback);
+        trainer_spec.bytes(44, self.unknown_surface.as_bytes());
+        model.message(2, &trainer_spec);
+        let mut normalizer_spec = Writer::default();
+        normalizer_spec.bytes(1, self.normalizer_name.as_bytes());
+        if let Some(charsmap) = &self.charsmap {
+            normalizer_spec.bytes(2, charsmap.blob());
+        }
+        normalizer_spec.bool(3, self.add_dummy_prefix);
+        normalizer_spec.bool(4, self.remove_extra_whitespaces);
+        normalizer_spec.bool(5, self.escape_whitespaces);
+        model.message(3, &normalizer_spec);
+
+        writer.write_all(&model.into_bytes())?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    // Reads a serialized `ModelProto`, whose missing fields take the defaults of its definition
+    fn parse(data: &[u8]) -> std::result::Result<Self, String> {
+        let mut model = Self::default();
+        let mut fields = Reader::new(data);
+        while let Some((number, value)) = fields.next_field()? {
+            match number {
+                1 => model.pieces.push(Self::parse_piece(value.as_bytes()?)?),
+                2 => {
+                    let mut fields = Reader::new(value.as_bytes()?);
+                    while let Some((number, value)) = fields.next_field()? {
+                        match number {
+                            3 => match value.as_u64()? {
+                                1 => {}
+                                2 => return Err("unsupported BPE model".to_owned()),
+                                3 => return Err("unsupported word model".to_owned()),
+                                4 => return Err("unsupported char model".to_owned()),
+                                model_type => {
+                                    return Err(format!("unknown model type {model_type}"));
+                                }
+                            },
+                            24 => model.treat_whitespace_as_suffix = value.as_bool()?,
+                            35 => model.byte_fallback = value.as_bool()?,
+                            44 => model.unknown_surface = value.as_str()?.to_owned(),
+                            _ => {}
+                        }
+                    }
+                }
+                3 => {
+                    let mut fields = Reader::new(value.as_bytes()?);
+                    while let Some((number, value)) = fields.next_field()? {
+                        match number {
+                            1 => model.normalizer_name = value.as_str()?.to_owned(),
+                            2 if !value.as_bytes()?.is_empty() => {
+                                model.charsmap =
+                                    Some(PrecompiledCharsmap::new(value.as_bytes()?.to_vec())?);
+                            }
+                            3 => model.add_dummy_prefix = value.as_bool()?,
+                            4 => model.remove_extra_whitespaces = value.as_bool()?,
+                            5 => model.escape_whitespaces = value.as_bool()?,
+                            _ => {}
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        model.validate()?;
+        Ok(model)
+    }
+
+    fn parse_piece(data: &[u8]) -> std::result::Result<Piece, String> {
+        let mut piece = Piece::new("", 0.0, PieceType::Normal);
+        let mut fields = Reader::new(data);
+        while let Some((number, value)) = fields.next_field()? {
+            match number {
+                1 => piece.piece = value.as_str()?.to_owned(),
+                2 => piece.score = value.as_f32()?,
+                3 => {
+                    piece.kind = PieceType::from_proto(value.as_u64()?)
+                        .ok_or_else(|| format!("unknown piece type {value:?}"))?;
+                }
+                _ => {}
+            }
+        }
+        Ok(piece)
+    }
+
+    // Same checks as the original implementation when loading a model
+    fn validate(&self) -> std::result::Result<(), String> {
+        let mut pieces = HashSet::new();
+        for piece in &self.pieces {
+            if piece.piece.is_empty() {
+                return Err("empty piece".to_owned());
+            }
+            if !pieces.insert(piece.piece.as_str()) {
+                return Err(format!("piece `{}` is defined more than once", piece.piece));
+            }
+        }
+        match self
+            .pieces
+            .iter()
+            .filter(|piece| piece.kind == PieceType::Unknown)
+            .count()
+        {
+            0 => return Err("no unknown piece".to_owned()),
+            1 => {}
+            _ => return Err("more than one unknown piece".to_owned()),
+        }
+        if self.byte_fallback {
+            for byte in 0..=u8::MAX {
+                let piece = byte_piece(byte);
+                if !self
+                    .pieces
+                    .iter()
+                    .any(|p| p.piece == piece && p.kind == PieceType::Byte)
+                {
+                    return Err(format!("missing byte piece `{piece}` for byte fallback"));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for UnigramModel {
+    // Empty model with the defaults of the specs of the original implementation, but without normalization rules
+    fn default() -> Self {
+        UnigramModel {
+            pieces: Vec::new(),
+            normalizer_name: "identity".to_owned(),
+            charsmap: None,
+            add_dummy_prefix: true,
+            remove_extra_whitespaces: true,
+            escape_whitespaces: true,
+            treat_whitespace_as_suffix: false,
+            byte_fallback: false,
+            unknown_surface: DEFAULT_UNKNOWN_SURFACE.to_owned(),
+        }
+    }
+}
+
+fn byte_piece(byte: u8) -> String {
+    format!("<0x{byte:02X}>")
+}
+
+// Byte-wise trie mapping pieces to their ids
+#[derive(Debug, Clone)]
+struct Trie {
+    children: HashMap<(u32, u8), u32>,
+    values: Vec<Option<u32>>,
+}
+
+impl Default for Trie {
+    fn default() -> Self {
+        Trie {
+            children: HashMap::new(),
+            values: vec![None],
+        }
+    }
+}
+
+impl Trie {
+    fn insert(&mut self, key: &str, value: u32) {
+        let mut node = 0;
+        for &byte in key.as_bytes() {
+            node = match self.children.get(&(node, byte)) {
+                Some(&child) => child,
+                None => {
+                    let child = self.values.len() as u32;
+                    self.values.push(None);
+                    self.children.insert((node, byte), child);
+                    child
+                }
+            };
+        }
+        self.values[node as usize] = Some(value);
+    }
+
+    // Lengths and values of the keys that are prefixes of `text`, the shortest first
+    fn common_prefixes<'a>(&'a self, text: &'a [u8]) -> impl Iterator<Item = (usize, u32)> + 'a {
+        let mut node = 0;
+        text.iter()
+            .enumerate()
+            .map_while(move |(i, byte)| {
+                node = *self.children.get(&(node, *byte))?;
+                Some((i + 1, self.values[node as usize]))
+            })
+            .filter_map(|(length, value)| value.map(|value| (length, value)))
+    }
+}
+
+// Scores of the pieces that normalized text is split into, shared by the tokenizer and the trainer
+#[derive(Debug, Clone)]
+pub(crate) struct Segmenter {
+    // Normal, user-defined and unused pieces
+    trie: Trie,
+    pieces: Vec<(f32, PieceType)>,
+    unknown_id: u32,
+    // Range of the scores of the normal pieces
+    min_score: f32,
+    max_score: f32,
+}
+
+impl Segmenter {
+    pub(crate) fn new(pieces: &[Piece], unknown_id: u32) -> Self {
+        let mut trie = Trie::default();
+        let mut min_score = f32::MAX;
+        let mut max_score = f32::MIN;
+        for (id, piece) in pieces.iter().enumerate() {
+            match piece.kind {
+                PieceType::Normal => {
+                    min_score = min_score.min(piece.score);
+                    max_score = max_score.max(piece.score);
+                    trie.insert(&piece.piece, id as u32);
+                }
+                PieceType::UserDefined | PieceType::Unused => trie.insert(&piece.piece, id as u32),
+                _ => {}
+            }
+        }
+        if min_score > max_score {
+            (min_score, max_score) = (0.0, 0.0);
+        }
+        Segmenter {
+            trie,
+            pieces: pieces
+                .iter()
+                .map(|piece| (piece.score, piece.kind))
+                .collect(),
+            unknown_id,
+            min_score,
+            max_score,
+        }
+    }
+
+    // Score of a piece found in the text, where user-defined pieces get a bonus so that they are always chosen
+    fn score(&self, id: u32, length: usize) -> Option<f32> {
+        match self.pieces[id as usize] {
+            (_, PieceType::Unused) => None,
+            (_, PieceType::UserDefined) => Some(length as f32 * self.max_score - 0.1),
+            (score, _) => Some(score),
+        }
+    }
+
+    // Best segmentation of normalized text into the byte ranges of its pieces, along with their ids. Like the
+    // optimized Viterbi algorithm of the original implementation, the best segmentation ending at every position
+    // is found from left to right, and unknown characters are single pieces.
+    pub(crate) fn viterbi(&self, text: &str) -> Vec<(Range<usize>, u32)> {
+        let unknown_score = self.min_score - UNKNOWN_PENALTY;
+        // Score of the best segmentation ending at every position, along with the start and id of its last piece
+        let mut best: Vec<Option<(f32, usize, u32)>> = vec![None; text.len() + 1];
+        let update = |best: &mut [Option<(f32, usize, u32)>], end: usize, score: f32, start, id| {
+            if best[end].is_none_or(|(best_score, _, _)| score > best_score) {
+                best[end] = Some((score, start, id));
+            }
+        };
+        let mut start = 0;
+        while start < text.len() {
+            let score_so_far = best[start].map_or(0.0, |(score, _, _)| score);
+            let char_len = text[start..].chars().next().unwrap().len_utf8();
+            let mut has_single_char = false;
+            for (length, id) in self.trie.common_prefixes(&text.as_bytes()[start..]) {
+                let Some(score) = self.score(id, length) else {
+                    continue;
+                };
+                update(&mut best, start + length, score_so_far + score, start, id);
+                has_single_char |= length == char_len;
+            }
+            if !has_single_char {
+                update(
+                    &mut best,
+                    start + char_len,
+                    score_so_far + unknown_score,
+                    start,
+                    self.unknown_id,
+                );
+            }
+            start += char_len;
+        }
+
+        let mut pieces = Vec::new();
+        let mut end = text.len();
+        while let Some((_, start, id)) = best[end].filter(|_| end > 0) {
+            pieces.push((start..end, id));
+            end = start;
+        }
+        pieces.reverse();
+        pieces
+    }
+
+    // Every possible segmentation of normalized text, where unknown characters are single pieces
+    fn lattice(&self, text: &str) -> Lattice {
+        let unknown_score = self.min_score - UNKNOWN_PENALTY;
+        let mut lattice = Lattice::new(text.len());
+        for (start, ch) in text.char_indices() {
+            let mut has_single_char = false;
+            for (length, id) in self.trie.common_prefixes(&text.as_bytes()[start..]) {
+                let Some(score) = self.score(id, text[start..start + length].chars().count())
+                else {
+                    continue;
+                };
+                lattice.insert(Node {
+                    start,
+                    end: start + length,
+                    id,
+                    score,
+                });
+                has_single_char |= length == ch.len_utf8();
+            }
+            if !has_single_char {
+                lattice.insert(Node {
+                    start,
+                    end: start + ch.len_utf8(),
+                    id: self.unknown_id,
+                    score: unknown_score,
+                });
+            }
+        }
+        lattice
+    }
+}
+
+/// Tokenizer of SentencePiece Unigram models, which normalizes text and splits it into the pieces of its most likely
+/// segmentation, or of randomly sampled ones for subword regularization
+#[derive(Debug, Clone)]
+pub struct UnigramTokenizer {
+    model: UnigramModel,
+    normalizer: Normalizer,
+    segmenter: Segmenter,
+    ids: HashMap<String, u32>,
+}
+
+impl UnigramTokenizer {
+    pub fn new(model: &UnigramModel) -> Self {
+        let mut user_defined = Trie::default();
+        let mut ids = HashMap::new();
+        let mut unknown_id = 0;
+        for (id, piece) in model.pieces.iter().enumerate() {
+            match piece.kind {
+                PieceType::UserDefined => user_defined.insert(&piece.piece, id as u32),
+                PieceType::Unknown => unknown_id = id as u32,
+                _ => {}
+            }
+            ids.insert(piece.piece.clone(), id as u32);
+        }
+        UnigramTokenizer {
+            normalizer: Normalizer {
+                charsmap: model.charsmap.clone(),
+                user_defined,
+                add_dummy_prefix: model.add_dummy_prefix,
+                remove_extra_whitespaces: model.remove_extra_whitespaces,
+                escape_whitespaces: model.escape_whitespaces,
+                treat_whitespace_as_suffix: model.treat_whitespace_as_suffix,
+            },
+            segmenter: Segmenter::new(&model.pieces, unknown_id),
+            model: model.clone(),
+            ids,
+        }
+    }
+
+    pub fn model(&self) -> &UnigramModel {
+        &self.model
+    }
+
+    /// Id of a piece of the model
+    pub fn id(&self, piece: &str) -> Option<u32> {
+        self.ids.get(piece).copied()
+    }
+
+    /// Text as normalized by the model before splitting it, with spaces replaced by `▁`
+    pub fn normalize(&self, text: &str) -> String {
+        self.normalizer.normalize(text).0
+    }
+
+    /// Splits a line into the pieces of its most likely segmentation and returns their ids along with their offsets
+    /// into `line`
+    pub fn encode(&self, line: &str) -> Encoding {
+        let (normalized, offsets) = self.normalizer.normalize(line);
+        let pieces = self.segmenter.viterbi(&normalized);
+        self.encoding(line, &normalized, &offsets, pieces)
+    }
+
+    /// Encodes every line in parallel, keeping their order
+    pub fn encode_batch(&self, lines: &[&str]) -> Vec<Encoding> {
+        lines.par_iter().map(|line| self.encode(line)).collect()
+    }
+
+    /// Splits a line into the pieces of a segmentation sampled from all of them, for subword regularization.
+    /// Segmentations are sampled with a probability proportional to their likelihood raised to `alpha`, so that 0
+    /// samples them uniformly and higher values approach the most likely one. The same `seed` gives the same sample.
+    pub fn sample(&self, line: &str, alpha: f32, seed: u64) -> Encoding {
+        let (normalized, offsets) = self.normalizer.normalize(line);
+        let lattice = self.segmenter.lattice(&normalized);
+        let pieces = lattice
+            .sample(f64::from(alpha), &mut SplitMix64::new(seed))
+            .into_iter()
+            .map(|node| (node.start..node.end, node.id))
+            .collect();
+        self.encoding(line, &normalized, &offsets, pieces)
+    }
+
+    /// Text of the pieces with the given ids, where `▁` becomes a space again. Control pieces are left out, and ids
+    /// out of the model are ignored.
+    pub fn decode(&self, ids: &[u32]) -> String {
+        self.decode_pieces(ids.iter().filter_map(|&id| {
+            let piece = self.model.pieces.get(id as usize)?;
+            Some((piece.piece.as_str(), Some(piece.kind)))
+        }))
+    }
+
+    // Tokens of the pieces of normalized text, whose unknown pieces are split into bytes with byte fallback
+    fn encoding(
+        &self,
+        line: &str,
+        normalized: &str,
+        offsets: &[usize],
+        pieces: Vec<(Range<usize>, u32)>,
+    ) -> Encoding {
+        // Char offset of every char boundary of the line
+        let mut char_offsets = vec![0; line.len() + 1];
+        for (char_position, (position, _)) in line.char_indices().enumerate() {
+            char_offsets[position] = char_position;
+        }
+        char_offsets[line.len()] = line.chars().count();
+        let token = |text: String, start: usize, end: usize| Token {
+            text,
+            start,
+            end,
+            char_start: char_offsets[start],
+            char_end: char_offsets[end],
+        };
+
+        let mut encoding = Encoding::default();
+        for (range, id) in pieces {
+            let (start, end) = (offsets[range.start], offsets[range.end]);
+            if id == self.segmenter.unknown_id && self.model.byte_fallback {
+                // Only the last byte spans the unknown character
+                let bytes = normalized[range].as_bytes();
+                for (i, &byte) in bytes.iter().enumerate() {
+                    let piece = byte_piece(byte);
+                    encoding.ids.push(self.ids[&piece]);
+                    let last = i == bytes.len() - 1;
+                    encoding
+                        .tokens
+                        .push(token(piece, start, if last { end } else { start }));
+                }
+            } else {
+                encoding.ids.push(id);
+                encoding
+                    .tokens
+                    .push(token(normalized[range].to_owned(), start, end));
+            }
+        }
+        encoding
+    }
+
+    // Joins pieces along with their types, if they are in the model, decoding consecutive byte pieces as UTF-8
+    fn decode_pieces<'a>(
+        &self,
+        pieces: impl Iterator<Item = (&'a str, Option<PieceType>)>,
+    ) -> String {
+        let mut text = String::new();
+        let mut bytes = Vec::new();
+        let mut first = true;
+        for (piece, kind) in pieces {
+            if kind == Some(PieceType::Byte)
+                && let Some(byte) = piece
+                    .strip_prefix("<0x")
+                    .and_then(|hex| hex.strip_suffix('>'))
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+            {
+                bytes.push(byte);
+                first = false;
+                continue;
+            }
+            text.push_str(&String::from_utf8_lossy(&bytes));
+            bytes.clear();
+            match kind {
+                Some(PieceType::Control) => continue,
+                Some(PieceType::Unknown) => text.push_str(&self.model.unknown_surface),
+                _ => {
+                    let mut piece = piece;
+                    if first && self.strips_dummy_space() && !self.model.treat_whitespace_as_suffix
+                    {
+                        piece = piece.strip_prefix(SPACE_SYMBOL).unwrap_or(piece);
+                    }
+                    text.push_str(&piece.replace(SPACE_SYMBOL, " "));
+                }
+            }
+            first = false;
+        }
+        text.push_str(&String::from_utf8_lossy(&bytes));
+        if self.strips_dummy_space() && self.model.treat_whitespace_as_suffix {
+            text.truncate(text.strip_suffix(' ').unwrap_or(&text).len());
+        }
+        text
+    }
+
+    // Whether the space at the beginning (or end) of the text is removed when decoding, which was either added or
+    // would have been removed when normalizing
+    fn strips_dummy_space(&self) -> bool {
+        self.model.add_dummy_prefix || self.model.remove_extra_whitespaces
+    }
+}
+
+impl Tokenizer for UnigramTokenizer {
+    fn name(&self) -> &str {
+        "unigram"
+    }
+
+    fn tokenize_line(&self, line: &str) -> Vec<String> {
+        self.encode(line)
+            .tokens
+            .into_iter()
+            .map(|token| token.text)
+            .collect()
+    }
+
+    fn tokenize_spans(&self, line: &str) -> Vec<Token> {
+        self.encode(line).tokens
+    }
+}
+
+impl Detokenizer for UnigramTokenizer {
+    // Pieces out of the model, such as the text of unknown pieces, are kept as they are
+    fn detokenize_line(&self, tokens: &[String]) -> String {
+        self.decode_pieces(tokens.iter().map(|token| {
+            let kind = self.id(token).map(|id| self.model.pieces[id as usize].kind);
+            (token.as_str(), kind)
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn model(byte_fallback: bool) -> UnigramModel {
+        let mut pieces = vec![
+            Piece::new("<unk>", 0.0, PieceType::Unknown),
+            Piece::new("<s>", 0.0, PieceType::Control),
+            Piece::new("</s>", 0.0, PieceType::Control),
+            Piece::new("<sep>", 0.0, PieceType::UserDefined),
+        ];
+        if byte_fallback {
+            pieces.extend(
+                (0..=u8::MAX).map(|byte| Piece::new(&byte_piece(byte), 0.0, PieceType::Byte)),
+            );
+        }
+        for (piece, score) in [
+            ("▁", -2.0),
+            ("▁he", -3.0),
+            ("▁hell", -5.0),
+            ("llo", -4.0),
+            ("o", -3.0),
+            ("▁world", -4.0),
+            ("l", -3.5),
+            ("h", -4.0),
+            ("e", -4.0),
+            ("w", -4.0),
+        ] {
+            pieces.push(Piece::new(piece, score, PieceType::Normal));
+        }
+        UnigramModel::new(pieces).unwrap()
+    }
+
+    fn texts(encoding: &Encoding) -> Vec<&str> {
+        encoding
+            .tokens
+            .iter()
+            .map(|token| token.text.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn encode_and_decode() {
+        let tokenizer = UnigramTokenizer::new(&model(false));
+        // ▁he llo (-7) is more likely than ▁hell o (-8)
+        let encoding = tokenizer.encode("  hello  world<sep>");
+        assert_eq!(texts(&encoding), ["▁he", "llo", "▁world", "<sep>"]);
+        assert_eq!(encoding.ids, [5, 7, 9, 3]);
+        let spans: Vec<(usize, usize)> = encoding.tokens.iter().map(|t| (t.start, t.end)).collect();
+        assert_eq!(spans, [(2, 4), (4, 7), (7, 14), (14, 19)]);
+        assert_eq!(tokenizer.decode(&encoding.ids), "hello world<sep>");
+
+        // Unknown characters are single pieces
+        let encoding = tokenizer.encode("hé wé");
+        assert_eq!(texts(&encoding), ["▁", "h", "é", "▁", "w", "é"]);
+        assert_eq!(encoding.ids[2], 0);
+        assert_eq!(
+            (encoding.tokens[2].char_start, encoding.tokens[2].char_end),
+            (1, 2)
+        );
+        assert_eq!(tokenizer.decode(&encoding.ids), "h ⁇  w ⁇ ");
+        assert_eq!(
+            tokenizer.detokenize_line(&tokenizer.tokenize_line("hé wé")),
+            "hé wé"
+        );
+        assert_eq!(tokenizer.tokenize_line(" "), Vec::<String>::new());
+    }
+
+    #[test]
+    fn byte_fallback() {
+        let tokenizer = UnigramTokenizer::new(&model(true));
+        let encoding = tokenizer.encode("hé");
+        assert_eq!(texts(&encoding), ["▁", "h", "<0xC3>", "<0xA9>"]);
+        assert_eq!(encoding.ids[2], 4 + 0xc3);
+        let spans: Vec<(usize, usize)> = encoding.tokens.iter().map(|t| (t.start, t.end)).collect();
+        assert_eq!(spans, [(0, 0), (0, 1), (1, 1), (1, 3)]);
+        assert_eq!(tokenizer.decode(&encoding.ids), "hé");
+        assert_eq!(
+            tokenizer.detokenize_line(&tokenizer.tokenize_line("hé")),
+            "hé"
+        );
+    }
+
+    #[test]
+    fn sample() {
+        let tokenizer = UnigramTokenizer::new(&model(false));
+        let mut segmentations = HashSet::new();
+        for seed in 0..100 {
+            let encoding = tokenizer.sample("hello world", 0.5, seed);
+            assert_eq!(tokenizer.decode(&encoding.ids), "hello world");
+            segmentations.insert(encoding.ids);
+        }
+        assert!(segmentations.len() > 2);
+        assert_eq!(
+            tokenizer.sample("hello world", 0.5, 7),
+            tokenizer.sample("hello world", 0.5, 7)
+        );
+    }
+
+    #[test]
+    fn read_and_write_models() {
+        let model = model(true);
+        let mut written = Vec::new();
+        model.write(&mut written).unwrap();
+        assert_eq!(UnigramModel::read(written.as_slice()).unwrap(), model);
+
+        // Model with a BPE trainer spec (field 2, model type 2)
+        assert!(matches!(
+            UnigramModel::read([0x12, 0x02, 0x18, 0x02].as_slice()),
+            Err(Error::InvalidModel { path: None, message }) if message == "unsupported BPE model"
+        ));
+        assert!(matches!(
+            UnigramModel::new(vec![Piece::new("a", 0.0, PieceType::Normal)]),
+            Err(Error::InvalidModel { path: None, message }) if message == "no unknown piece"
+        ));
+        assert!(UnigramModel::read([0x0a, 0x05, 0x0a].as_slice()).is_err());
+    }
+}
@@ -0,0 +1,266 @@
+// Port of the `Lattice` of https://github.com/google/sentencepiece/blob/master/src/unigram_model.cc, with every
+// possible segmentation of a text into pieces
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct Node {
+    pub(super) start: usize,
+    pub(super) end: usize,
+    pub(super) id: u32,
+    pub(super) score: f32,
+}
+
+#[derive(Debug, Default)]
+pub(super) struct Lattice {
+    len: usize,
+    nodes: Vec<Node>,
+    // Indices of the nodes starting and ending at every byte position, in the order they were inserted
+    begin_nodes: Vec<Vec<usize>>,
+    end_nodes: Vec<Vec<usize>>,
+}
+
+impl Lattice {
+    // Empty lattice of a text of `len` bytes
+    pub(super) fn new(len: usize) -> Self {
+        Lattice {
+            len,
+            nodes: Vec::new(),
+            begin_nodes: vec![Vec::new(); len + 1],
+            end_nodes: vec![Vec::new(); len + 1],
+        }
+    }
+
+    pub(super) fn insert(&mut self, node: Node) {
+        self.begin_nodes[node.start].push(self.nodes.len());
+        self.end_nodes[node.end].push(self.nodes.len());
+        self.nodes.push(node);
+    }
+
+    // Best segmentation, leaving out the nodes rejected by `skip`, or `None` if there is none. Among segmentations with
+    // the same score, the one whose last pieces start first is chosen, like in the original implementation.
+    pub(super) fn viterbi(&self, skip: impl Fn(&Node) -> bool) -> Option<Vec<&Node>> {
+        // Best score of the segmentations ending with every node, along with the node before it
+        let mut best: Vec<Option<(f32, Option<usize>)>> = vec![None; self.nodes.len()];
+        for position in 0..self.len {
+            for &node in &self.begin_nodes[position] {
+                if skip(&self.nodes[node]) {
+                    continue;
+                }
+                let score = self.nodes[node].score;
+                best[node] = if position == 0 {
+                    Some((score, None))
+                } else {
+                    self.best_previous(position, &best)
+                        .map(|(previous, previous_score)| (previous_score + score, Some(previous)))
+                };
+            }
+        }
+        let (mut node, _) = self.best_previous(self.len, &best)?;
+        let mut path = vec![&self.nodes[node]];
+        while let Some((_, Some(previous))) = best[node] {
+            path.push(&self.nodes[previous]);
+            node = previous;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    // Node ending at `position` with the best segmentation, along with its score
+    fn best_previous(
+        &self,
+        position: usize,
+        best: &[Option<(f32, Option<usize>)>],
+    ) -> Option<(usize, f32)> {
+        let mut best_previous: Option<(usize, f32)> = None;
+        for &node in &self.end_nodes[position] {
+            if let Some((score, _)) = best[node]
+                && best_previous.is_none_or(|(_, best_score)| score > best_score)
+            {
+                best_previous = Some((node, score));
+            }
+        }
+        best_previous
+    }
+
+    // Log of the sum of the probabilities of the segmentations up to the start of every node, which are the
+    // exponentials of their scores multiplied by `inverse_temperature`, along with that of the whole text
+    fn forward(&self, inverse_temperature: f64) -> (Vec<f64>, f64) {
+        let mut alpha = vec![f64::NEG_INFINITY; self.nodes.len()];
+        let sum_at =
+            |position: usize, alpha: &[f64]| {
+                log_sum_exp(self.end_nodes[position].iter().map(|&node| {
+                    alpha[node] + inverse_temperature * f64::from(self.nodes[node].score)
+                }))
+            };
+        for position in 0..self.len {
+            let sum = if position == 0 {
+                0.0
+            } else {
+                sum_at(position, &alpha)
+            };
+            for &node in &self.begin_nodes[position] {
+                alpha[node] = sum;
+            }
+        }
+        let total = sum_at(self.len, &alpha);
+        (alpha, total)
+    }
+
+    // Log of the sum of the probabilities of the segmentations from the end of every node
+    fn backward(&self, inverse_temperature: f64) -> Vec<f64> {
+        let mut beta = vec![f64::NEG_INFINITY; self.nodes.len()];
+        for position in (1..=self.len).rev() {
+            let sum = if position == self.len {
+                0.0
+            } else {
+                log_sum_exp(self.begin_nodes[position].iter().map(|&node| {
+                    beta[node] + inverse_temperature * f64::from(self.nodes[node].score)
+                }))
+            };
+            for &node in &self.end_nodes[position] {
+                beta[node] = sum;
+            }
+        }
+        beta
+    }
+
+    // Adds the expected number of times every piece is found, for a text seen `frequency` times, to `expected`,
+    // leaving out the ids out of its range (unknown pieces). Returns the log-likelihood of the text times its
+    // frequency.
+    pub(super) fn add_marginals(&self, frequency: f64, expected: &mut [f64]) -> f64 {
+        let (alpha, total) = self.forward(1.0);
+        let beta = self.backward(1.0);
+        for (i, node) in self.nodes.iter().enumerate() {
+            if let Some(count) = expected.get_mut(node.id as usize) {
+                *count += frequency * (alpha[i] + f64::from(node.score) + beta[i] - total).exp();
+            }
+        }
+        frequency * total
+    }
+
+    // Segmentation sampled with a probability proportional to the exponential of its score multiplied by
+    // `inverse_temperature`, by forward-filtering and backward-sampling
+    pub(super) fn sample(&self, inverse_temperature: f64, rng: &mut SplitMix64) -> Vec<&Node> {
+        let (alpha, mut total) = self.forward(inverse_temperature);
+        let mut path = Vec::new();
+        let mut position = self.len;
+        while position > 0 {
+            let candidates = &self.end_nodes[position];
+            let mut threshold = rng.next_f64();
+            let mut chosen = candidates[candidates.len() - 1];
+            for &node in candidates {
+                let score = alpha[node] + inverse_temperature * f64::from(self.nodes[node].score);
+                threshold -= (score - total).exp();
+                if threshold < 0.0 {
+                    chosen = node;
+                    break;
+                }
+            }
+            path.push(&self.nodes[chosen]);
+            total = alpha[chosen];
+            position = self.nodes[chosen].start;
+        }
+        path.reverse();
+        path
+    }
+}
+
+fn log_sum_exp(values: impl Iterator<Item = f64>) -> f64 {
+    let values: Vec<f64> = values.collect();
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    if max == f64::NEG_INFINITY {
+        return max;
+    }
+    max + values
+        .iter()
+        .map(|value| (value - max).exp())
+        .sum::<f64>()
+        .ln()
+}
+
+// Small seedable generator of the random numbers of subword sampling (https://prng.di.unimi.it/splitmix64.c)
+#[derive(Debug, Clone)]
+pub(super) struct SplitMix64(u64);
+
+impl SplitMix64 {
+    pub(super) fn new(seed: u64) -> Self {
+        SplitMix64(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    // Uniform number in [0, 1)
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Lattice of "abc" with the pieces "a", "b", "c", "ab" and "bc"
+    fn lattice() -> Lattice {
+        let mut lattice = Lattice::new(3);
+        for (start, end, id, score) in [
+            (0, 1, 0, -1.0),
+            (0, 2, 3, -2.5),
+            (1, 2, 1, -1.0),
+            (1, 3, 4, -1.5),
+            (2, 3, 2, -1.0),
+        ] {
+            lattice.insert(Node {
+                start,
+                end,
+                id,
+                score,
+            });
+        }
+        lattice
+    }
+
+    fn ids(path: &[&Node]) -> Vec<u32> {
+        path.iter().map(|node| node.id).collect()
+    }
+
+    #[test]
+    fn viterbi() {
+        let lattice = lattice();
+        assert_eq!(ids(&lattice.viterbi(|_| false).unwrap()), [0, 4]);
+        assert_eq!(
+            ids(&lattice.viterbi(|node| node.id == 4).unwrap()),
+            [0, 1, 2]
+        );
+        assert_eq!(lattice.viterbi(|node| node.start == 0), None);
+    }
+
+    #[test]
+    fn marginals_and_sampling() {
+        let lattice = lattice();
+        let mut expected = vec![0.0; 5];
+        let likelihood = lattice.add_marginals(2.0, &mut expected);
+        // Segmentations: a b c (-3), a bc (-2.5), ab c (-3.5)
+        let total = [-3.0f64, -2.5, -3.5].iter().map(|s| s.exp()).sum::<f64>();
+        assert!((likelihood - 2.0 * total.ln()).abs() < 1e-9);
+        let probability = |score: f64| score.exp() / total;
+        assert!((expected[0] - 2.0 * (probability(-3.0) + probability(-2.5))).abs() < 1e-9);
+        assert!((expected[4] - 2.0 * probability(-2.5)).abs() < 1e-9);
+
+        let mut rng = SplitMix64::new(1);
+        let mut counts = [0; 3];
+        for _ in 0..3000 {
+            match ids(&lattice.sample(1.0, &mut rng))[..] {
+                [0, 1, 2] => counts[0] += 1,
+                [0, 4] => counts[1] += 1,
+                [3, 2] => counts[2] += 1,
+                _ => unreachable!(),
+            }
+        }
+        assert!(counts[1] > counts[0] && counts[0] > counts[2] && counts[2] > 0);
+    }
+}
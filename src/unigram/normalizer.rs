@@ -0,0 +1,235 @@
+use super::{SPACE_SYMBOL, Trie};
+
+// Port of https://github.com/google/sentencepiece/blob/master/src/normalizer.cc
+
+// Compiled normalization rules of a model (such as `nmt_nfkc`), made of a Darts double-array trie whose keys are the
+// UTF-8 sequences to replace and whose values are the offsets of their replacements, which follow the trie and are
+// terminated by NUL characters
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct PrecompiledCharsmap {
+    blob: Vec<u8>,
+    units: Vec<u32>,
+    replacements: String,
+}
+
+impl PrecompiledCharsmap {
+    pub(crate) fn new(blob: Vec<u8>) -> Result<Self, String> {
+        let invalid = || "invalid precompiled charsmap".to_owned();
+        let trie_size = blob
+            .get(..4)
+            .map(|size| u32::from_le_bytes(size.try_into().unwrap()) as usize)
+            .ok_or_else(invalid)?;
+        let trie = blob.get(4..4 + trie_size).ok_or_else(invalid)?;
+        if trie_size % 4 != 0 {
+            return Err(invalid());
+        }
+        let units = trie
+            .chunks_exact(4)
+            .map(|unit| u32::from_le_bytes(unit.try_into().unwrap()))
+            .collect();
+        let replacements =
+            String::from_utf8(blob[4 + trie_size..].to_vec()).map_err(|_| invalid())?;
+        Ok(PrecompiledCharsmap {
+            blob,
+            units,
+            replacements,
+        })
+    }
+
+    pub(super) fn blob(&self) -> &[u8] {
+        &self.blob
+    }
+
+    // Replacement of the longest key that is a prefix of `input`, along with the length of the key
+    pub(crate) fn longest_prefix(&self, input: &[u8]) -> Option<(&str, usize)> {
+        let unit = |position: usize| self.units.get(position).copied();
+        let offset = |unit: u32| ((unit >> 10) << ((unit & (1 << 9)) >> 6)) as usize;
+        let mut longest = None;
+        let mut position = offset(unit(0)?);
+        for (i, &byte) in input.iter().enumerate() {
+            position ^= byte as usize;
+            let node = unit(position)?;
+            // The label of a node is its byte, and leaves have their highest bit set so that they never match
+            if node & ((1 << 31) | 0xff) != byte as u32 {
+                break;
+            }
+            position ^= offset(node);
+            if (node >> 8) & 1 == 1 {
+                let value = (unit(position)? & ((1 << 31) - 1)) as usize;
+                longest = Some((value, i + 1));
+            }
+        }
+        let (value, length) = longest?;
+        let replacement = self.replacements.get(value..)?;
+        let end = replacement.find('\0').unwrap_or(replacement.len());
+        Some((&replacement[..end], length))
+    }
+}
+
+// Normalizes text before it is split into pieces, replacing spaces with `▁` and adding one at the beginning
+#[derive(Debug, Clone)]
+pub(super) struct Normalizer {
+    pub(super) charsmap: Option<PrecompiledCharsmap>,
+    // User-defined pieces, which are never normalized
+    pub(super) user_defined: Trie,
+    pub(super) add_dummy_prefix: bool,
+    pub(super) remove_extra_whitespaces: bool,
+    pub(super) escape_whitespaces: bool,
+    pub(super) treat_whitespace_as_suffix: bool,
+}
+
+impl Normalizer {
+    // Normalized text, along with the byte offset into `input` of every byte of the normalized text and of its end
+    pub(super) fn normalize(&self, mut input: &str) -> (String, Vec<usize>) {
+        let mut normalized = String::with_capacity(input.len() * 3 / 2);
+        let mut offsets = Vec::with_capacity(input.len() * 3 / 2);
+        let mut consumed = 0;
+        if self.remove_extra_whitespaces {
+            while !input.is_empty() {
+                let (replacement, length) = self.normalize_prefix(input);
+                if replacement != " " {
+                    break;
+                }
+                input = &input[length..];
+                consumed += length;
+            }
+        }
+        if input.is_empty() {
+            offsets.push(consumed);
+            return (normalized, offsets);
+        }
+
+        let space = if self.escape_whitespaces {
+            SPACE_SYMBOL
+        } else {
+            " "
+        };
+        let push_space = |normalized: &mut String, offsets: &mut Vec<usize>, consumed: usize| {
+            normalized.push_str(space);
+            offsets.extend(std::iter::repeat_n(consumed, space.len()));
+        };
+        if self.add_dummy_prefix && !self.treat_whitespace_as_suffix {
+            push_space(&mut normalized, &mut offsets, consumed);
+        }
+        let mut is_previous_space = self.remove_extra_whitespaces;
+        while !input.is_empty() {
+            let (mut replacement, length) = self.normalize_prefix(input);
+            if is_previous_space {
+                replacement = replacement.trim_start_matches(' ');
+            }
+            if !replacement.is_empty() {
+                for ch in replacement.chars() {
+                    if ch == ' ' {
+                        push_space(&mut normalized, &mut offsets, consumed);
+                    } else {
+                        normalized.push(ch);
+                        offsets.extend(std::iter::repeat_n(consumed, ch.len_utf8()));
+                    }
+                }
+                is_previous_space = replacement.ends_with(' ');
+            }
+            consumed += length;
+            input = &input[length..];
+            if !self.remove_extra_whitespaces {
+                is_previous_space = false;
+            }
+        }
+        if self.remove_extra_whitespaces {
+            while normalized.ends_with(space) {
+                let length = normalized.len() - space.len();
+                consumed = offsets[length];
+                normalized.truncate(length);
+                offsets.truncate(length);
+            }
+        }
+        if self.add_dummy_prefix && self.treat_whitespace_as_suffix {
+            push_space(&mut normalized, &mut offsets, consumed);
+        }
+        offsets.push(consumed);
+        (normalized, offsets)
+    }
+
+    // Replacement of the beginning of `input` along with the number of bytes it replaces, which is a user-defined
+    // piece as it is, the longest rule of the charsmap or else the first character as it is
+    fn normalize_prefix<'a>(&'a self, input: &'a str) -> (&'a str, usize) {
+        if let Some((length, _)) = self.user_defined.common_prefixes(input.as_bytes()).last() {
+            return (&input[..length], length);
+        }
+        if let Some(replacement) = self
+            .charsmap
+            .as_ref()
+            .and_then(|charsmap| charsmap.longest_prefix(input.as_bytes()))
+        {
+            return replacement;
+        }
+        let length = input.chars().next().unwrap().len_utf8();
+        (&input[..length], length)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Double array with a single rule replacing "A" with "aa"
+    fn charsmap() -> PrecompiledCharsmap {
+        let mut units = vec![0u32; 66];
+        // The root points to 1, whose child for "A" is 1 ^ 0x41 = 0x40, which has a leaf at 0x40 ^ 1
+        units[0] = 1 << 10;
+        units[0x40] = (1 << 10) | (1 << 8) | 0x41;
+        units[0x41] = 1 << 31;
+        let mut blob = ((units.len() * 4) as u32).to_le_bytes().to_vec();
+        for unit in units {
+            blob.extend_from_slice(&unit.to_le_bytes());
+        }
+        blob.extend_from_slice(b"aa\0");
+        PrecompiledCharsmap::new(blob).unwrap()
+    }
+
+    fn normalizer() -> Normalizer {
+        Normalizer {
+            charsmap: None,
+            user_defined: Trie::default(),
+            add_dummy_prefix: true,
+            remove_extra_whitespaces: true,
+            escape_whitespaces: true,
+            treat_whitespace_as_suffix: false,
+        }
+    }
+
+    #[test]
+    fn whitespaces() {
+        let normalizer = normalizer();
+        let (normalized, offsets) = normalizer.normalize("  a  bc ");
+        assert_eq!(normalized, "▁a▁bc");
+        assert_eq!(offsets, [2, 2, 2, 2, 3, 3, 3, 5, 6, 7]);
+        assert_eq!(normalizer.normalize("   "), (String::new(), vec![3]));
+
+        let normalizer = Normalizer {
+            remove_extra_whitespaces: false,
+            escape_whitespaces: false,
+            ..self::normalizer()
+        };
+        assert_eq!(normalizer.normalize("a  b ").0, " a  b ");
+        let normalizer = Normalizer {
+            treat_whitespace_as_suffix: true,
+            ..self::normalizer()
+        };
+        assert_eq!(normalizer.normalize(" a b ").0, "a▁b▁");
+    }
+
+    #[test]
+    fn precompiled_charsmap() {
+        let mut user_defined = Trie::default();
+        user_defined.insert("AB", 0);
+        let normalizer = Normalizer {
+            charsmap: Some(charsmap()),
+            user_defined,
+            ..normalizer()
+        };
+        let (normalized, offsets) = normalizer.normalize("AbAB");
+        assert_eq!(normalized, "▁aabAB");
+        assert_eq!(offsets, [0, 0, 0, 0, 0, 1, 2, 2, 4]);
+        assert!(PrecompiledCharsmap::new(vec![8, 0, 0, 0, 1]).is_err());
+    }
+}
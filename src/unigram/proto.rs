@@ -0,0 +1,157 @@
+// Minimal reader and writer of the protocol buffers wire format, enough for the `ModelProto` messages of
+// https://github.com/google/sentencepiece/blob/master/src/sentencepiece_model.proto
+
+const VARINT: u8 = 0;
+const FIXED64: u8 = 1;
+const LENGTH_DELIMITED: u8 = 2;
+const FIXED32: u8 = 5;
+
+// Value of a field, as found in the wire format
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) enum Value<'a> {
+    Varint(u64),
+    Fixed64(u64),
+    Bytes(&'a [u8]),
+    Fixed32(u32),
+}
+
+impl<'a> Value<'a> {
+    pub(super) fn as_u64(self) -> Result<u64, String> {
+        match self {
+            Value::Varint(value) => Ok(value),
+            _ => Err("expected a varint".to_owned()),
+        }
+    }
+
+    pub(super) fn as_bool(self) -> Result<bool, String> {
+        self.as_u64().map(|value| value != 0)
+    }
+
+    pub(super) fn as_f32(self) -> Result<f32, String> {
+        match self {
+            Value::Fixed32(value) => Ok(f32::from_bits(value)),
+            _ => Err("expected a 32-bit float".to_owned()),
+        }
+    }
+
+    pub(super) fn as_bytes(self) -> Result<&'a [u8], String> {
+        match self {
+            Value::Bytes(bytes) => Ok(bytes),
+            _ => Err("expected a length-delimited field".to_owned()),
+        }
+    }
+
+    pub(super) fn as_str(self) -> Result<&'a str, String> {
+        std::str::from_utf8(self.as_bytes()?).map_err(|_| "invalid UTF-8 in a string".to_owned())
+    }
+}
+
+// Fields of a message, in the order they are found
+pub(super) struct Reader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub(super) fn new(data: &'a [u8]) -> Self {
+        Reader { data, position: 0 }
+    }
+
+    // Number and value of the next field, or `None` at the end of the message
+    pub(super) fn next_field(&mut self) -> Result<Option<(u32, Value<'a>)>, String> {
+        if self.position == self.data.len() {
+            return Ok(None);
+        }
+        let key = self.varint()?;
+        let number = u32::try_from(key >> 3).map_err(|_| "invalid field number".to_owned())?;
+        let value = match (key & 7) as u8 {
+            VARINT => Value::Varint(self.varint()?),
+            FIXED64 => Value::Fixed64(u64::from_le_bytes(self.take(8)?.try_into().unwrap())),
+            LENGTH_DELIMITED => {
+                let length = usize::try_from(self.varint()?)
+                    .map_err(|_| "invalid field length".to_owned())?;
+                Value::Bytes(self.take(length)?)
+            }
+            FIXED32 => Value::Fixed32(u32::from_le_bytes(self.take(4)?.try_into().unwrap())),
+            wire_type => return Err(format!("unsupported wire type {wire_type}")),
+        };
+        Ok(Some((number, value)))
+    }
+
+    fn varint(&mut self) -> Result<u64, String> {
+        let mut value = 0;
+        for shift in (0..64).step_by(7) {
+            let byte = self.take(1)?[0];
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err("varint too long".to_owned())
+    }
+
+    fn take(&mut self, length: usize) -> Result<&'a [u8], String> {
+        let end = self
+            .position
+            .checked_add(length)
+            .filter(|&end| end <= self.data.len())
+            .ok_or_else(|| "truncated message".to_owned())?;
+        let bytes = &self.data[self.position..end];
+        self.position = end;
+        Ok(bytes)
+    }
+}
+
+// Serialized message, to which fields are appended
+#[derive(Debug, Default)]
+pub(super) struct Writer {
+    buffer: Vec<u8>,
+}
+
+impl Writer {
+    pub(super) fn varint(&mut self, number: u32, value: u64) {
+        self.key(number, VARINT);
+        self.raw_varint(value);
+    }
+
+    // Negative values take 10 bytes, like the `int32` fields of the original implementation
+    pub(super) fn int32(&mut self, number: u32, value: i32) {
+        self.varint(number, i64::from(value) as u64);
+    }
+
+    pub(super) fn bool(&mut self, number: u32, value: bool) {
+        self.varint(number, u64::from(value));
+    }
+
+    pub(super) fn f32(&mut self, number: u32, value: f32) {
+        self.key(number, FIXED32);
+        self.buffer
+            .extend_from_slice(&value.to_bits().to_le_bytes());
+    }
+
+    pub(super) fn bytes(&mut self, number: u32, value: &[u8]) {
+        self.key(number, LENGTH_DELIMITED);
+        self.raw_varint(value.len() as u64);
+        self.buffer.extend_from_slice(value);
+    }
+
+    pub(super) fn message(&mut self, number: u32, message: &Writer) {
+        self.bytes(number, &message.buffer);
+    }
+
+    pub(super) fn into_bytes(self) -> Vec<u8> {
+        self.buffer
+    }
+
+    fn key(&mut self, number: u32, wire_type: u8) {
+        self.raw_varint((u64::from(number) << 3) | u64::from(wire_type));
+    }
+
+    fn raw_varint(&mut self, mut value: u64) {
+        while value >= 0x80 {
+            self.buffer.push((value as u8) | 0x80);
+            value >>= 7;
+        }
+        self.buffer.push(value as u8);
+    }
+}
@@ -0,0 +1,638 @@
+use super::normalizer::Normalizer;
+use super::{Piece, PieceType, SPACE_SYMBOL, Segmenter, Trie, UnigramModel, byte_piece};
+use crate::error::{Error, Result};
+use crate::stream::{fold_lines_parallel, merge_counts};
+use rayon::prelude::*;
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+
+// Port of the training of https://github.com/google/sentencepiece/blob/master/src/unigram_model_trainer.cc
+
+include!(concat!(env!("OUT_DIR"), "/scripts.rs"));
+
+// Longest sentence used for training in bytes, like the `max_sentence_length` option of `spm_train`
+const MAX_SENTENCE_LENGTH: usize = 4192;
+
+// Character replacing the characters left out by the character coverage
+const UNKNOWN_CHAR: char = '\u{2047}';
+
+// Pieces whose expected frequency is lower are dropped by the M-step
+const EXPECTED_FREQUENCY_THRESHOLD: f64 = 0.5;
+
+// Decrement of the scores of the required characters missing from the final pieces
+const MIN_SCORE_PENALTY_DELTA: f32 = 0.0001;
+
+// Index of the inherited script in `SCRIPTS`, whose characters (such as combining marks) take the script of the
+// character before them
+const INHERITED_SCRIPT: u8 = 1;
+
+// Script of the characters of the scripts that are not in `SCRIPTS`
+const OTHER_SCRIPT: u8 = u8::MAX;
+
+/// Trains Unigram models from raw text, like `spm_train --model_type=unigram`. Sentences are only normalized by the
+/// handling of whitespace (the `identity` rules) and split into words starting with `▁`. Pieces are then found with
+/// the EM algorithm, pruning the least useful ones until the vocabulary has the requested size.
+#[derive(Debug, Clone)]
+pub struct UnigramTrainer {
+    vocab_size: usize,
+    character_coverage: f64,
+    max_piece_length: usize,
+    seed_size: usize,
+    shrinking_factor: f64,
+    num_sub_iterations: usize,
+    user_defined_symbols: Vec<String>,
+    byte_fallback: bool,
+}
+
+impl UnigramTrainer {
+    /// Trainer of models with up to `vocab_size` pieces, counting `<unk>`, `<s>`, `</s>`, the user-defined symbols
+    /// and the byte pieces. Models are smaller when the corpus does not have enough pieces.
+    pub fn new(vocab_size: usize) -> Self {
+        UnigramTrainer {
+            vocab_size,
+            character_coverage: 0.9995,
+            max_piece_length: 16,
+            seed_size: 1_000_000,
+            shrinking_factor: 0.75,
+            num_sub_iterations: 2,
+            user_defined_symbols: Vec::new(),
+            byte_fallback: false,
+        }
+    }
+
+    /// Fraction of the characters of the corpus that are pieces (0.9995 by default), leaving out the rarest ones
+    pub fn character_coverage(mut self, character_coverage: f64) -> Self {
+        self.character_coverage = character_coverage;
+        self
+    }
+
+    /// Length of the longest pieces in characters (16 by default)
+    pub fn max_piece_length(mut self, max_piece_length: usize) -> Self {
+        self.max_piece_length = max_piece_length;
+        self
+    }
+
+    /// Number of pieces that training starts from (1,000,000 by default)
+    pub fn seed_size(mut self, seed_size: usize) -> Self {
+        self.seed_size = seed_size;
+        self
+    }
+
+    /// Fraction of the pieces kept by every pruning step (0.75 by default)
+    pub fn shrinking_factor(mut self, shrinking_factor: f64) -> Self {
+        self.shrinking_factor = shrinking_factor;
+        self
+    }
+
+    /// Number of EM iterations before every pruning step (2 by default)
+    pub fn num_sub_iterations(mut self, num_sub_iterations: usize) -> Self {
+        self.num_sub_iterations = num_sub_iterations;
+        self
+    }
+
+    /// Pieces that are always produced when found in the text, which other pieces never overlap
+    pub fn user_defined_symbols(mut self, user_defined_symbols: Vec<String>) -> Self {
+        self.user_defined_symbols = user_defined_symbols;
+        self
+    }
+
+    /// Adds the 256 byte pieces, which unknown characters are split into instead of `<unk>` (disabled by default)
+    pub fn byte_fallback(mut self, byte_fallback: bool) -> Self {
+        self.byte_fallback = byte_fallback;
+        self
+    }
+
+    pub fn train(&self, text: &str) -> Result<UnigramModel> {
+        let normalizer = self.normalizer();
+        let counts = text
+            .par_lines()
+            .fold(HashMap::new, |mut counts, line| {
+                count_words(&normalizer, line, &mut counts);
+                counts
+            })
+            .reduce(HashMap::new, merge_counts);
+        self.train_counts(counts)
+    }
+
+    pub fn train_reader(&self, reader: impl BufRead) -> Result<UnigramModel> {
+        let normalizer = self.normalizer();
+        let counts = fold_lines_parallel(
+            reader,
+            |counts, line| count_words(&normalizer, line, counts),
+            merge_counts,
+        )?;
+        self.train_counts(counts)
+    }
+
+    pub fn train_file(&self, corpus_file_path: &str) -> Result<UnigramModel> {
+        let reader = BufReader::new(
+            File::open(corpus_file_path).map_err(|e| Error::io(corpus_file_path, e))?,
+        );
+        self.train_reader(reader)
+            .map_err(|e| e.with_path(corpus_file_path))
+    }
+
+    // Normalizer of the training corpus, which keeps the user-defined symbols as they are
+    fn normalizer(&self) -> Normalizer {
+        let mut user_defined = Trie::default();
+        for (i, symbol) in self.user_defined_symbols.iter().enumerate() {
+            user_defined.insert(symbol, i as u32);
+        }
+        Normalizer {
+            charsmap: None,
+            user_defined,
+            add_dummy_prefix: true,
+            remove_extra_whitespaces: true,
+            escape_whitespaces: true,
+            treat_whitespace_as_suffix: false,
+        }
+    }
+
+    // Trains a model from the number of times every normalized word has been seen
+    fn train_counts(&self, counts: HashMap<String, u64>) -> Result<UnigramModel> {
+        // Characters that must be pieces, the most frequent first, until they cover enough of the corpus
+        let mut char_counts: HashMap<char, u64> = HashMap::new();
+        for (word, &count) in &counts {
+            for ch in word.chars().filter(|&ch| ch != UNKNOWN_CHAR && ch != '\0') {
+                *char_counts.entry(ch).or_default() += count;
+            }
+        }
+        let total = char_counts.values().sum::<u64>() as f64;
+        let mut sorted_chars: Vec<(char, u64)> = char_counts.into_iter().collect();
+        sorted_chars.sort_unstable_by_key(|&(ch, count)| (Reverse(count), ch));
+        let mut required_chars = Vec::new();
+        let mut accumulated = 0;
+        for (ch, count) in sorted_chars {
+            if accumulated as f64 / total >= self.character_coverage {
+                break;
+            }
+            accumulated += count;
+            required_chars.push((ch, count));
+        }
+        let meta_size =
+            3 + self.user_defined_symbols.len() + if self.byte_fallback { 256 } else { 0 };
+        if self.vocab_size < meta_size + required_chars.len() {
+            return Err(Error::InvalidModel {
+                path: None,
+                message: format!(
+                    "the vocabulary size {} is smaller than the {} meta pieces and required characters",
+                    self.vocab_size,
+                    meta_size + required_chars.len()
+                ),
+            });
+        }
+
+        let required: HashSet<char> = required_chars.iter().map(|&(ch, _)| ch).collect();
+        let mut words: HashMap<String, u64> = HashMap::new();
+        for (word, count) in counts {
+            let word = word
+                .chars()
+                .map(|ch| {
+                    if required.contains(&ch) {
+                        ch
+                    } else {
+                        UNKNOWN_CHAR
+                    }
+                })
+                .collect();
+            *words.entry(word).or_default() += count;
+        }
+        let mut words: Vec<(String, u64)> = words.into_iter().collect();
+        words.sort_unstable();
+
+        let desired_size = self.vocab_size * 11 / 10;
+        let mut pieces = self.seed_pieces(&words, &required_chars);
+        loop {
+            for _ in 0..self.num_sub_iterations {
+                let expected = expected_frequencies(&pieces, &words);
+                pieces = maximize(&pieces, &expected);
+            }
+            if pieces.len() <= desired_size {
+                break;
+            }
+            let pruned = self.prune(&pieces, &words, desired_size);
+            if pruned.len() == pieces.len() {
+                break;
+            }
+            pieces = pruned;
+        }
+
+        let mut model_pieces = vec![
+            Piece::new("<unk>", 0.0, PieceType::Unknown),
+            Piece::new("<s>", 0.0, PieceType::Control),
+            Piece::new("</s>", 0.0, PieceType::Control),
+        ];
+        for symbol in &self.user_defined_symbols {
+            model_pieces.push(Piece::new(symbol, 0.0, PieceType::UserDefined));
+        }
+        if self.byte_fallback {
+            model_pieces.extend(
+                (0..=u8::MAX).map(|byte| Piece::new(&byte_piece(byte), 0.0, PieceType::Byte)),
+            );
+        }
+        model_pieces.extend(self.finalize(&pieces, &required_chars, meta_size));
+        UnigramModel::new(model_pieces)
+    }
+
+    // Initial pieces, which are the required characters and the most frequent valid substrings of words, scored by
+    // their log probabilities. Since pieces never span words, substrings are counted in words rather than in the
+    // sentences of the suffix array of the original implementation.
+    fn seed_pieces(&self, words: &[(String, u64)], required_chars: &[(char, u64)]) -> Vec<Piece> {
+        let substrings = words
+            .par_iter()
+            .fold(
+                HashMap::new,
+                |mut substrings: HashMap<&str, u64>, (word, count)| {
+                    let boundaries: Vec<usize> = word
+                        .char_indices()
+                        .map(|(position, _)| position)
+                        .chain([word.len()])
+                        .collect();
+                    let chars: Vec<char> = word.chars().collect();
+                    for start in 0..chars.len() {
+                        let end = chars.len().min(start + self.max_piece_length);
+                        // Substrings starting at `start`, which stay invalid once they are
+                        let valid_len = valid_prefix_len(&chars[start..end]);
+                        for end in start + 2..=start + valid_len {
+                            *substrings
+                                .entry(&word[boundaries[start]..boundaries[end]])
+                                .or_default() += count;
+                        }
+                    }
+                    substrings
+                },
+            )
+            .reduce(HashMap::new, |mut substrings, other_substrings| {
+                for (substring, count) in other_substrings {
+                    *substrings.entry(substring).or_default() += count;
+                }
+                substrings
+            });
+
+        let chars: Vec<String> = required_chars
+            .iter()
+            .map(|(ch, _)| ch.to_string())
+            .collect();
+        let mut seeds: Vec<(&str, u64)> = substrings
+            .into_iter()
+            .filter(|&(_, count)| count >= 2)
+            .map(|(substring, count)| (substring, count * substring.chars().count() as u64))
+            .chain(
+                chars
+                    .iter()
+                    .zip(required_chars)
+                    .map(|(ch, &(_, count))| (ch.as_str(), count)),
+            )
+            .collect();
+        seeds.sort_unstable_by_key(|&(piece, score)| (Reverse(score), piece));
+        seeds.truncate(self.seed_size);
+        let log_sum = (seeds.iter().map(|&(_, score)| score).sum::<u64>() as f64).ln();
+        seeds
+            .into_iter()
+            .map(|(piece, score)| {
+                Piece::new(
+                    piece,
+                    ((score as f64).ln() - log_sum) as f32,
+                    PieceType::Normal,
+                )
+            })
+            .collect()
+    }
+
+    // Keeps the pieces whose removal would reduce the likelihood of the corpus the most, along with those that
+    // cannot be split into other pieces, until there are either `desired_size` pieces or the shrinking factor of them
+    fn prune(&self, pieces: &[Piece], words: &[(String, u64)], desired_size: usize) -> Vec<Piece> {
+        let segmenter = Segmenter::new(pieces, pieces.len() as u32);
+        // Pieces that the segmentation of every piece becomes without it, or `None` if its best segmentation
+        // already is made of other pieces
+        let alternatives: Vec<Option<Vec<u32>>> = pieces
+            .par_iter()
+            .enumerate()
+            .map(|(id, piece)| {
+                let lattice = segmenter.lattice(&piece.piece);
+                if lattice.viterbi(|_| false)?.len() >= 2 {
+                    return None;
+                }
+                let alternative = lattice
+                    .viterbi(|node| {
+                        node.id == id as u32 && node.end - node.start == piece.piece.len()
+                    })
+                    .unwrap_or_default();
+                Some(alternative.iter().map(|node| node.id).collect())
+            })
+            .collect();
+
+        // Frequency of every piece in the best segmentation of the corpus, along with that of the words it is in
+        let segmentations: Vec<Vec<u32>> = words
+            .par_iter()
+            .map(|(word, _)| {
+                segmenter
+                    .viterbi(word)
+                    .into_iter()
+                    .map(|(_, id)| id)
+                    .collect()
+            })
+            .collect();
+        let mut frequencies = vec![0.0; pieces.len()];
+        let mut word_frequencies = vec![0.0; pieces.len()];
+        let mut total_word_frequency = 0.0;
+        for ((_, count), segmentation) in words.iter().zip(&segmentations) {
+            let count = *count as f64;
+            total_word_frequency += count;
+            for &id in segmentation
+                .iter()
+                .filter(|&&id| (id as usize) < pieces.len())
+            {
+                frequencies[id as usize] += count;
+                word_frequencies[id as usize] += count;
+            }
+        }
+        let sum: f64 = frequencies.iter().sum();
+        let log_sum = sum.ln();
+
+        let mut kept = Vec::new();
+        let mut candidates = Vec::new();
+        for (id, alternative) in alternatives.iter().enumerate() {
+            let frequency = frequencies[id];
+            match alternative {
+                _ if frequency == 0.0 => {}
+                None => {}
+                Some(alternative) if alternative.is_empty() => kept.push(pieces[id].clone()),
+                Some(alternative) => {
+                    // Likelihood of the words with the piece, and with its frequency moved to its alternatives
+                    let log_probability = frequency.ln() - log_sum;
+                    let log_sum_alternative =
+                        (sum + frequency * (alternative.len() - 1) as f64).ln();
+                    let log_probability_alternative: f64 = alternative
+                        .iter()
+                        .filter(|&&other| (other as usize) < pieces.len())
+                        .map(|&other| {
+                            (frequencies[other as usize] + frequency).ln() - log_sum_alternative
+                        })
+                        .sum();
+                    let loss = word_frequencies[id] / total_word_frequency
+                        * (log_probability - log_probability_alternative);
+                    candidates.push((id, loss));
+                }
+            }
+        }
+        let pruned_size = desired_size.max((self.shrinking_factor * pieces.len() as f64) as usize);
+        candidates.sort_by(|(id, loss), (other_id, other_loss)| {
+            other_loss.total_cmp(loss).then(id.cmp(other_id))
+        });
+        for (id, _) in candidates {
+            if kept.len() >= pruned_size {
+                break;
+            }
+            kept.push(pieces[id].clone());
+        }
+        kept
+    }
+
+    // Pieces of the model, which are the required characters and then the best pieces, sorted by their scores
+    fn finalize(
+        &self,
+        pieces: &[Piece],
+        required_chars: &[(char, u64)],
+        meta_size: usize,
+    ) -> Vec<Piece> {
+        let scores: HashMap<&str, f32> = pieces
+            .iter()
+            .map(|piece| (piece.piece.as_str(), piece.score))
+            .collect();
+        let min_score = pieces.iter().map(|piece| piece.score).fold(0.0, f32::min);
+        let mut final_pieces: HashMap<String, f32> = HashMap::new();
+        let mut penalty = 0.0;
+        for (ch, _) in required_chars {
+            let ch = ch.to_string();
+            let score = match scores.get(ch.as_str()) {
+                Some(&score) => score,
+                None => {
+                    penalty += MIN_SCORE_PENALTY_DELTA;
+                    min_score - penalty
+                }
+            };
+            final_pieces.insert(ch, score);
+        }
+        let mut sorted: Vec<&Piece> = pieces.iter().collect();
+        sorted.sort_by(|a, b| b.score.total_cmp(&a.score).then(a.piece.cmp(&b.piece)));
+        for piece in sorted {
+            if final_pieces.len() >= self.vocab_size - meta_size {
+                break;
+            }
+            final_pieces
+                .entry(piece.piece.clone())
+                .or_insert(piece.score);
+        }
+        let mut final_pieces: Vec<Piece> = final_pieces
+            .into_iter()
+            .map(|(piece, score)| Piece::new(&piece, score, PieceType::Normal))
+            .collect();
+        final_pieces.sort_by(|a, b| b.score.total_cmp(&a.score).then(a.piece.cmp(&b.piece)));
+        final_pieces
+    }
+}
+
+// Counts the words of a normalized sentence, which start with `▁` and are also split by user-defined symbols
+fn count_words(normalizer: &Normalizer, line: &str, counts: &mut HashMap<String, u64>) {
+    if line.len() > MAX_SENTENCE_LENGTH {
+        return;
+    }
+    let (normalized, _) = normalizer.normalize(line);
+    let mut add = |word: &str| {
+        if !word.is_empty() {
+            *counts.entry(word.to_owned()).or_default() += 1;
+        }
+    };
+    let mut word_start = 0;
+    let mut position = 0;
+    while position < normalized.len() {
+        let rest = &normalized[position..];
+        if let Some((length, _)) = normalizer
+            .user_defined
+            .common_prefixes(rest.as_bytes())
+            .last()
+        {
+            add(&normalized[word_start..position]);
+            position += length;
+            word_start = position;
+            continue;
+        }
+        if rest.starts_with(SPACE_SYMBOL) {
+            add(&normalized[word_start..position]);
+            word_start = position;
+        }
+        position += rest.chars().next().unwrap().len_utf8();
+    }
+    add(&normalized[word_start..]);
+}
+
+// Number of characters of the longest valid piece at the beginning of `chars`. Pieces cannot contain unknown
+// characters, can only start with `▁` and cannot mix scripts.
+fn valid_prefix_len(chars: &[char]) -> usize {
+    let mut previous_script = None;
+    for (i, &ch) in chars.iter().enumerate() {
+        match ch {
+            UNKNOWN_CHAR | '\0' | ' ' => return i,
+            '\u{2581}' if i > 0 => return i,
+            '\u{2581}' => {}
+            _ => {
+                let mut script = script(ch);
+                if let Some(previous_script) = previous_script {
+                    if script == INHERITED_SCRIPT {
+                        script = previous_script;
+                    } else if script != previous_script {
+                        return i;
+                    }
+                }
+                previous_script = Some(script);
+            }
+        }
+    }
+    chars.len()
+}
+
+fn script(ch: char) -> u8 {
+    let i = SCRIPTS.partition_point(|&(start, _, _)| start <= ch);
+    match i.checked_sub(1).map(|i| SCRIPTS[i]) {
+        Some((_, end, script)) if ch <= end => script,
+        _ => OTHER_SCRIPT,
+    }
+}
+
+// Expected number of times every piece is found in the words
+fn expected_frequencies(pieces: &[Piece], words: &[(String, u64)]) -> Vec<f64> {
+    let segmenter = Segmenter::new(pieces, pieces.len() as u32);
+    words
+        .par_iter()
+        .fold(
+            || vec![0.0; pieces.len()],
+            |mut expected, (word, count)| {
+                segmenter
+                    .lattice(word)
+                    .add_marginals(*count as f64, &mut expected);
+                expected
+            },
+        )
+        .reduce(
+            || vec![0.0; pieces.len()],
+            |mut expected, other_expected| {
+                for (frequency, other_frequency) in expected.iter_mut().zip(other_expected) {
+                    *frequency += other_frequency;
+                }
+                expected
+            },
+        )
+}
+
+// M-step, which drops the pieces that are not expected to be found and scores the rest with Bayesian smoothing
+fn maximize(pieces: &[Piece], expected: &[f64]) -> Vec<Piece> {
+    let kept: Vec<(&Piece, f64)> = pieces
+        .iter()
+        .zip(expected.iter().copied())
+        .filter(|&(_, frequency)| frequency >= EXPECTED_FREQUENCY_THRESHOLD)
+        .collect();
+    let log_sum = digamma(kept.iter().map(|&(_, frequency)| frequency).sum());
+    kept.into_iter()
+        .map(|(piece, frequency)| {
+            Piece::new(
+                &piece.piece,
+                (digamma(frequency) - log_sum) as f32,
+                PieceType::Normal,
+            )
+        })
+        .collect()
+}
+
+fn digamma(mut x: f64) -> f64 {
+    let mut result = 0.0;
+    while x < 7.0 {
+        result -= 1.0 / x;
+        x += 1.0;
+    }
+    x -= 0.5;
+    let xx = 1.0 / x;
+    let xx2 = xx * xx;
+    let xx4 = xx2 * xx2;
+    result + x.ln() + xx2 / 24.0 - 7.0 / 960.0 * xx4 + 31.0 / 8064.0 * xx4 * xx2
+        - 127.0 / 30720.0 * xx4 * xx4
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tokenizer::Tokenizer;
+    use crate::unigram::UnigramTokenizer;
+
+    const CORPUS: &str = "the cat sat on the mat\nthe dog sat on the log\nthe cat and the dog\n\
+                          a cat is not a dog\nthe mat is on the log\n";
+
+    #[test]
+    fn train() {
+        let corpus = CORPUS.repeat(20);
+        let model = UnigramTrainer::new(40).train(&corpus).unwrap();
+        let pieces = model.pieces();
+        assert!(pieces.len() <= 40);
+        assert_eq!(pieces[0], Piece::new("<unk>", 0.0, PieceType::Unknown));
+        assert_eq!(pieces[2].piece, "</s>");
+        for ch in ["▁", "t", "h", "e", "c", "a", "g"] {
+            assert!(pieces.iter().any(|piece| piece.piece == ch), "{ch}");
+        }
+        assert!(pieces[3..].windows(2).all(|w| w[0].score >= w[1].score));
+        assert!(pieces.iter().any(|piece| piece.piece == "▁the"));
+
+        let tokenizer = UnigramTokenizer::new(&model);
+        let encoding = tokenizer.encode("the cat sat on the dog");
+        assert_eq!(tokenizer.decode(&encoding.ids), "the cat sat on the dog");
+        assert_eq!(tokenizer.tokenize_line("the cat")[0], "▁the");
+        assert_eq!(
+            UnigramTrainer::new(40)
+                .train_reader(corpus.as_bytes())
+                .unwrap(),
+            model
+        );
+
+        assert!(matches!(
+            UnigramTrainer::new(10).train(&corpus),
+            Err(Error::InvalidModel { path: None, .. })
+        ));
+    }
+
+    #[test]
+    fn user_defined_symbols_and_byte_fallback() {
+        let corpus = CORPUS.replace(" on ", " <sep> on ").repeat(20);
+        let model = UnigramTrainer::new(300)
+            .user_defined_symbols(vec!["<sep>".to_owned()])
+            .byte_fallback(true)
+            .train(&corpus)
+            .unwrap();
+        assert_eq!(
+            model.pieces()[3],
+            Piece::new("<sep>", 0.0, PieceType::UserDefined)
+        );
+        assert_eq!(model.pieces()[4].piece, "<0x00>");
+        assert!(
+            !model.pieces()[260..]
+                .iter()
+                .any(|piece| piece.piece.contains('<'))
+        );
+
+        let tokenizer = UnigramTokenizer::new(&model);
+        let tokens = tokenizer.tokenize_line("the<sep>cat é");
+        assert!(tokens.contains(&"<sep>".to_owned()));
+        assert!(tokens.ends_with(&["<0xC3>".to_owned(), "<0xA9>".to_owned()]));
+    }
+
+    #[test]
+    fn valid_pieces() {
+        let chars = |text: &str| text.chars().collect::<Vec<char>>();
+        assert_eq!(valid_prefix_len(&chars("▁hello▁world")), 6);
+        assert_eq!(valid_prefix_len(&chars("abc.")), 3);
+        assert_eq!(valid_prefix_len(&chars("e\u{301}t")), 3);
+        assert_eq!(valid_prefix_len(&chars("日本語です")), 5);
+        assert_eq!(valid_prefix_len(&chars("дом⁇")), 3);
+        assert_eq!(valid_prefix_len(&chars("ab\u{2581}")), 2);
+    }
+}
@@ -0,0 +1,73 @@
+use crate::tokenizer::{Detokenizer, Token, Tokenizer};
+
+/// Tokenizer that only splits lines on (Unicode) whitespace, mostly useful as a baseline or for text that is
+/// already tokenized
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WhitespaceTokenizer;
+
+impl Tokenizer for WhitespaceTokenizer {
+    fn name(&self) -> &str {
+        "whitespace"
+    }
+
+    fn tokenize_line(&self, line: &str) -> Vec<String> {
+        line.split_whitespace().map(str::to_owned).collect()
+    }
+
+    fn tokenize_spans(&self, line: &str) -> Vec<Token> {
+        let mut tokens: Vec<Token> = Vec::new();
+        let mut in_token = false;
+        for (char_position, (position, ch)) in line.char_indices().enumerate() {
+            if ch.is_whitespace() {
+                in_token = false;
+            } else if in_token {
+                let token = tokens.last_mut().unwrap();
+                token.text.push(ch);
+                token.end = position + ch.len_utf8();
+                token.char_end = char_position + 1;
+            } else {
+                in_token = true;
+                tokens.push(Token {
+                    text: ch.to_string(),
+                    start: position,
+                    end: position + ch.len_utf8(),
+                    char_start: char_position,
+                    char_end: char_position + 1,
+                });
+            }
+        }
+        tokens
+    }
+}
+
+impl Detokenizer for WhitespaceTokenizer {
+    fn detokenize_line(&self, tokens: &[String]) -> String {
+        tokens.join(" ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spans_of_unicode_whitespace() {
+        let tokens = WhitespaceTokenizer.tokenize_spans(" daß\u{a0}ist,\tgut. ");
+        let spans: Vec<(&str, usize, usize, usize, usize)> = tokens
+            .iter()
+            .map(|t| (t.text.as_str(), t.start, t.end, t.char_start, t.char_end))
+            .collect();
+        assert_eq!(
+            spans,
+            vec![
+                ("daß", 1, 5, 1, 4),
+                ("ist,", 7, 11, 5, 9),
+                ("gut.", 12, 16, 10, 14),
+            ]
+        );
+        assert_eq!(
+            WhitespaceTokenizer.detokenize_line(&WhitespaceTokenizer.tokenize_line(" a  b ")),
+            "a b"
+        );
+    }
+}
@@ -0,0 +1,633 @@
+use crate::error::{Error, Result};
+use crate::stream::read_line;
+use crate::tokenizer::{Detokenizer, Encoding, Token, Tokenizer};
+use rayon::prelude::*;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use unicode_normalization::UnicodeNormalization;
+
+// Port of the `BasicTokenizer` and `WordpieceTokenizer` of
+// https://github.com/google-research/bert/blob/master/tokenization.py
+
+// `NONSPACING_MARKS`, `PUNCTUATION`, `SPACE_SEPARATORS` and `CONTROLS`, the ranges of the Unicode categories used by
+// the basic tokenizer, generated by build.rs
+include!(concat!(env!("OUT_DIR"), "/bert_classes.rs"));
+
+/// Tokens of a WordPiece model, as found in the `vocab.txt` files of BERT models, whose ids are the numbers of the
+/// lines they are found in (starting from 0)
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct WordPieceVocabulary {
+    tokens: Vec<String>,
+    ids: HashMap<String, u32>,
+}
+
+impl WordPieceVocabulary {
+    /// Vocabulary whose ids are the positions of the tokens. Repeated tokens take the id of their last position, like
+    /// in the original tokenizer.
+    pub fn new(tokens: Vec<String>) -> Self {
+        let ids = tokens
+            .iter()
+            .enumerate()
+            .map(|(id, token)| (token.clone(), id as u32))
+            .collect();
+        WordPieceVocabulary { tokens, ids }
+    }
+
+    pub fn tokens(&self) -> &[String] {
+        &self.tokens
+    }
+
+    pub fn id(&self, token: &str) -> Option<u32> {
+        self.ids.get(token).copied()
+    }
+
+    pub fn token(&self, id: u32) -> Option<&str> {
+        self.tokens.get(id as usize).map(String::as_str)
+    }
+
+    pub fn len(&self) -> usize {
+        self.tokens.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tokens.is_empty()
+    }
+
+    /// Loads a `vocab.txt` file with one token per line
+    pub fn load(vocabulary_file_path: &str) -> Result<Self> {
+        let reader = BufReader::new(
+            File::open(vocabulary_file_path).map_err(|e| Error::io(vocabulary_file_path, e))?,
+        );
+        Self::read(reader).map_err(|e| e.with_path(vocabulary_file_path))
+    }
+
+    pub fn read(mut reader: impl BufRead) -> Result<Self> {
+        let mut tokens = Vec::new();
+        let mut offset = 0;
+        while let Some(line) = read_line(&mut reader, &mut offset, None)? {
+            tokens.push(line.trim().to_owned());
+        }
+        Ok(Self::new(tokens))
+    }
+
+    pub fn save(&self, vocabulary_file_path: &str) -> Result<()> {
+        let writer = BufWriter::new(
+            File::create(vocabulary_file_path).map_err(|e| Error::io(vocabulary_file_path, e))?,
+        );
+        self.write(writer)
+            .map_err(|e| e.with_path(vocabulary_file_path))
+    }
+
+    pub fn write(&self, mut writer: impl Write) -> Result<()> {
+        for token in &self.tokens {
+            writeln!(writer, "{token}")?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct WordPieceTokenizerBuilder {
+    lowercase: bool,
+    strip_accents: Option<bool>,
+    split_chinese_chars: bool,
+    unknown_token: String,
+    continuing_prefix: String,
+    max_chars_per_word: usize,
+}
+
+impl Default for WordPieceTokenizerBuilder {
+    fn default() -> Self {
+        WordPieceTokenizerBuilder {
+            lowercase: true,
+            strip_accents: None,
+            split_chinese_chars: true,
+            unknown_token: "[UNK]".to_owned(),
+            continuing_prefix: "##".to_owned(),
+            max_chars_per_word: 200,
+        }
+    }
+}
+
+impl WordPieceTokenizerBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Lowercases the text before splitting it, as needed by uncased models (the default)
+    pub fn lowercase(mut self, lowercase: bool) -> Self {
+        self.lowercase = lowercase;
+        self
+    }
+
+    /// Removes the accents of the text before splitting it, which is done by default only when lowercasing
+    pub fn strip_accents(mut self, strip_accents: bool) -> Self {
+        self.strip_accents = Some(strip_accents);
+        self
+    }
+
+    /// Makes every CJK ideograph a word of its own (the default)
+    pub fn split_chinese_chars(mut self, split_chinese_chars: bool) -> Self {
+        self.split_chinese_chars = split_chinese_chars;
+        self
+    }
+
+    /// Token produced for the words that cannot be split into tokens of the vocabulary, `[UNK]` by default
+    pub fn unknown_token(mut self, unknown_token: &str) -> Self {
+        self.unknown_token = unknown_token.to_owned();
+        self
+    }
+
+    /// Prefix of the tokens that continue a word, `##` by default
+    pub fn continuing_prefix(mut self, continuing_prefix: &str) -> Self {
+        self.continuing_prefix = continuing_prefix.to_owned();
+        self
+    }
+
+    /// Words longer than this number of chars are replaced by the unknown token (200 by default)
+    pub fn max_chars_per_word(mut self, max_chars_per_word: usize) -> Self {
+        self.max_chars_per_word = max_chars_per_word;
+        self
+    }
+
+    /// Builds a tokenizer with the given vocabulary, which must contain the unknown token
+    pub fn build(self, vocabulary: WordPieceVocabulary) -> Result<WordPieceTokenizer> {
+        let Some(unknown_id) = vocabulary.id(&self.unknown_token) else {
+            return Err(Error::InvalidModel {
+                path: None,
+                message: format!(
+                    "the unknown token `{}` is not in the vocabulary",
+                    self.unknown_token
+                ),
+            });
+        };
+        Ok(WordPieceTokenizer {
+            basic_tokenizer: BasicTokenizer {
+                lowercase: self.lowercase,
+                strip_accents: self.strip_accents.unwrap_or(self.lowercase),
+                split_chinese_chars: self.split_chinese_chars,
+            },
+            vocabulary,
+            unknown_token: self.unknown_token,
+            unknown_id,
+            continuing_prefix: self.continuing_prefix,
+            max_chars_per_word: self.max_chars_per_word,
+        })
+    }
+}
+
+/// Tokenizer of BERT models, which splits text into words on whitespace and punctuation and then splits every word
+/// into the longest tokens of the vocabulary, from left to right
+#[derive(Debug, Clone)]
+pub struct WordPieceTokenizer {
+    basic_tokenizer: BasicTokenizer,
+    vocabulary: WordPieceVocabulary,
+    unknown_token: String,
+    unknown_id: u32,
+    continuing_prefix: String,
+    max_chars_per_word: usize,
+}
+
+impl WordPieceTokenizer {
+    pub fn builder() -> WordPieceTokenizerBuilder {
+        WordPieceTokenizerBuilder::new()
+    }
+
+    pub fn vocabulary(&self) -> &WordPieceVocabulary {
+        &self.vocabulary
+    }
+
+    /// Tokenizes a line and returns the ids of its tokens along with their offsets into `line`
+    pub fn encode(&self, line: &str) -> Encoding {
+        let mut encoding = Encoding::default();
+        for word in self.basic_tokenizer.tokenize(line) {
+            self.encode_word(&word, &mut encoding);
+        }
+        encoding
+    }
+
+    /// Encodes every line in parallel, keeping their order
+    pub fn encode_batch(&self, lines: &[&str]) -> Vec<Encoding> {
+        lines.par_iter().map(|line| self.encode(line)).collect()
+    }
+
+    /// Tokens of the given ids, `None` if any of them is out of the vocabulary
+    pub fn ids_to_tokens(&self, ids: &[u32]) -> Option<Vec<String>> {
+        ids.iter()
+            .map(|&id| self.vocabulary.token(id).map(str::to_owned))
+            .collect()
+    }
+
+    // Splits a word that is already normalized and separated from the rest of the text, as done by the WordPiece
+    // model of Hugging Face pipelines, with offsets into `word`
+    pub(crate) fn encode_normalized_word(&self, word: &str) -> Encoding {
+        let word: Vec<(char, Span)> = word
+            .char_indices()
+            .enumerate()
+            .map(|(char_position, (position, ch))| {
+                let span = Span {
+                    start: position,
+                    end: position + ch.len_utf8(),
+                    char_start: char_position,
+                    char_end: char_position + 1,
+                };
+                (ch, span)
+            })
+            .collect();
+        let mut encoding = Encoding::default();
+        if !word.is_empty() {
+            self.encode_word(&word, &mut encoding);
+        }
+        encoding
+    }
+
+    // Greedy longest-match-first split of a word, which becomes a single unknown token if any part of it cannot be
+    // matched
+    fn encode_word(&self, word: &[(char, Span)], encoding: &mut Encoding) {
+        let token = |text: String, first: &Span, last: &Span| Token {
+            text,
+            start: first.start,
+            end: last.end,
+            char_start: first.char_start,
+            char_end: last.char_end,
+        };
+        let unknown = |encoding: &mut Encoding| {
+            encoding.ids.push(self.unknown_id);
+            encoding.tokens.push(token(
+                self.unknown_token.clone(),
+                &word[0].1,
+                &word[word.len() - 1].1,
+            ));
+        };
+        if word.len() > self.max_chars_per_word {
+            unknown(encoding);
+            return;
+        }
+
+        let text: String = word.iter().map(|(ch, _)| ch).collect();
+        let mut boundaries: Vec<usize> = text.char_indices().map(|(i, _)| i).collect();
+        boundaries.push(text.len());
+        let mut pieces = Vec::new();
+        let mut candidate = String::new();
+        let mut start = 0;
+        while start < word.len() {
+            let mut found = None;
+            for end in (start + 1..=word.len()).rev() {
+                candidate.clear();
+                if start > 0 {
+                    candidate.push_str(&self.continuing_prefix);
+                }
+                candidate.push_str(&text[boundaries[start]..boundaries[end]]);
+                if let Some(id) = self.vocabulary.id(&candidate) {
+                    found = Some((id, end));
+                    break;
+                }
+            }
+            let Some((id, end)) = found else {
+                unknown(encoding);
+                return;
+            };
+            pieces.push((
+                id,
+                token(candidate.clone(), &word[start].1, &word[end - 1].1),
+            ));
+            start = end;
+        }
+        for (id, piece) in pieces {
+            encoding.ids.push(id);
+            encoding.tokens.push(piece);
+        }
+    }
+}
+
+impl Tokenizer for WordPieceTokenizer {
+    fn name(&self) -> &str {
+        "wordpiece"
+    }
+
+    fn tokenize_line(&self, line: &str) -> Vec<String> {
+        self.encode(line)
+            .tokens
+            .into_iter()
+            .map(|token| token.text)
+            .collect()
+    }
+
+    fn tokenize_spans(&self, line: &str) -> Vec<Token> {
+        self.encode(line).tokens
+    }
+}
+
+impl Detokenizer for WordPieceTokenizer {
+    // Joins the tokens that continue a word with the previous one. Since the text was normalized, the original case,
+    // accents and spacing around punctuation are not recovered.
+    fn detokenize_line(&self, tokens: &[String]) -> String {
+        let mut text = String::new();
+        for token in tokens {
+            match token.strip_prefix(self.continuing_prefix.as_str()) {
+                Some(subword) if !subword.is_empty() && !text.is_empty() => text.push_str(subword),
+                _ => {
+                    if !text.is_empty() {
+                        text.push(' ');
+                    }
+                    text.push_str(token);
+                }
+            }
+        }
+        text
+    }
+}
+
+// Byte and char offsets of a character of the original line
+#[derive(Debug, Clone, Copy)]
+struct Span {
+    start: usize,
+    end: usize,
+    char_start: usize,
+    char_end: usize,
+}
+
+// Splits text into words on whitespace, punctuation and CJK ideographs, normalizing their characters
+#[derive(Debug, Clone)]
+struct BasicTokenizer {
+    lowercase: bool,
+    strip_accents: bool,
+    split_chinese_chars: bool,
+}
+
+impl BasicTokenizer {
+    // Words as normalized characters, each one along with the offsets of the character of the line it comes from
+    fn tokenize(&self, line: &str) -> Vec<Vec<(char, Span)>> {
+        let mut words = Vec::new();
+        let mut word = Vec::new();
+        let mut normalized = Vec::new();
+        for (char_position, (position, ch)) in line.char_indices().enumerate() {
+            let span = Span {
+                start: position,
+                end: position + ch.len_utf8(),
+                char_start: char_position,
+                char_end: char_position + 1,
+            };
+            // Invalid and control characters are removed without splitting the word they are found in
+            if is_removed(ch) {
+                continue;
+            }
+            if is_whitespace(ch) {
+                end_word(&mut word, &mut words);
+                continue;
+            }
+            let chinese_char = self.split_chinese_chars && is_chinese_char(ch);
+            if chinese_char {
+                end_word(&mut word, &mut words);
+            }
+            self.normalize(line, position, &mut normalized);
+            for &normalized_ch in &normalized {
+                if is_punctuation(normalized_ch) {
+                    end_word(&mut word, &mut words);
+                    words.push(vec![(normalized_ch, span)]);
+                } else {
+                    word.push((normalized_ch, span));
+                }
+            }
+            if chinese_char {
+                end_word(&mut word, &mut words);
+            }
+        }
+        end_word(&mut word, &mut words);
+        words
+    }
+
+    // Lowercases the character at `position` and removes its accents, which may result in several characters or none
+    // at all
+    fn normalize(&self, line: &str, position: usize, normalized: &mut Vec<char>) {
+        let ch = line[position..].chars().next().unwrap();
+        normalized.clear();
+        if ch.is_ascii() {
+            normalized.push(if self.lowercase {
+                ch.to_ascii_lowercase()
+            } else {
+                ch
+            });
+        } else if self.lowercase && ch == 'Σ' {
+            normalized.push(self.lowercase_sigma(line, position));
+        } else if self.lowercase {
+            normalized.extend(ch.to_lowercase());
+        } else {
+            normalized.push(ch);
+        }
+        if self.strip_accents && !ch.is_ascii() {
+            let stripped: Vec<char> = normalized
+                .iter()
+                .copied()
+                .nfd()
+                .filter(|&ch| !is_nonspacing_mark(ch))
+                .collect();
+            *normalized = stripped;
+        }
+    }
+
+    // Lowercase of the capital sigma at `position`, which depends on whether it ends a word. The original tokenizer
+    // lowercases whole whitespace-separated words, so the word is lowercased the same way here.
+    fn lowercase_sigma(&self, line: &str, position: usize) -> char {
+        let is_boundary =
+            |ch: char| is_whitespace(ch) || (self.split_chinese_chars && is_chinese_char(ch));
+        let start = line[..position]
+            .char_indices()
+            .rfind(|&(_, ch)| is_boundary(ch))
+            .map_or(0, |(i, ch)| i + ch.len_utf8());
+        let end = line[position..]
+            .find(is_boundary)
+            .map_or(line.len(), |i| position + i);
+        let kept = |text: &str| -> String { text.chars().filter(|&ch| !is_removed(ch)).collect() };
+        let prefix = kept(&line[start..position]);
+        // Lowercasing does not change the length of the sigmas of the prefix, whichever their form
+        let lowercased_word = format!("{prefix}{}", kept(&line[position..end])).to_lowercase();
+        lowercased_word[prefix.to_lowercase().len()..]
+            .chars()
+            .next()
+            .unwrap()
+    }
+}
+
+fn end_word(word: &mut Vec<(char, Span)>, words: &mut Vec<Vec<(char, Span)>>) {
+    if !word.is_empty() {
+        words.push(std::mem::take(word));
+    }
+}
+
+fn in_class(ch: char, ranges: &[(char, char)]) -> bool {
+    ranges
+        .binary_search_by(|&(start, end)| {
+            if end < ch {
+                Ordering::Less
+            } else if start > ch {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+pub(crate) fn is_removed(ch: char) -> bool {
+    ch == '\0' || ch == '\u{fffd}' || is_control(ch)
+}
+
+pub(crate) fn is_nonspacing_mark(ch: char) -> bool {
+    in_class(ch, &NONSPACING_MARKS)
+}
+
+pub(crate) fn is_whitespace(ch: char) -> bool {
+    matches!(ch, ' ' | '\t' | '\n' | '\r') || in_class(ch, &SPACE_SEPARATORS)
+}
+
+fn is_control(ch: char) -> bool {
+    !matches!(ch, '\t' | '\n' | '\r') && in_class(ch, &CONTROLS)
+}
+
+// All non-alphanumeric ASCII characters are punctuation for BERT, even the ones that are symbols for Unicode
+pub(crate) fn is_punctuation(ch: char) -> bool {
+    ch.is_ascii_punctuation() || in_class(ch, &PUNCTUATION)
+}
+
+// Ideographs of the CJK Unified Ideographs blocks, which does not include the Hangul, Hiragana and Katakana scripts
+pub(crate) fn is_chinese_char(ch: char) -> bool {
+    matches!(ch,
+        '\u{4E00}'..='\u{9FFF}'
+        | '\u{3400}'..='\u{4DBF}'
+        | '\u{20000}'..='\u{2A6DF}'
+        | '\u{2A700}'..='\u{2B73F}'
+        | '\u{2B740}'..='\u{2B81F}'
+        | '\u{2B820}'..='\u{2CEAF}'
+        | '\u{F900}'..='\u{FAFF}'
+        | '\u{2F800}'..='\u{2FA1F}'
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Vocabulary of the tests of the original tokenizer
+    const VOCABULARY: &str =
+        "[UNK]\n[CLS]\n[SEP]\nwant\n##want\n##ed\nwa\nun\nrunn\n##ing\n,\nlow\nlowest\n";
+
+    fn tokenizer(builder: WordPieceTokenizerBuilder) -> WordPieceTokenizer {
+        builder
+            .build(WordPieceVocabulary::read(VOCABULARY.as_bytes()).unwrap())
+            .unwrap()
+    }
+
+    fn basic_tokens(basic_tokenizer: &BasicTokenizer, line: &str) -> Vec<String> {
+        basic_tokenizer
+            .tokenize(line)
+            .iter()
+            .map(|word| word.iter().map(|(ch, _)| ch).collect())
+            .collect()
+    }
+
+    #[test]
+    fn basic_tokenizer() {
+        let uncased = BasicTokenizer {
+            lowercase: true,
+            strip_accents: true,
+            split_chinese_chars: true,
+        };
+        assert_eq!(
+            basic_tokens(&uncased, " \tHeLLo!how  \n Are yoU?  "),
+            ["hello", "!", "how", "are", "you", "?"]
+        );
+        assert_eq!(basic_tokens(&uncased, "H\u{e9}llo"), ["hello"]);
+        // Like Python's `str.lower`, a capital sigma at the end of a word becomes a final sigma
+        assert_eq!(
+            basic_tokens(&uncased, "ΟΔΟΣ ΣΑΣ. Σ"),
+            ["οδος", "σας", ".", "σ"]
+        );
+        assert_eq!(
+            basic_tokens(&uncased, "ah\u{535a}\u{63a8}zz"),
+            ["ah", "\u{535a}", "\u{63a8}", "zz"]
+        );
+        // Control characters are removed, while other spaces split words
+        assert_eq!(
+            basic_tokens(&uncased, "a\u{0}b\u{200b}c\u{a0}d"),
+            ["abc", "d"]
+        );
+        // All ASCII punctuation and symbols are split, but not the other symbols
+        assert_eq!(basic_tokens(&uncased, "a$b«c€"), ["a", "$", "b", "«", "c€"]);
+
+        let cased = BasicTokenizer {
+            lowercase: false,
+            strip_accents: false,
+            split_chinese_chars: true,
+        };
+        assert_eq!(
+            basic_tokens(&cased, " \tHeLLo!how  \n Are yoU?  "),
+            ["HeLLo", "!", "how", "Are", "yoU", "?"]
+        );
+        assert_eq!(basic_tokens(&cased, "H\u{e9}llo"), ["H\u{e9}llo"]);
+    }
+
+    #[test]
+    fn wordpiece_tokenizer() {
+        let tokenizer = tokenizer(WordPieceTokenizer::builder());
+        assert_eq!(
+            tokenizer.tokenize_line("unwanted running"),
+            ["un", "##want", "##ed", "runn", "##ing"]
+        );
+        assert_eq!(
+            tokenizer.tokenize_line("UnwantedX running"),
+            ["[UNK]", "runn", "##ing"]
+        );
+        assert_eq!(tokenizer.tokenize_line(""), Vec::<String>::new());
+        assert_eq!(tokenizer.encode("unwanted,").ids, [7, 4, 5, 10]);
+        assert_eq!(
+            tokenizer.detokenize_line(&tokenizer.tokenize_line("unwanted running")),
+            "unwanted running"
+        );
+
+        // Offsets point to the original text, before lowercasing and removing accents
+        let encoding = tokenizer.encode("Ünwanted lowést");
+        let spans: Vec<(&str, usize, usize, usize, usize)> = encoding
+            .tokens
+            .iter()
+            .map(|t| (t.text.as_str(), t.start, t.end, t.char_start, t.char_end))
+            .collect();
+        assert_eq!(
+            spans,
+            vec![
+                ("un", 0, 3, 0, 2),
+                ("##want", 3, 7, 2, 6),
+                ("##ed", 7, 9, 6, 8),
+                ("lowest", 10, 17, 9, 15),
+            ]
+        );
+        assert_eq!(encoding.ids, [7, 4, 5, 12]);
+
+        let tokenizer = self::tokenizer(WordPieceTokenizer::builder().max_chars_per_word(5));
+        assert_eq!(tokenizer.tokenize_line("running low"), ["[UNK]", "low"]);
+        let tokenizer = self::tokenizer(WordPieceTokenizer::builder().lowercase(false));
+        assert_eq!(tokenizer.tokenize_line("Running low"), ["[UNK]", "low"]);
+    }
+
+    #[test]
+    fn vocabulary() {
+        let vocabulary = WordPieceVocabulary::read(VOCABULARY.as_bytes()).unwrap();
+        assert_eq!(vocabulary.len(), 13);
+        assert_eq!(vocabulary.id("##ing"), Some(9));
+        assert_eq!(vocabulary.token(9), Some("##ing"));
+        let mut written = Vec::new();
+        vocabulary.write(&mut written).unwrap();
+        assert_eq!(String::from_utf8(written).unwrap(), VOCABULARY);
+        // The unknown token is required
+        assert!(matches!(
+            WordPieceTokenizer::builder()
+                .unknown_token("<unk>")
+                .build(vocabulary),
+            Err(Error::InvalidModel { path: None, message })
+                if message == "the unknown token `<unk>` is not in the vocabulary"
+        ));
+    }
+}
@@ -0,0 +1,599 @@
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+fn run_tokenaisu(args: &[&str]) {
+    let status = Command::new(env!("CARGO_BIN_EXE_tokenaisu"))
+        .args(args)
+        .status()
+        .unwrap();
+    assert!(status.success());
+}
+
+fn run_tokenaisu_stdin(args: &[&str], input: &str) -> String {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_tokenaisu"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn cli_no_escape() {
+    run_tokenaisu(&[
+        "--language",
+        "en",
+        "--input-file-path",
+        "tests/untokenized_text.txt",
+        "--output-file-path",
+        "tests/cli_no_escape_test.txt",
+        "--no-escape",
+    ]);
+    let text_data = fs::read_to_string("tests/cli_no_escape_test.txt").unwrap();
+    let ground_truth = fs::read_to_string("tests/tokenized_text.txt").unwrap();
+    fs::remove_file("tests/cli_no_escape_test.txt").unwrap();
+    assert_eq!(text_data, ground_truth);
+}
+
+#[test]
+fn cli_escape_by_default() {
+    run_tokenaisu(&[
+        "-l",
+        "en",
+        "-i",
+        "tests/untokenized_text.txt",
+        "-o",
+        "tests/cli_escape_test.txt",
+    ]);
+    let text_data = fs::read_to_string("tests/cli_escape_test.txt").unwrap();
+    let ground_truth = fs::read_to_string("tests/tokenized_text.txt").unwrap();
+    fs::remove_file("tests/cli_escape_test.txt").unwrap();
+    assert_eq!(text_data, ground_truth.replace('\'', "&apos;"));
+}
+
+#[test]
+fn cli_aggressive() {
+    run_tokenaisu(&[
+        "-l",
+        "en",
+        "-i",
+        "tests/untokenized_text.txt",
+        "-o",
+        "tests/cli_aggressive_test.txt",
+        "--no-escape",
+        "-a",
+    ]);
+    let text_data = fs::read_to_string("tests/cli_aggressive_test.txt").unwrap();
+    fs::remove_file("tests/cli_aggressive_test.txt").unwrap();
+    assert!(text_data.contains("Down the Rabbit @-@ Hole\n"));
+    assert!(text_data.contains("a daisy @-@ chain"));
+}
+
+#[test]
+fn cli_language() {
+    fs::write(
+        "tests/cli_language_input.txt",
+        "de musique rap issus de l'immigration\n",
+    )
+    .unwrap();
+    run_tokenaisu(&[
+        "-l",
+        "fr",
+        "-i",
+        "tests/cli_language_input.txt",
+        "-o",
+        "tests/cli_language_test.txt",
+        "--no-escape",
+    ]);
+    let text_data = fs::read_to_string("tests/cli_language_test.txt").unwrap();
+    fs::remove_file("tests/cli_language_input.txt").unwrap();
+    fs::remove_file("tests/cli_language_test.txt").unwrap();
+    assert_eq!(text_data, "de musique rap issus de l' immigration\n");
+}
+
+#[test]
+fn cli_protected_patterns() {
+    fs::write(
+        "tests/cli_protected_input.txt",
+        "Some text containing the protected pattern $'$ and /'/ and <b>.\n",
+    )
+    .unwrap();
+    fs::write("tests/cli_protected_patterns.txt", "<[^>]+>\n").unwrap();
+    run_tokenaisu(&[
+        "-l",
+        "en",
+        "-i",
+        "tests/cli_protected_input.txt",
+        "-o",
+        "tests/cli_protected_test.txt",
+        "--no-escape",
+        "--protected-pattern",
+        r"\$'\$",
+        "--protected-pattern",
+        "/'/",
+        "--protected-patterns-file",
+        "tests/cli_protected_patterns.txt",
+    ]);
+    let text_data = fs::read_to_string("tests/cli_protected_test.txt").unwrap();
+    fs::remove_file("tests/cli_protected_input.txt").unwrap();
+    fs::remove_file("tests/cli_protected_patterns.txt").unwrap();
+    fs::remove_file("tests/cli_protected_test.txt").unwrap();
+    assert_eq!(
+        text_data,
+        "Some text containing the protected pattern $'$ and /'/ and <b> .\n"
+    );
+}
+
+#[test]
+fn cli_stdin_stdout() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_tokenaisu"))
+        .args(["-l", "en", "--no-escape"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let input = fs::read_to_string("tests/untokenized_text.txt").unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+    let ground_truth = fs::read_to_string("tests/tokenized_text.txt").unwrap();
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), ground_truth);
+}
+
+#[test]
+fn cli_invalid_protected_pattern() {
+    let output = Command::new(env!("CARGO_BIN_EXE_tokenaisu"))
+        .args([
+            "-l",
+            "en",
+            "-i",
+            "tests/untokenized_text.txt",
+            "-o",
+            "tests/cli_invalid_pattern_test.txt",
+            "--protected-pattern",
+            "(unclosed",
+        ])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("invalid protected pattern #0 `(unclosed`"));
+    // The output file is not created when the options are invalid
+    assert!(!Path::new("tests/cli_invalid_pattern_test.txt").exists());
+}
+
+#[test]
+fn cli_penn() {
+    fs::write(
+        "tests/cli_penn_input.txt",
+        "\"I can't believe it (really),\" he said.\n",
+    )
+    .unwrap();
+    run_tokenaisu(&[
+        "-l",
+        "en",
+        "-i",
+        "tests/cli_penn_input.txt",
+        "-o",
+        "tests/cli_penn_test.txt",
+        "--penn",
+    ]);
+    let text_data = fs::read_to_string("tests/cli_penn_test.txt").unwrap();
+    fs::remove_file("tests/cli_penn_input.txt").unwrap();
+    fs::remove_file("tests/cli_penn_test.txt").unwrap();
+    assert_eq!(
+        text_data,
+        "`` I ca n't believe it -LRB- really -RRB- , '' he said .\n"
+    );
+}
+
+#[test]
+fn cli_skip_xml() {
+    fs::write(
+        "tests/cli_xml_input.txt",
+        "<refset setid=\"test\" srclang=\"any\" trglang=\"en\">\n<seg id=\"1\">Hello, <b>world</b>!</seg>\n</refset>\n",
+    )
+    .unwrap();
+    run_tokenaisu(&[
+        "-l",
+        "en",
+        "-i",
+        "tests/cli_xml_input.txt",
+        "-o",
+        "tests/cli_xml_test.txt",
+        "-x",
+        "--protect-xml-tags",
+    ]);
+    let text_data = fs::read_to_string("tests/cli_xml_test.txt").unwrap();
+    fs::remove_file("tests/cli_xml_input.txt").unwrap();
+    fs::remove_file("tests/cli_xml_test.txt").unwrap();
+    assert_eq!(
+        text_data,
+        "<refset setid=\"test\" srclang=\"any\" trglang=\"en\">\n<seg id=\"1\"> Hello , <b> world </b> ! </seg>\n</refset>\n"
+    );
+}
+
+#[test]
+fn cli_protected_presets() {
+    fs::write(
+        "tests/cli_presets_input.txt",
+        "Write to dev@example.com or visit https://example.com/a-b.\n",
+    )
+    .unwrap();
+    run_tokenaisu(&[
+        "-l",
+        "en",
+        "-i",
+        "tests/cli_presets_input.txt",
+        "-o",
+        "tests/cli_presets_test.txt",
+        "-a",
+        "--protect",
+        "url,email",
+    ]);
+    let text_data = fs::read_to_string("tests/cli_presets_test.txt").unwrap();
+    fs::remove_file("tests/cli_presets_input.txt").unwrap();
+    fs::remove_file("tests/cli_presets_test.txt").unwrap();
+    assert_eq!(
+        text_data,
+        "Write to dev@example.com or visit https://example.com/a-b .\n"
+    );
+}
+
+#[test]
+fn cli_tokenize_subcommand() {
+    let input = fs::read_to_string("tests/untokenized_text.txt").unwrap();
+    let ground_truth = fs::read_to_string("tests/tokenized_text.txt").unwrap();
+    assert_eq!(
+        run_tokenaisu_stdin(&["tokenize", "-l", "en", "--no-escape"], &input),
+        ground_truth
+    );
+}
+
+#[test]
+fn cli_normalize() {
+    assert_eq!(
+        run_tokenaisu_stdin(
+            &["normalize", "-l", "en"],
+            "The  expression “the Convention” (see below) .\nIt costs 12\u{00A0}000 ％\n"
+        ),
+        "The expression \"the Convention\" (see below).\nIt costs 12.000 ％\n"
+    );
+    assert_eq!(
+        run_tokenaisu_stdin(
+            &["normalize", "-l", "en", "--pre-replace-unicode-punct"],
+            "It costs １２\u{00A0}０００ ％\n"
+        ),
+        "It costs 12.000%\n"
+    );
+}
+
+#[test]
+fn cli_split_sentences() {
+    let input = "Mr. Smith arrived. He said \"Hi.\" Then he\nleft.\n\nThe end.\n";
+    assert_eq!(
+        run_tokenaisu_stdin(&["split-sentences", "-l", "en"], input),
+        "Mr. Smith arrived.\nHe said \"Hi.\"\nThen he left.\n<P>\nThe end.\n"
+    );
+    assert_eq!(
+        run_tokenaisu_stdin(&["split-sentences", "-l", "en", "-n"], input),
+        "Mr. Smith arrived.\nHe said \"Hi.\"\nThen he left.\nThe end.\n"
+    );
+}
+
+#[test]
+fn cli_truecase() {
+    run_tokenaisu(&[
+        "train-truecaser",
+        "--model",
+        "tests/truecase_model_cli.txt",
+        "--input-file-path",
+        "tests/tokenized_text.txt",
+    ]);
+    let model = fs::read_to_string("tests/truecase_model_cli.txt").unwrap();
+    let truecased = run_tokenaisu_stdin(
+        &["truecase", "--model", "tests/truecase_model_cli.txt"],
+        "THE RABBIT WAS LATE , THOUGHT ALICE .\nthe end .\n",
+    );
+    let detruecased = run_tokenaisu_stdin(&["detruecase"], &truecased);
+    fs::remove_file("tests/truecase_model_cli.txt").unwrap();
+
+    assert!(model.lines().any(|line| line.starts_with("Alice (")));
+    assert_eq!(
+        truecased,
+        "the Rabbit was late , thought Alice .\nthe end .\n"
+    );
+    assert_eq!(
+        detruecased,
+        "The Rabbit was late , thought Alice .\nThe end .\n"
+    );
+}
+
+#[test]
+fn cli_escape_deescape() {
+    let escaped = run_tokenaisu_stdin(&["escape"], "Tom & Jerry's <b>[show]</b>\n");
+    assert_eq!(
+        escaped,
+        "Tom &amp; Jerry&apos;s &lt;b&gt;&#91;show&#93;&lt;/b&gt;\n"
+    );
+    assert_eq!(
+        run_tokenaisu_stdin(&["deescape"], &escaped),
+        "Tom & Jerry's <b>[show]</b>\n"
+    );
+}
+
+#[test]
+fn cli_generic_language() {
+    assert_eq!(
+        run_tokenaisu_stdin(&["-l", "xx", "--no-escape"], "It's Dr. Who.\n"),
+        "It ' s Dr . Who .\n"
+    );
+}
+
+#[test]
+fn cli_prefixes_file() {
+    fs::write("tests/prefixes_cli.txt", "# Domain abbreviations\napprox\n").unwrap();
+    let tokenized = run_tokenaisu_stdin(
+        &["-l", "en", "--prefixes-file", "tests/prefixes_cli.txt"],
+        "It is approx. Ten metres long.\n",
+    );
+    let split = run_tokenaisu_stdin(
+        &[
+            "split-sentences",
+            "-l",
+            "en",
+            "--prefixes-file",
+            "tests/prefixes_cli.txt",
+        ],
+        "Ask Dr. Smith. It is approx. Ten metres long.\n",
+    );
+    let split_without_builtin = run_tokenaisu_stdin(
+        &[
+            "split-sentences",
+            "-l",
+            "en",
+            "--prefixes-file",
+            "tests/prefixes_cli.txt",
+            "--no-builtin-prefixes",
+        ],
+        "Ask Dr. Smith. It is approx. Ten metres long.\n",
+    );
+    fs::remove_file("tests/prefixes_cli.txt").unwrap();
+
+    assert_eq!(tokenized, "It is approx. Ten metres long .\n");
+    assert_eq!(split, "Ask Dr. Smith.\nIt is approx. Ten metres long.\n");
+    assert_eq!(
+        split_without_builtin,
+        "Ask Dr.\nSmith.\nIt is approx. Ten metres long.\n"
+    );
+}
+
+#[test]
+fn cli_tokenizer_selection() {
+    let input = "Hello, world.  It's\tfine.\n";
+    assert_eq!(
+        run_tokenaisu_stdin(&["-l", "en", "--tokenizer", "whitespace"], input),
+        "Hello, world. It's fine.\n"
+    );
+    assert_eq!(
+        run_tokenaisu_stdin(&["-l", "en", "-t", "moses", "--no-escape"], input),
+        "Hello , world . It 's fine .\n"
+    );
+    let lines = "a  b\n".repeat(250);
+    for threads in ["1", "2"] {
+        assert_eq!(
+            run_tokenaisu_stdin(
+                &[
+                    "-t",
+                    "whitespace",
+                    "--threads",
+                    threads,
+                    "--batch-lines",
+                    "7"
+                ],
+                &lines
+            ),
+            "a b\n".repeat(250)
+        );
+    }
+    let status = Command::new(env!("CARGO_BIN_EXE_tokenaisu"))
+        .args(["-l", "en", "--tokenizer", "unknown"])
+        .stderr(Stdio::null())
+        .status()
+        .unwrap();
+    assert!(!status.success());
+    // Only the Moses tokenizer requires a language
+    let output = Command::new(env!("CARGO_BIN_EXE_tokenaisu"))
+        .args(["-t", "moses"])
+        .stdin(Stdio::null())
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("requires a language"));
+}
+
+#[test]
+fn cli_learn_and_apply_bpe() {
+    let corpus = "low low low low low lower lower\n\
+                  newest newest newest newest newest newest widest widest widest\n";
+    let codes = run_tokenaisu_stdin(&["learn-bpe", "--symbols", "6"], corpus);
+    assert_eq!(
+        codes,
+        "#version: 0.2\ns t</w>\ne st</w>\nl o\nw est</w>\nn e\nne west</w>\n"
+    );
+    fs::write("tests/cli_bpe_codes_test.txt", codes).unwrap();
+    let segmented = run_tokenaisu_stdin(
+        &["apply-bpe", "--codes", "tests/cli_bpe_codes_test.txt"],
+        "lowest newest\n",
+    );
+    let tokenized = run_tokenaisu_stdin(
+        &[
+            "-l",
+            "en",
+            "-t",
+            "bpe",
+            "-m",
+            "tests/cli_bpe_codes_test.txt",
+        ],
+        "lowest newest\n",
+    );
+    fs::remove_file("tests/cli_bpe_codes_test.txt").unwrap();
+    assert_eq!(segmented, "lo@@ west newest\n");
+    assert_eq!(tokenized, segmented);
+}
+
+#[test]
+fn cli_wordpiece_tokenizer() {
+    fs::write(
+        "tests/cli_wordpiece_vocab_test.txt",
+        "[UNK]\nwant\n##want\n##ed\nun\nrunn\n##ing\n,\nUn\n",
+    )
+    .unwrap();
+    let model_args = [
+        "-l",
+        "en",
+        "-t",
+        "wordpiece",
+        "-m",
+        "tests/cli_wordpiece_vocab_test.txt",
+    ];
+    let uncased = run_tokenaisu_stdin(&model_args, "Unwanted, running!\n");
+    let cased = run_tokenaisu_stdin(&[&model_args[..], &["--cased"]].concat(), "Unwanted\n");
+    fs::remove_file("tests/cli_wordpiece_vocab_test.txt").unwrap();
+    assert_eq!(uncased, "un ##want ##ed , runn ##ing [UNK]\n");
+    assert_eq!(cased, "Un ##want ##ed\n");
+}
+
+#[test]
+fn cli_unigram_tokenizer() {
+    let corpus = "the cat sat on the mat\nthe dog sat on the log\nthe cat and the dog\n".repeat(20);
+    run_tokenaisu_stdin(
+        &[
+            "train-unigram",
+            "-m",
+            "tests/cli_unigram_test.model",
+            "--vocab-size",
+            "30",
+            "--user-defined-symbols",
+            "<sep>,<cls>",
+        ],
+        &corpus,
+    );
+    let tokenized = run_tokenaisu_stdin(
+        &[
+            "-l",
+            "en",
+            "-t",
+            "unigram",
+            "-m",
+            "tests/cli_unigram_test.model",
+        ],
+        "the cat<sep> sat\n",
+    );
+    fs::remove_file("tests/cli_unigram_test.model").unwrap();
+    let tokens: Vec<&str> = tokenized.trim_end().split(' ').collect();
+    assert_eq!(tokens[0], "▁the");
+    assert!(tokens.contains(&"<sep>"));
+    assert_eq!(tokens.concat(), "▁the▁cat<sep>▁sat");
+}
+
+#[test]
+fn cli_byte_bpe_tokenizer() {
+    const BASE64: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut ranks: String = (0..=u8::MAX)
+        .map(|byte| {
+            let first = BASE64[(byte >> 2) as usize] as char;
+            let second = BASE64[((byte & 3) << 4) as usize] as char;
+            format!("{first}{second}== {byte}\n")
+        })
+        .collect();
+    // he, ll, llo, " w", or, " wor" and ld
+    ranks.push_str("aGU= 256\nbGw= 257\nbGxv 258\nIHc= 259\nb3I= 260\nIHdvcg== 261\nbGQ= 262\n");
+    fs::write("tests/cli_byte_bpe_test.tiktoken", ranks).unwrap();
+    fs::write("tests/cli_byte_bpe_input_test.txt", "hello world\nhello\n").unwrap();
+    let tokenized = run_tokenaisu_stdin(
+        &[
+            "-l",
+            "en",
+            "-t",
+            "byte_bpe",
+            "-m",
+            "tests/cli_byte_bpe_test.tiktoken",
+        ],
+        "hello world\n",
+    );
+    let counts = run_tokenaisu_stdin(
+        &[
+            "count-tokens",
+            "-m",
+            "tests/cli_byte_bpe_test.tiktoken",
+            "--preset",
+            "cl100k_base",
+            "tests/cli_byte_bpe_input_test.txt",
+            "-",
+        ],
+        "hello",
+    );
+    fs::remove_file("tests/cli_byte_bpe_test.tiktoken").unwrap();
+    fs::remove_file("tests/cli_byte_bpe_input_test.txt").unwrap();
+    assert_eq!(tokenized, "he llo Ġwor ld\n");
+    assert_eq!(
+        counts,
+        "8\ttests/cli_byte_bpe_input_test.txt\n2\t-\n10\ttotal\n"
+    );
+}
+
+#[test]
+fn cli_huggingface_tokenizer() {
+    let tokenizer = r###"{
+        "added_tokens": [
+            {"id": 0, "content": "[UNK]", "special": true, "normalized": false},
+            {"id": 1, "content": "[CLS]", "special": true, "normalized": false},
+            {"id": 2, "content": "[SEP]", "special": true, "normalized": false}
+        ],
+        "normalizer": {"type": "BertNormalizer", "lowercase": true},
+        "pre_tokenizer": {"type": "BertPreTokenizer"},
+        "post_processor": {"type": "BertProcessing", "cls": ["[CLS]", 1], "sep": ["[SEP]", 2]},
+        "decoder": {"type": "WordPiece", "prefix": "##"},
+        "model": {
+            "type": "WordPiece",
+            "unk_token": "[UNK]",
+            "vocab": {"[UNK]": 0, "[CLS]": 1, "[SEP]": 2, "don": 3, "##'": 4, "'": 5, "t": 6, "stop": 7, "##ping": 8}
+        }
+    }"###;
+    fs::write("tests/cli_huggingface_test.json", tokenizer).unwrap();
+    let args = [
+        "-l",
+        "en",
+        "-t",
+        "huggingface",
+        "-m",
+        "tests/cli_huggingface_test.json",
+        "--no-escape",
+    ];
+    let tokenized = run_tokenaisu_stdin(&args, "Don't stopping\n");
+    let moses_tokenized = run_tokenaisu_stdin(
+        &[args.as_slice(), &["--moses-pre-tokenizer"]].concat(),
+        "Don't stopping\n",
+    );
+    fs::remove_file("tests/cli_huggingface_test.json").unwrap();
+    assert_eq!(tokenized, "[CLS] don ' t stop ##ping [SEP]\n");
+    assert_eq!(moses_tokenized, "[CLS] don [UNK] stop ##ping [SEP]\n");
+}
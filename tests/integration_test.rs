@@ -1,5 +1,9 @@
 use std::fs;
-use tokenaisu::moses::{Language, moses_tokenize_file};
+use std::io::Cursor;
+use tokenaisu::Error;
+use tokenaisu::moses::{
+    Language, moses_detokenize_file, moses_tokenize, moses_tokenize_file, moses_tokenize_reader,
+};
 
 #[test]
 fn tokenize_file() {
@@ -17,3 +21,126 @@ fn tokenize_file() {
     fs::remove_file("tests/tokenized_text_test.txt").unwrap();
     assert_eq!(text_data, ground_truth);
 }
+
+#[test]
+fn detokenize_file_round_trip() {
+    moses_detokenize_file(
+        "tests/tokenized_text.txt",
+        "tests/detokenized_text_test.txt",
+        Language::En,
+    )
+    .unwrap();
+    let detokenized = fs::read_to_string("tests/detokenized_text_test.txt").unwrap();
+    fs::remove_file("tests/detokenized_text_test.txt").unwrap();
+
+    // Tokenizing the detokenized text again should give back the tokenized text
+    let retokenized = moses_tokenize(&detokenized, Language::En, true, false, &[]).unwrap();
+    let ground_truth = fs::read_to_string("tests/tokenized_text.txt").unwrap();
+    assert_eq!(retokenized, ground_truth);
+
+    // It should also match the original text up to whitespace, except on the lines whose closing ' follows an
+    // opening `, which detokenizer.perl does not pair either and so detaches from the word before it
+    const UNPAIRED_QUOTE_LINES: [usize; 4] = [8, 9, 19, 46];
+    let original = fs::read_to_string("tests/untokenized_text.txt").unwrap();
+    assert_eq!(detokenized.lines().count(), original.lines().count());
+    for (i, (detokenized_line, original_line)) in
+        detokenized.lines().zip(original.lines()).enumerate()
+    {
+        let detokenized_words: Vec<&str> = detokenized_line.split_whitespace().collect();
+        let original_words: Vec<&str> = original_line.split_whitespace().collect();
+        if UNPAIRED_QUOTE_LINES.contains(&i) {
+            assert_ne!(
+                detokenized_words, original_words,
+                "line {i} now matches the original text"
+            );
+        } else {
+            assert_eq!(detokenized_words, original_words, "line {i}");
+        }
+    }
+}
+
+#[test]
+fn tokenize_reader_preserves_line_order() {
+    // Enough lines to span several streaming chunks
+    let text: String = (0..25_000)
+        .map(|i| format!("Line number {i}, (in order).\n"))
+        .collect();
+    let mut output = Vec::new();
+    moses_tokenize_reader(
+        Cursor::new(text),
+        &mut output,
+        Language::En,
+        true,
+        false,
+        &[],
+    )
+    .unwrap();
+    let expected: String = (0..25_000)
+        .map(|i| format!("Line number {i} , ( in order ) .\n"))
+        .collect();
+    assert_eq!(String::from_utf8(output).unwrap(), expected);
+}
+
+#[test]
+fn invalid_protected_pattern() {
+    let result = moses_tokenize(
+        "Some text.",
+        Language::En,
+        true,
+        false,
+        &[r"\w+", r"(unclosed"],
+    );
+    match result {
+        Err(Error::InvalidPattern { pattern, index, .. }) => {
+            assert_eq!(pattern, "(unclosed");
+            assert_eq!(index, 1);
+        }
+        other => panic!("expected an invalid pattern error, got {other:?}"),
+    }
+}
+
+#[test]
+fn missing_input_file() {
+    let result = moses_tokenize_file(
+        "tests/does_not_exist.txt",
+        "tests/does_not_exist_output.txt",
+        Language::En,
+        true,
+        false,
+        &[],
+    );
+    match result {
+        Err(Error::Io {
+            path: Some(path), ..
+        }) => assert_eq!(path, "tests/does_not_exist.txt"),
+        other => panic!("expected an I/O error, got {other:?}"),
+    }
+}
+
+#[test]
+fn invalid_utf8_offset() {
+    let mut input = b"First line.\nSecond ".to_vec();
+    input.extend_from_slice(&[0xff, 0xfe]);
+    input.extend_from_slice(b" line.\n");
+    let result = moses_tokenize_reader(
+        Cursor::new(input),
+        Vec::new(),
+        Language::En,
+        true,
+        false,
+        &[],
+    );
+    match result {
+        Err(Error::InvalidUtf8 { offset }) => assert_eq!(offset, 19),
+        other => panic!("expected an invalid UTF-8 error, got {other:?}"),
+    }
+}
+
+#[test]
+fn unsupported_language() {
+    assert_eq!("fr".parse::<Language>().unwrap(), Language::Fr);
+    assert!(matches!(
+        "qq".parse::<Language>(),
+        Err(Error::UnsupportedLanguage(language)) if language == "qq"
+    ));
+}